@@ -0,0 +1,5 @@
+fn main() {
+    // Links the Node.js addon against the symbols of the host process
+    #[cfg(feature = "napi")]
+    napi_build::setup();
+}
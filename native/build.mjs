@@ -0,0 +1,20 @@
+// Builds the native Node.js addon and copies it next to the loader (`index.js`)
+import { execFileSync } from "node:child_process";
+import { copyFileSync } from "node:fs";
+import { fileURLToPath } from "node:url";
+
+const root = fileURLToPath(new URL("..", import.meta.url));
+execFileSync(
+  "cargo",
+  ["build", "--release", "--no-default-features", "--features", "napi"],
+  { cwd: root, stdio: "inherit" },
+);
+
+const library = {
+  darwin: "libreact_swc_suspense_tracker.dylib",
+  win32: "react_swc_suspense_tracker.dll",
+}[process.platform] ?? "libreact_swc_suspense_tracker.so";
+copyFileSync(
+  new URL(`../target/release/${library}`, import.meta.url),
+  new URL("./react_swc_suspense_tracker.node", import.meta.url),
+);
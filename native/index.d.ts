@@ -0,0 +1,41 @@
+export interface TransformOptions {
+  /** The name of the file used in the boundary IDs (e.g. `src/App.tsx`) */
+  filename: string;
+  /** Defaults to `development` */
+  envName?: "development" | "test" | "production";
+  /** The plugin options */
+  config?: Record<string, unknown>;
+}
+
+export interface BoundaryMetadata {
+  id: string;
+  kind: "suspense" | "error";
+  component: string;
+  from: string;
+  /** 0 if unknown */
+  line: number;
+  enclosingComponent: string | null;
+  fallback?: string;
+  parent?: string;
+  location?: string;
+}
+
+export interface Diagnostic {
+  rule: string;
+  severity: "warning" | "error";
+  message: string;
+  /** 0 if unknown */
+  line: number;
+}
+
+export interface TransformResult {
+  code: string;
+  boundaries: BoundaryMetadata[];
+  diagnostics: Diagnostic[];
+}
+
+/** Transforms a source string like the SWC plugin, without the wasm plugin host */
+export function transformSync(
+  code: string,
+  options: TransformOptions,
+): TransformResult;
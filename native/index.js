@@ -0,0 +1,12 @@
+// Loads the native addon built by `npm run build:native`
+let binding;
+try {
+  binding = require("./react_swc_suspense_tracker.node");
+} catch (error) {
+  throw new Error(
+    "react-swc-suspense-tracker/native is not built for this platform, run `npm run build:native`",
+    { cause: error },
+  );
+}
+
+module.exports.transformSync = binding.transformSync;
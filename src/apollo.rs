@@ -0,0 +1,108 @@
+use swc_core::{
+    common::{util::take::Take, DUMMY_SP},
+    ecma::ast::*,
+};
+
+use crate::{ApolloHook, ApolloInstrumentation, HookInjection, TransformVisitor};
+
+pub(crate) const APOLLO_PACKAGE_NAME: &str = "@apollo/client";
+/// Entry points exporting the React hooks of Apollo Client
+const APOLLO_HOOK_PACKAGES: [&str; 2] = [APOLLO_PACKAGE_NAME, "@apollo/client/react"];
+const CONTEXT_ID_PATH: &str = "context.suspenseTrackerId";
+const SKIP_TOKEN_EXPORT: &str = "skipToken";
+
+/// Hooks used if `apolloHooks` is not configured
+///
+/// `useBackgroundQuery` starts the request which `useReadQuery` suspends on, the
+/// context reaches the links of the request while the read is attributed by wrapping.
+pub(crate) fn default_apollo_hooks() -> Vec<ApolloHook> {
+    [
+        ("useSuspenseQuery", ApolloInstrumentation::Context),
+        ("useBackgroundQuery", ApolloInstrumentation::Context),
+        ("useReadQuery", ApolloInstrumentation::Wrap),
+    ]
+    .into_iter()
+    .map(|(name, instrument)| ApolloHook {
+        name: name.to_string(),
+        instrument,
+    })
+    .collect()
+}
+
+impl TransformVisitor {
+    /// Adds `context: { suspenseTrackerId: "file:line" }` to the options of
+    /// `useSuspenseQuery(query, options)` or wraps `useReadQuery(queryRef)` into
+    /// `__trackSuspendingHook(() => useReadQuery(queryRef), "file:line")`
+    pub(crate) fn track_apollo_call(&mut self, call: &mut CallExpr) {
+        if !self.config.track_apollo {
+            return;
+        }
+        let hooks = self
+            .config
+            .apollo_hooks
+            .clone()
+            .unwrap_or_else(default_apollo_hooks);
+        let Some((package, hook)) = hooks.into_iter().find_map(|hook| {
+            APOLLO_HOOK_PACKAGES
+                .into_iter()
+                .find(|package| self.is_imported_call(&call.callee, package, &hook.name))
+                .map(|package| (package, hook))
+        }) else {
+            return;
+        };
+
+        match hook.instrument {
+            ApolloInstrumentation::Context => {
+                let options = call.args.get(1).map(|options| &*options.expr);
+                // `skipToken` disables the query and must be passed as is
+                if options.is_some_and(|options| self.is_skip_token(options)) {
+                    return;
+                }
+                // A variable may hold `skipToken` as well, which must not be spread
+                let variable = options.and_then(Expr::as_ident).cloned();
+                self.inject_hook_call_id(
+                    call,
+                    &[HookInjection::OptionsField {
+                        arg: 1,
+                        path: CONTEXT_ID_PATH.to_string(),
+                    }],
+                );
+                if let Some(variable) = variable {
+                    let skip_token = self.runtime_binding(package, SKIP_TOKEN_EXPORT);
+                    if let Some(options) = call.args.get_mut(1) {
+                        *options.expr =
+                            skip_token_guard(variable, skip_token, options.expr.as_mut().take());
+                    }
+                }
+            }
+            ApolloInstrumentation::Wrap => {
+                self.inject_hook_call_id(call, &[HookInjection::WrapCall])
+            }
+        }
+    }
+
+    fn is_skip_token(&self, options: &Expr) -> bool {
+        options.as_ident().is_some_and(|ident| {
+            self.imports.get(&ident.to_id()).is_some_and(|binding| {
+                APOLLO_HOOK_PACKAGES
+                    .iter()
+                    .any(|package| binding.is(package, SKIP_TOKEN_EXPORT))
+            })
+        })
+    }
+}
+
+/// Creates `options === skipToken ? options : tracked`
+fn skip_token_guard(options: Ident, skip_token: Ident, tracked: Expr) -> Expr {
+    Expr::Cond(CondExpr {
+        span: DUMMY_SP,
+        test: Box::new(Expr::Bin(BinExpr {
+            span: DUMMY_SP,
+            op: BinaryOp::EqEqEq,
+            left: Box::new(Expr::Ident(options.clone())),
+            right: Box::new(Expr::Ident(skip_token)),
+        })),
+        cons: Box::new(Expr::Ident(options)),
+        alt: Box::new(tracked),
+    })
+}
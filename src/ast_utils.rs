@@ -0,0 +1,286 @@
+//! Small helpers for creating the AST nodes injected by the plugin
+
+use swc_core::{
+    common::{Span, DUMMY_SP},
+    ecma::ast::*,
+};
+
+/// Creates an identifier without syntax context
+pub fn ident(sym: &str) -> Ident {
+    Ident {
+        ctxt: Default::default(),
+        span: DUMMY_SP,
+        sym: sym.into(),
+        optional: false,
+    }
+}
+
+/// Creates a string literal expression
+pub fn str_expr(value: &str) -> Expr {
+    Expr::Lit(Lit::Str(Str {
+        span: DUMMY_SP,
+        value: value.into(),
+        raw: None,
+    }))
+}
+
+/// Creates a number literal expression
+pub fn num_expr(value: usize) -> Expr {
+    Expr::Lit(Lit::Num(Number {
+        span: DUMMY_SP,
+        value: value as f64,
+        raw: None,
+    }))
+}
+
+/// Creates a template literal expression from its static parts and the expressions
+/// in between (`quasis` has one element more than `exprs`)
+pub fn tpl_expr(quasis: &[&str], exprs: Vec<Expr>) -> Expr {
+    let last = quasis.len() - 1;
+    Expr::Tpl(Tpl {
+        span: DUMMY_SP,
+        exprs: exprs.into_iter().map(Box::new).collect(),
+        quasis: quasis
+            .iter()
+            .enumerate()
+            .map(|(index, quasi)| TplElement {
+                span: DUMMY_SP,
+                tail: index == last,
+                cooked: Some((*quasi).into()),
+                raw: quasi
+                    .replace('\\', "\\\\")
+                    .replace('`', "\\`")
+                    .replace("${", "\\${")
+                    .into(),
+            })
+            .collect(),
+    })
+}
+
+/// Creates an object literal expression from `key: value` pairs
+/// (keys which are no identifiers like `code.filepath` are quoted)
+pub fn object_expr(props: Vec<(&str, Expr)>) -> Expr {
+    Expr::Object(ObjectLit {
+        span: DUMMY_SP,
+        props: props
+            .into_iter()
+            .map(|(key, value)| {
+                let key = if is_identifier(key) {
+                    PropName::Ident(IdentName {
+                        span: DUMMY_SP,
+                        sym: key.into(),
+                    })
+                } else {
+                    PropName::Str(Str {
+                        span: DUMMY_SP,
+                        value: key.into(),
+                        raw: None,
+                    })
+                };
+                PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                    key,
+                    value: Box::new(value),
+                })))
+            })
+            .collect(),
+    })
+}
+
+/// Checks if a property key can be written without quotes
+fn is_identifier(key: &str) -> bool {
+    let is_start = |c: char| c.is_ascii_alphabetic() || c == '_' || c == '$';
+    key.starts_with(is_start) && key.chars().all(|c| is_start(c) || c.is_ascii_digit())
+}
+
+/// Creates an array literal expression
+pub fn array_expr(elems: Vec<Expr>) -> Expr {
+    Expr::Array(ArrayLit {
+        span: DUMMY_SP,
+        elems: elems
+            .into_iter()
+            .map(|expr| {
+                Some(ExprOrSpread {
+                    spread: None,
+                    expr: Box::new(expr),
+                })
+            })
+            .collect(),
+    })
+}
+
+/// Creates a call expression `callee(...args)`
+pub fn call_expr(callee: Ident, args: Vec<Expr>) -> Expr {
+    Expr::Call(call_node(callee, args))
+}
+
+/// Creates the call `callee(...args)` without wrapping it into an [`Expr`]
+pub fn call_node(callee: Ident, args: Vec<Expr>) -> CallExpr {
+    CallExpr {
+        span: DUMMY_SP,
+        ctxt: Default::default(),
+        callee: Callee::Expr(Box::new(Expr::Ident(callee))),
+        args: args
+            .into_iter()
+            .map(|expr| ExprOrSpread {
+                spread: None,
+                expr: Box::new(expr),
+            })
+            .collect(),
+        type_args: None,
+    }
+}
+
+/// Creates the arrow function `() => body`
+pub fn arrow_expr(body: Expr) -> Expr {
+    Expr::Arrow(ArrowExpr {
+        span: DUMMY_SP,
+        ctxt: Default::default(),
+        params: Vec::new(),
+        body: Box::new(BlockStmtOrExpr::Expr(Box::new(body))),
+        is_async: false,
+        is_generator: false,
+        type_params: None,
+        return_type: None,
+    })
+}
+
+/// Creates an expression statement
+pub fn expr_stmt(expr: Expr) -> Stmt {
+    Stmt::Expr(ExprStmt {
+        span: DUMMY_SP,
+        expr: Box::new(expr),
+    })
+}
+
+/// Creates a module level expression statement
+pub fn expr_stmt_item(expr: Expr) -> ModuleItem {
+    ModuleItem::Stmt(expr_stmt(expr))
+}
+
+/// Creates `const name = init;`
+pub fn const_decl(name: &str, init: Expr) -> VarDecl {
+    VarDecl {
+        span: DUMMY_SP,
+        ctxt: Default::default(),
+        kind: VarDeclKind::Const,
+        declare: false,
+        decls: vec![VarDeclarator {
+            span: DUMMY_SP,
+            name: Pat::Ident(BindingIdent {
+                id: ident(name),
+                type_ann: None,
+            }),
+            init: Some(Box::new(init)),
+            definite: false,
+        }],
+    }
+}
+
+/// Creates `const name = init;` as module item
+pub fn const_item(name: &str, init: Expr) -> ModuleItem {
+    ModuleItem::Stmt(Stmt::Decl(Decl::Var(Box::new(const_decl(name, init)))))
+}
+
+/// Creates `export const name = init;`
+pub fn export_const_item(name: &str, init: Expr) -> ModuleItem {
+    ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+        span: DUMMY_SP,
+        decl: Decl::Var(Box::new(const_decl(name, init))),
+    }))
+}
+
+/// Creates `import { name1, name2 as local } from "src";` of `(imported, local)` pairs
+pub fn named_import(names: &[(&str, &str)], src: &str) -> ModuleItem {
+    ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+        span: DUMMY_SP,
+        specifiers: names
+            .iter()
+            .map(|(imported, local)| {
+                ImportSpecifier::Named(ImportNamedSpecifier {
+                    span: DUMMY_SP,
+                    local: ident(local),
+                    imported: (imported != local).then(|| ModuleExportName::Ident(ident(imported))),
+                    is_type_only: false,
+                })
+            })
+            .collect(),
+        src: Box::new(Str {
+            span: DUMMY_SP,
+            value: src.into(),
+            raw: None,
+        }),
+        type_only: false,
+        with: None,
+        phase: ImportPhase::Evaluation,
+    }))
+}
+
+/// Creates a JSX attribute `name="value"` at the position of `span`
+pub fn jsx_str_attr(name: &str, value: &str, span: Span) -> JSXAttrOrSpread {
+    JSXAttrOrSpread::JSXAttr(JSXAttr {
+        span,
+        name: JSXAttrName::Ident(IdentName {
+            span,
+            sym: name.into(),
+        }),
+        value: Some(JSXAttrValue::Str(Str {
+            span,
+            value: value.into(),
+            raw: None,
+        })),
+    })
+}
+
+/// Creates a JSX attribute `name={expr}` at the position of `span`
+pub fn jsx_expr_attr(name: &str, expr: Expr, span: Span) -> JSXAttrOrSpread {
+    JSXAttrOrSpread::JSXAttr(JSXAttr {
+        span,
+        name: JSXAttrName::Ident(IdentName {
+            span,
+            sym: name.into(),
+        }),
+        value: Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+            span,
+            expr: JSXExpr::Expr(Box::new(expr)),
+        })),
+    })
+}
+
+/// Returns the value of the attribute `name` of a JSX element
+pub fn jsx_attr_value<'a>(jsx_element: &'a JSXElement, name: &str) -> Option<&'a JSXAttrValue> {
+    jsx_element
+        .opening
+        .attrs
+        .iter()
+        .find_map(|attr| match attr {
+            JSXAttrOrSpread::JSXAttr(JSXAttr {
+                name: JSXAttrName::Ident(attr_name),
+                value: Some(value),
+                ..
+            }) if attr_name.sym == name => Some(value),
+            _ => None,
+        })
+}
+
+/// Creates a JSX element `<name {...attrs}>{children}</name>`
+pub fn jsx_element(
+    name: &str,
+    attrs: Vec<JSXAttrOrSpread>,
+    children: Vec<JSXElementChild>,
+) -> JSXElement {
+    JSXElement {
+        span: DUMMY_SP,
+        opening: JSXOpeningElement {
+            name: JSXElementName::Ident(ident(name)),
+            span: DUMMY_SP,
+            attrs,
+            self_closing: false,
+            type_args: None,
+        },
+        children,
+        closing: Some(JSXClosingElement {
+            span: DUMMY_SP,
+            name: JSXElementName::Ident(ident(name)),
+        }),
+    }
+}
@@ -0,0 +1,79 @@
+use swc_core::{
+    common::util::take::Take,
+    ecma::{
+        ast::*,
+        visit::{VisitMut, VisitMutWith},
+    },
+};
+
+use crate::{
+    ast_utils::{call_expr, ident, str_expr},
+    suspenders::is_component_name,
+    TransformVisitor,
+};
+
+/// Server safe runtime entry point (no client-only React APIs)
+const SERVER_TRACKER_PACKAGE_NAME: &str = "react-swc-suspense-tracker/server";
+const TRACK_AWAIT_IMPORT_NAME: &str = "__trackAwait";
+
+impl TransformVisitor {
+    /// Wraps the awaits of an async function component (e.g. a Server Component)
+    /// into `await __trackAwait(expr, "file:line")`
+    ///
+    /// Anonymous functions (`export default async function () {}`) count as components.
+    pub(crate) fn track_async_function(&mut self, name: Option<&str>, function: &mut Function) {
+        if !function.is_async || !name.is_none_or(is_component_name) {
+            return;
+        }
+        if let Some(body) = &mut function.body {
+            self.wrap_awaits(body);
+        }
+    }
+
+    /// Same as [`Self::track_async_function`] for async arrow function components
+    pub(crate) fn track_async_arrow(&mut self, name: &str, arrow: &mut ArrowExpr) {
+        if arrow.is_async && is_component_name(name) {
+            self.wrap_awaits(&mut *arrow.body);
+        }
+    }
+
+    fn wrap_awaits<N>(&mut self, body: &mut N)
+    where
+        N: for<'a> VisitMutWith<AwaitWrapper<'a>>,
+    {
+        let mut wrapper = AwaitWrapper {
+            visitor: self,
+            found: false,
+        };
+        body.visit_mut_with(&mut wrapper);
+        if wrapper.found {
+            self.add_runtime_import_from(SERVER_TRACKER_PACKAGE_NAME, TRACK_AWAIT_IMPORT_NAME);
+        }
+    }
+}
+
+/// Wraps the arguments of `await` expressions of a component body
+pub(crate) struct AwaitWrapper<'a> {
+    visitor: &'a TransformVisitor,
+    found: bool,
+}
+
+impl VisitMut for AwaitWrapper<'_> {
+    fn visit_mut_await_expr(&mut self, await_expr: &mut AwaitExpr) {
+        await_expr.visit_mut_children_with(self);
+
+        let id = self
+            .visitor
+            .generate_boundary_id(self.visitor.lookup_line(await_expr.span.lo));
+        let arg = await_expr.arg.as_mut().take();
+        *await_expr.arg = call_expr(ident(TRACK_AWAIT_IMPORT_NAME), vec![arg, str_expr(&id)]);
+        self.found = true;
+    }
+
+    // Awaits of nested functions don't block the component
+    fn visit_mut_function(&mut self, _: &mut Function) {}
+
+    fn visit_mut_arrow_expr(&mut self, _: &mut ArrowExpr) {}
+
+    fn visit_mut_class(&mut self, _: &mut Class) {}
+}
@@ -0,0 +1,159 @@
+use std::{fmt, fs, io, path::Path};
+
+use serde::Deserialize;
+
+use crate::scan::InventoryEntry;
+
+/// A boundary of a saved JSON inventory
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BaselineEntry {
+    file: String,
+    id: String,
+    component: String,
+    line: usize,
+    enclosing_component: Option<String>,
+}
+
+/// The fields of a boundary which are compared
+#[derive(Clone, Copy)]
+pub struct Entry<'a> {
+    file: &'a str,
+    id: &'a str,
+    component: &'a str,
+    line: usize,
+    enclosing_component: Option<&'a str>,
+}
+
+impl Changes<'_> {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.moved.is_empty()
+    }
+}
+
+impl Entry<'_> {
+    /// Whether both entries are the same boundary component rendered by the same component
+    fn is_same_place(&self, other: &Entry) -> bool {
+        self.file == other.file
+            && self.component == other.component
+            && self.enclosing_component == other.enclosing_component
+    }
+}
+
+/// Boundaries which changed compared to the baseline
+pub struct Changes<'a> {
+    pub added: Vec<Entry<'a>>,
+    pub removed: Vec<Entry<'a>>,
+    /// Boundaries which got another ID, e.g. because lines were inserted above them
+    pub moved: Vec<(Entry<'a>, Entry<'a>)>,
+}
+
+/// Reads an inventory written by `suspense-tracker --format json`
+pub fn read_baseline(path: &Path) -> io::Result<Vec<BaselineEntry>> {
+    let raw = fs::read_to_string(path)?;
+    serde_json::from_str(&raw).map_err(|error| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("failed to parse {}: {error}", path.display()),
+        )
+    })
+}
+
+/// Compares the current inventory with the baseline
+pub fn diff_inventories<'a>(
+    baseline: &'a [BaselineEntry],
+    inventory: &'a [InventoryEntry],
+) -> Changes<'a> {
+    let baseline = baseline
+        .iter()
+        .map(|entry| Entry {
+            file: &entry.file,
+            id: &entry.id,
+            component: &entry.component,
+            line: entry.line,
+            enclosing_component: entry.enclosing_component.as_deref(),
+        })
+        .collect();
+    diff_entries(baseline, inventory.iter().map(Entry::from).collect())
+}
+
+/// Compares two lists of boundaries
+///
+/// Boundaries keeping their ID are unchanged. Of the remaining boundaries, a removed and
+/// an added boundary of the same component, file and enclosing component count as moved.
+pub fn diff_entries<'a>(old: Vec<Entry<'a>>, new: Vec<Entry<'a>>) -> Changes<'a> {
+    let mut removed = old;
+    let mut added = Vec::new();
+    for current in new {
+        match removed
+            .iter()
+            .position(|old| old.file == current.file && old.id == current.id)
+        {
+            Some(index) => {
+                removed.remove(index);
+            }
+            None => added.push(current),
+        }
+    }
+
+    let mut moved = Vec::new();
+    added.retain(
+        |current| match removed.iter().position(|old| old.is_same_place(current)) {
+            Some(index) => {
+                moved.push((removed.remove(index), *current));
+                false
+            }
+            None => true,
+        },
+    );
+
+    Changes {
+        added,
+        removed,
+        moved,
+    }
+}
+
+impl<'a> From<&'a InventoryEntry> for Entry<'a> {
+    fn from(entry: &'a InventoryEntry) -> Self {
+        Entry {
+            file: &entry.file,
+            id: &entry.boundary.id,
+            component: &entry.boundary.component,
+            line: entry.boundary.line,
+            enclosing_component: entry.boundary.enclosing_component.as_deref(),
+        }
+    }
+}
+
+impl fmt::Display for Entry<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{} <{}>", self.file, self.line, self.component)?;
+        match self.enclosing_component {
+            Some(component) => write!(f, " in {component}"),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Prints `+` for added, `-` for removed and `~` for moved boundaries and a summary
+impl fmt::Display for Changes<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for entry in &self.added {
+            writeln!(f, "+ {entry}")?;
+        }
+        for entry in &self.removed {
+            writeln!(f, "- {entry}")?;
+        }
+        for (old, new) in &self.moved {
+            writeln!(f, "~ {}:{} -> {new}", old.file, old.line)?;
+        }
+        writeln!(
+            f,
+            "{} added, {} removed, {} moved",
+            self.added.len(),
+            self.removed.len(),
+            self.moved.len()
+        )
+    }
+}
@@ -0,0 +1,31 @@
+use crate::scan::InventoryEntry;
+
+/// Renders a `.d.ts` file declaring a union type of all boundary IDs
+///
+/// Boundaries sharing an ID are listed once, the order of the inventory is kept.
+pub fn boundary_id_declaration(inventory: &[InventoryEntry]) -> String {
+    let mut ids: Vec<&str> = Vec::new();
+    for entry in inventory {
+        if !ids.contains(&entry.boundary.id.as_str()) {
+            ids.push(&entry.boundary.id);
+        }
+    }
+
+    let mut dts = String::from("// Generated by suspense-tracker - do not edit\n");
+    if ids.is_empty() {
+        dts.push_str("export type BoundaryId = never;\n");
+        return dts;
+    }
+    let members: Vec<String> = ids
+        .into_iter()
+        .map(|id| {
+            let literal = serde_json::to_string(id).expect("failed to serialize boundary id");
+            format!("  | {literal}")
+        })
+        .collect();
+    dts.push_str(&format!(
+        "export type BoundaryId =\n{};\n",
+        members.join("\n")
+    ));
+    dts
+}
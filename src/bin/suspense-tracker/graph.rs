@@ -0,0 +1,117 @@
+use react_swc_suspense_tracker::BoundaryKind;
+use serde::Serialize;
+
+use crate::scan::InventoryEntry;
+
+/// The boundaries of a file nested by their position in the JSX
+#[derive(Serialize)]
+pub struct FileTree<'a> {
+    file: &'a str,
+    boundaries: Vec<BoundaryNode<'a>>,
+}
+
+/// A boundary and the boundaries nested inside it
+#[derive(Serialize)]
+pub struct BoundaryNode<'a> {
+    id: &'a str,
+    kind: BoundaryKind,
+    component: &'a str,
+    line: usize,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    children: Vec<BoundaryNode<'a>>,
+}
+
+/// Nests the boundaries of every file below their enclosing boundary
+///
+/// Nesting is only known within a file, boundaries rendered through components
+/// of other files are roots of their own file.
+pub fn boundary_trees(inventory: &[InventoryEntry]) -> Vec<FileTree<'_>> {
+    let parents = parent_indices(inventory);
+    let mut trees: Vec<FileTree> = Vec::new();
+    for (index, entry) in inventory.iter().enumerate() {
+        if parents[index].is_some() {
+            continue;
+        }
+        let node = boundary_node(inventory, &parents, index);
+        match trees.last_mut() {
+            Some(tree) if tree.file == entry.file => tree.boundaries.push(node),
+            _ => trees.push(FileTree {
+                file: &entry.file,
+                boundaries: vec![node],
+            }),
+        }
+    }
+    trees
+}
+
+/// Renders the boundary nesting as Graphviz DOT graph with one cluster per file
+pub fn boundary_dot_graph(inventory: &[InventoryEntry]) -> String {
+    let parents = parent_indices(inventory);
+    let mut dot = String::from("digraph boundaries {\n  node [shape=box];\n");
+    let mut files: Vec<&str> = Vec::new();
+    for entry in inventory {
+        if !files.contains(&entry.file.as_str()) {
+            files.push(&entry.file);
+        }
+    }
+    for (cluster, file) in files.into_iter().enumerate() {
+        dot.push_str(&format!(
+            "  subgraph cluster_{cluster} {{\n    label={};\n",
+            dot_string(file)
+        ));
+        for (index, entry) in inventory.iter().enumerate() {
+            if entry.file == file {
+                let label = format!("<{}>\n{}", entry.boundary.component, entry.boundary.id);
+                dot.push_str(&format!("    b{index} [label={}];\n", dot_string(&label)));
+            }
+        }
+        dot.push_str("  }\n");
+    }
+    for (index, parent) in parents.iter().enumerate() {
+        if let Some(parent) = parent {
+            dot.push_str(&format!("  b{parent} -> b{index};\n"));
+        }
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+/// Returns the inventory index of the enclosing boundary of every boundary
+///
+/// Parents are found before their children as files are traversed in order.
+fn parent_indices(inventory: &[InventoryEntry]) -> Vec<Option<usize>> {
+    inventory
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| {
+            let parent = entry.boundary.parent.as_ref()?;
+            (0..index).rev().find(|&candidate| {
+                inventory[candidate].file == entry.file
+                    && inventory[candidate].boundary.id == *parent
+            })
+        })
+        .collect()
+}
+
+fn boundary_node<'a>(
+    inventory: &'a [InventoryEntry],
+    parents: &[Option<usize>],
+    index: usize,
+) -> BoundaryNode<'a> {
+    let boundary = &inventory[index].boundary;
+    BoundaryNode {
+        id: &boundary.id,
+        kind: boundary.kind,
+        component: &boundary.component,
+        line: boundary.line,
+        children: (index + 1..inventory.len())
+            .filter(|&child| parents[child] == Some(index))
+            .map(|child| boundary_node(inventory, parents, child))
+            .collect(),
+    }
+}
+
+/// Quotes a DOT string (JSON escaping is compatible)
+fn dot_string(value: &str) -> String {
+    serde_json::to_string(value).expect("failed to serialize label")
+}
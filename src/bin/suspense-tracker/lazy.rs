@@ -0,0 +1,56 @@
+use std::{io, path::PathBuf};
+
+use react_swc_suspense_tracker::{check_source, Config, RuleSeverity};
+
+use crate::scan::{development_context, visit_source_files};
+
+/// The diagnostics rule reporting lazy components rendered outside of boundaries
+const UNGUARDED_LAZY: &str = "unguarded-lazy";
+
+/// A lazy component rendered without a Suspense boundary of its module around it
+pub struct UnguardedLazy {
+    /// The file rendering the component (relative to the scanned root)
+    pub file: String,
+    /// The line of the rendered element (0 if unknown)
+    pub line: usize,
+    /// Description of the problem
+    pub message: String,
+}
+
+/// Scans all source files below `roots` for `React.lazy`, `next/dynamic` and
+/// `@loadable/component` components which are rendered outside of the Suspense
+/// boundaries of their module
+///
+/// The `unguarded-lazy` rule is enabled independent of the configured `rules`.
+pub fn scan_unguarded_lazy(roots: &[PathBuf], config: &Config) -> io::Result<Vec<UnguardedLazy>> {
+    let mut config = config.clone();
+    config
+        .rules
+        .insert(UNGUARDED_LAZY.to_string(), RuleSeverity::Warn);
+
+    let mut report = Vec::new();
+    visit_source_files(roots, |file, code| {
+        match check_source(code, config.clone(), development_context(file)) {
+            Ok(diagnostics) => report.extend(
+                diagnostics
+                    .into_iter()
+                    .filter(|diagnostic| diagnostic.rule == UNGUARDED_LAZY)
+                    .map(|diagnostic| UnguardedLazy {
+                        file: file.to_string(),
+                        line: diagnostic.line,
+                        message: diagnostic.message,
+                    }),
+            ),
+            Err(error) => eprintln!("warning: skipping {file}: {error}"),
+        }
+    })?;
+    Ok(report)
+}
+
+/// Renders one `file:line: message` line per unguarded lazy component
+pub fn unguarded_lazy_report(report: &[UnguardedLazy]) -> String {
+    report
+        .iter()
+        .map(|entry| format!("{}:{}: {}\n", entry.file, entry.line, entry.message))
+        .collect()
+}
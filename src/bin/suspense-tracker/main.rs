@@ -0,0 +1,243 @@
+//! `suspense-tracker` - prints a JSON inventory of all boundaries of a project
+//!
+//! ```text
+//! suspense-tracker [--config <swc-plugin-config.json>] [--format json|dts|tree|dot|unguarded-lazy] [--out <file>] <dir>...
+//! suspense-tracker report [--html] [--config <swc-plugin-config.json>] [--out <file>] <dir>...
+//! suspense-tracker --watch [--config <swc-plugin-config.json>] <dir>...
+//! suspense-tracker diff <baseline.json> [--strict] [--config <swc-plugin-config.json>] [--out <file>] <dir>...
+//! ```
+//!
+//! `--format dts` prints a `type BoundaryId = "src/App.tsx:12" | ...` declaration instead.
+//! `--format tree` and `--format dot` print the nesting of the boundaries of every file as
+//! JSON tree or Graphviz graph.
+//! `--format unguarded-lazy` prints a `file:line: message` report of lazy components rendered
+//! outside of the Suspense boundaries of their module and fails if there are any.
+//! `report` prints a Markdown (or HTML) summary of the boundaries grouped by directory.
+//! `--watch` keeps the inventory in memory and prints the boundaries of changed files.
+//! `diff` compares the boundaries with a saved JSON inventory and fails on removed boundaries
+//! with `--strict`.
+
+use std::{fs, io, path::PathBuf, process::ExitCode};
+
+use react_swc_suspense_tracker::Config;
+
+mod diff;
+mod dts;
+mod graph;
+mod lazy;
+mod report;
+mod scan;
+mod watch;
+
+const USAGE: &str = "usage: suspense-tracker [--config <swc-plugin-config.json>] [--format json|dts|tree|dot|unguarded-lazy] [--out <file>] <dir>...
+       suspense-tracker report [--html] [--config <swc-plugin-config.json>] [--out <file>] <dir>...
+       suspense-tracker --watch [--config <swc-plugin-config.json>] <dir>...
+       suspense-tracker diff <baseline.json> [--strict] [--config <swc-plugin-config.json>] [--out <file>] <dir>...";
+
+/// What the tool prints
+enum Command {
+    /// The inventory of all boundaries in the given format
+    Inventory(Format),
+    /// A Markdown or HTML summary for people who don't read the code
+    Report {
+        /// Whether to print HTML instead of Markdown
+        html: bool,
+    },
+    /// The boundaries added, removed or moved whenever a file changes
+    Watch,
+    /// The boundaries added, removed or moved since a saved JSON inventory
+    Diff {
+        /// The saved inventory
+        baseline: PathBuf,
+        /// Whether removed boundaries fail the command
+        strict: bool,
+    },
+}
+
+/// How the inventory is printed
+enum Format {
+    /// JSON array of all boundaries
+    Json,
+    /// TypeScript declaration of a union type of all boundary IDs
+    Dts,
+    /// JSON tree of the nested boundaries of every file
+    Tree,
+    /// Graphviz DOT graph of the nested boundaries of every file
+    Dot,
+    /// Report of lazy components rendered outside of boundaries
+    UnguardedLazy,
+}
+
+/// Parsed command line arguments
+struct Options {
+    /// Plugin configuration (same shape as the SWC plugin options)
+    config: Config,
+    /// Directories or files to scan
+    roots: Vec<PathBuf>,
+    /// What is printed
+    command: Command,
+    /// File the output is written to (stdout if not set)
+    out: Option<PathBuf>,
+}
+
+fn parse_options(args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut args = args.peekable();
+    let mut command = match args
+        .next_if(|arg| arg == "report" || arg == "diff")
+        .as_deref()
+    {
+        Some("report") => Command::Report { html: false },
+        Some(_) => Command::Diff {
+            baseline: PathBuf::from(args.next().ok_or("diff requires a baseline inventory")?),
+            strict: false,
+        },
+        None => Command::Inventory(Format::Json),
+    };
+    let mut config = Config::default();
+    let mut roots = Vec::new();
+    let mut out = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--config" => {
+                let path = args.next().ok_or("--config requires a path")?;
+                let raw = fs::read_to_string(&path)
+                    .map_err(|error| format!("failed to read {path}: {error}"))?;
+                config = serde_json::from_str(&raw)
+                    .map_err(|error| format!("failed to parse {path}: {error}"))?;
+            }
+            "--format" if matches!(command, Command::Inventory(_)) => {
+                let format = match args.next().as_deref() {
+                    Some("json") => Format::Json,
+                    Some("dts") => Format::Dts,
+                    Some("tree") => Format::Tree,
+                    Some("dot") => Format::Dot,
+                    Some("unguarded-lazy") => Format::UnguardedLazy,
+                    _ => {
+                        return Err(format!(
+                            "--format requires json, dts, tree, dot or unguarded-lazy\n{USAGE}"
+                        ))
+                    }
+                };
+                command = Command::Inventory(format);
+            }
+            "--html" => match &mut command {
+                Command::Report { html } => *html = true,
+                _ => return Err(format!("--html is only supported by report\n{USAGE}")),
+            },
+            "--watch" => match command {
+                Command::Inventory(_) => command = Command::Watch,
+                _ => return Err(format!("--watch is not supported by subcommands\n{USAGE}")),
+            },
+            "--strict" => match &mut command {
+                Command::Diff { strict, .. } => *strict = true,
+                _ => return Err(format!("--strict is only supported by diff\n{USAGE}")),
+            },
+            "--out" => out = Some(PathBuf::from(args.next().ok_or("--out requires a path")?)),
+            "-h" | "--help" => return Err(USAGE.to_string()),
+            _ if arg.starts_with('-') => return Err(format!("unknown option {arg}\n{USAGE}")),
+            _ => roots.push(PathBuf::from(arg)),
+        }
+    }
+
+    if matches!(command, Command::Watch) && out.is_some() {
+        return Err(format!("--out is not supported by --watch\n{USAGE}"));
+    }
+    if roots.is_empty() {
+        roots.push(PathBuf::from("."));
+    }
+
+    Ok(Options {
+        config,
+        roots,
+        command,
+        out,
+    })
+}
+
+/// Scans the roots and renders the output, `false` if the output reports a failure
+fn render(options: &Options) -> io::Result<(String, bool)> {
+    let format = match &options.command {
+        Command::Inventory(format) => format,
+        Command::Report { html } => {
+            let inventory = scan::scan(&options.roots, &options.config)?;
+            let report = if *html {
+                report::html_report(&inventory)
+            } else {
+                report::markdown_report(&inventory)
+            };
+            return Ok((report, true));
+        }
+        Command::Diff { baseline, strict } => {
+            let baseline = diff::read_baseline(baseline)?;
+            let inventory = scan::scan(&options.roots, &options.config)?;
+            let changes = diff::diff_inventories(&baseline, &inventory);
+            return Ok((changes.to_string(), !*strict || changes.removed.is_empty()));
+        }
+        Command::Watch => unreachable!("watch mode does not render a single output"),
+    };
+    let output = match format {
+        Format::Json => {
+            let inventory = scan::scan(&options.roots, &options.config)?;
+            let json =
+                serde_json::to_string_pretty(&inventory).expect("failed to serialize inventory");
+            format!("{json}\n")
+        }
+        Format::Dts => dts::boundary_id_declaration(&scan::scan(&options.roots, &options.config)?),
+        Format::Tree => {
+            let inventory = scan::scan(&options.roots, &options.config)?;
+            let json = serde_json::to_string_pretty(&graph::boundary_trees(&inventory))
+                .expect("failed to serialize boundary trees");
+            format!("{json}\n")
+        }
+        Format::Dot => graph::boundary_dot_graph(&scan::scan(&options.roots, &options.config)?),
+        Format::UnguardedLazy => {
+            let report = lazy::scan_unguarded_lazy(&options.roots, &options.config)?;
+            return Ok((lazy::unguarded_lazy_report(&report), report.is_empty()));
+        }
+    };
+    Ok((output, true))
+}
+
+fn main() -> ExitCode {
+    let options = match parse_options(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("{message}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if let Command::Watch = options.command {
+        return match watch::watch(&options.roots, &options.config) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(error) => {
+                eprintln!("failed to scan: {error}");
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    let (output, success) = match render(&options) {
+        Ok(rendered) => rendered,
+        Err(error) => {
+            eprintln!("failed to scan: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match &options.out {
+        Some(path) => {
+            if let Err(error) = fs::write(path, output) {
+                eprintln!("failed to write {}: {error}", path.display());
+                return ExitCode::FAILURE;
+            }
+        }
+        None => print!("{output}"),
+    }
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
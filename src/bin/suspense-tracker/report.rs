@@ -0,0 +1,114 @@
+use std::collections::BTreeMap;
+
+use crate::scan::InventoryEntry;
+
+/// Column headers of the boundary tables
+const COLUMNS: [&str; 5] = ["Boundary", "Kind", "Fallback", "Component", "Location"];
+
+/// Renders a Markdown summary with one table of boundaries per directory
+pub fn markdown_report(inventory: &[InventoryEntry]) -> String {
+    let directories = group_by_directory(inventory);
+    let mut markdown = format!("# Suspense boundaries\n\n{}\n", summary(&directories));
+    for (directory, entries) in directories {
+        markdown.push_str(&format!("\n## {directory}\n\n"));
+        markdown.push_str(&format!("| {} |\n", COLUMNS.join(" | ")));
+        markdown.push_str(&format!("|{}\n", " --- |".repeat(COLUMNS.len())));
+        for entry in entries {
+            let cells: Vec<String> = row(entry)
+                .iter()
+                .map(|cell| match cell {
+                    Some(cell) => format!("`{}`", cell.replace('|', "\\|")),
+                    None => "-".to_string(),
+                })
+                .collect();
+            markdown.push_str(&format!("| {} |\n", cells.join(" | ")));
+        }
+    }
+    markdown
+}
+
+/// Renders a standalone HTML page with one table of boundaries per directory
+pub fn html_report(inventory: &[InventoryEntry]) -> String {
+    let directories = group_by_directory(inventory);
+    let mut html = String::from(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>Suspense boundaries</title>\n</head>\n<body>\n",
+    );
+    html.push_str(&format!(
+        "<h1>Suspense boundaries</h1>\n<p>{}</p>\n",
+        summary(&directories)
+    ));
+    for (directory, entries) in directories {
+        html.push_str(&format!(
+            "<h2>{}</h2>\n<table>\n<tr>",
+            escape_html(directory)
+        ));
+        for column in COLUMNS {
+            html.push_str(&format!("<th>{column}</th>"));
+        }
+        html.push_str("</tr>\n");
+        for entry in entries {
+            html.push_str("<tr>");
+            for cell in row(entry) {
+                match cell {
+                    Some(cell) => {
+                        html.push_str(&format!("<td><code>{}</code></td>", escape_html(&cell)))
+                    }
+                    None => html.push_str("<td>-</td>"),
+                }
+            }
+            html.push_str("</tr>\n");
+        }
+        html.push_str("</table>\n");
+    }
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+/// Groups the boundaries by the directory of their file (`.` for files in the root)
+fn group_by_directory(inventory: &[InventoryEntry]) -> BTreeMap<&str, Vec<&InventoryEntry>> {
+    let mut directories: BTreeMap<&str, Vec<&InventoryEntry>> = BTreeMap::new();
+    for entry in inventory {
+        let directory = entry
+            .file
+            .rsplit_once('/')
+            .map_or(".", |(directory, _)| directory);
+        directories.entry(directory).or_default().push(entry);
+    }
+    directories
+}
+
+/// Returns `3 boundaries in 2 directories`
+fn summary(directories: &BTreeMap<&str, Vec<&InventoryEntry>>) -> String {
+    let boundaries = directories.values().map(Vec::len).sum();
+    format!(
+        "{} in {}",
+        count(boundaries, "boundary", "boundaries"),
+        count(directories.len(), "directory", "directories")
+    )
+}
+
+fn count(count: usize, singular: &str, plural: &str) -> String {
+    format!("{count} {}", if count == 1 { singular } else { plural })
+}
+
+/// Returns the cells of a boundary in the order of [`COLUMNS`] (`None` if unknown)
+fn row(entry: &InventoryEntry) -> [Option<String>; 5] {
+    let boundary = &entry.boundary;
+    [
+        Some(format!("<{}>", boundary.component)),
+        Some(boundary.kind.as_str().to_string()),
+        boundary
+            .fallback
+            .as_ref()
+            .map(|fallback| format!("<{fallback}>")),
+        boundary.enclosing_component.clone(),
+        Some(format!("{}:{}", entry.file, boundary.line)),
+    ]
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
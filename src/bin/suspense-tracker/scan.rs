@@ -0,0 +1,194 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use react_swc_suspense_tracker::{analyze_source, BoundaryMetadata, Config, Context, Environment};
+use serde::Serialize;
+
+/// File extensions which are scanned for boundaries
+const SOURCE_EXTENSIONS: [&str; 6] = [".ts", ".tsx", ".js", ".jsx", ".mjs", ".cjs"];
+
+/// A boundary found while scanning a project
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InventoryEntry {
+    /// The file containing the boundary (relative to the scanned root)
+    pub file: String,
+    #[serde(flatten)]
+    pub boundary: BoundaryMetadata,
+}
+
+/// Scans all source files below `roots` and returns every boundary found
+pub fn scan(roots: &[PathBuf], config: &Config) -> io::Result<Vec<InventoryEntry>> {
+    let mut inventory = Vec::new();
+    visit_source_files(roots, |file, code| {
+        inventory.extend(analyze_file(file, code, config));
+    })?;
+    Ok(inventory)
+}
+
+/// Returns the boundaries of a single file, files which can't be parsed are skipped
+pub fn analyze_file(file: &str, code: &str, config: &Config) -> Vec<InventoryEntry> {
+    match analyze_source(code, config.clone(), development_context(file)) {
+        Ok(boundaries) => boundaries
+            .into_iter()
+            .map(|boundary| InventoryEntry {
+                file: file.to_string(),
+                boundary,
+            })
+            .collect(),
+        Err(error) => {
+            eprintln!("warning: skipping {file}: {error}");
+            Vec::new()
+        }
+    }
+}
+
+/// Calls `visit` with the relative filename and the code of every source file below `roots`,
+/// files which can't be read (e.g. not UTF-8) are skipped
+pub fn visit_source_files(roots: &[PathBuf], mut visit: impl FnMut(&str, &str)) -> io::Result<()> {
+    for (path, file) in source_files(roots)? {
+        let code = match fs::read_to_string(&path) {
+            Ok(code) => code,
+            Err(error) => {
+                eprintln!("warning: skipping {file}: {error}");
+                continue;
+            }
+        };
+        visit(&file, &code);
+    }
+    Ok(())
+}
+
+/// Returns the path and the relative filename of every source file below `roots`
+pub fn source_files(roots: &[PathBuf]) -> io::Result<Vec<(PathBuf, String)>> {
+    let mut source_files = Vec::new();
+    for root in roots {
+        let mut files = Vec::new();
+        if root.is_dir() {
+            collect_source_files(root, &mut files)?;
+        } else {
+            files.push(root.clone());
+        }
+        files.sort();
+
+        source_files.extend(files.into_iter().map(|path| {
+            let file = relative_filename(root, &path);
+            (path, file)
+        }));
+    }
+    Ok(source_files)
+}
+
+pub fn development_context(filename: &str) -> Context {
+    Context {
+        env_name: Environment::Development,
+        filename: filename.to_string(),
+    }
+}
+
+/// Recursively collects all source files, skipping `node_modules` and hidden directories
+fn collect_source_files(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or_default();
+
+        if path.is_dir() {
+            if !is_skipped_dir(name) {
+                collect_source_files(&path, files)?;
+            }
+        } else if is_source_file(name) {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+fn is_skipped_dir(name: &str) -> bool {
+    name == "node_modules" || name.starts_with('.')
+}
+
+fn is_source_file(name: &str) -> bool {
+    !name.ends_with(".d.ts")
+        && SOURCE_EXTENSIONS
+            .iter()
+            .any(|extension| name.ends_with(extension))
+}
+
+/// Returns the source files below `dir` which [`source_files`] lists for `roots`
+pub fn source_files_below(roots: &[PathBuf], dir: &Path) -> io::Result<Vec<(PathBuf, String)>> {
+    let Some(root) = scanned_root(roots, dir) else {
+        return Ok(Vec::new());
+    };
+    let mut files = Vec::new();
+    collect_source_files(dir, &mut files)?;
+    files.sort();
+    Ok(files
+        .into_iter()
+        .map(|path| {
+            let file = relative_filename(root, &path);
+            (path, file)
+        })
+        .collect())
+}
+
+/// Returns the relative filename of `path` if it is a source file [`source_files`] lists
+/// for `roots`, without checking that the file exists
+pub fn source_filename(roots: &[PathBuf], path: &Path) -> Option<String> {
+    if let Some(root) = roots.iter().find(|root| *root == path) {
+        return Some(relative_filename(root, path));
+    }
+    let name = path.file_name()?.to_str()?;
+    let root = scanned_root(roots, path.parent()?)?;
+    is_source_file(name).then(|| relative_filename(root, path))
+}
+
+/// Returns the directory of `roots` containing `dir` unless `dir` is (inside of) a skipped
+/// directory
+fn scanned_root<'a>(roots: &'a [PathBuf], dir: &Path) -> Option<&'a PathBuf> {
+    roots.iter().find(|root| {
+        root.is_dir()
+            && dir.strip_prefix(root).is_ok_and(|relative| {
+                relative
+                    .components()
+                    .all(|component| !is_skipped_dir(&component.as_os_str().to_string_lossy()))
+            })
+    })
+}
+
+/// Returns the path of `path` relative to `root` using `/` separators
+fn relative_filename(root: &Path, path: &Path) -> String {
+    let relative = if root.is_dir() {
+        path.strip_prefix(root).unwrap_or(path)
+    } else {
+        path
+    };
+    relative.to_string_lossy().replace('\\', "/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unreadable_files_are_skipped() {
+        let root =
+            std::env::temp_dir().join(format!("suspense-tracker-scan-{}", std::process::id()));
+        fs::create_dir_all(&root).unwrap();
+        fs::write(root.join("App.tsx"), "export const App = () => null;").unwrap();
+        fs::write(root.join("Latin1.tsx"), b"// Gr\xfc\xdfe\n").unwrap();
+
+        let mut files = Vec::new();
+        let result = visit_source_files(std::slice::from_ref(&root), |file, _| {
+            files.push(file.to_string())
+        });
+        fs::remove_dir_all(&root).unwrap();
+
+        result.unwrap();
+        assert_eq!(files, ["App.tsx"]);
+    }
+}
@@ -0,0 +1,346 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    fs, io,
+    path::{Path, PathBuf},
+    sync::mpsc,
+    time::Duration,
+};
+
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use react_swc_suspense_tracker::Config;
+
+use crate::{
+    diff::{diff_entries, Entry},
+    scan::{analyze_file, source_filename, source_files, source_files_below, InventoryEntry},
+};
+
+/// How long to wait for further events after a change, editors often save a file in
+/// several steps (write a temporary file, rename it, update the metadata)
+const DEBOUNCE: Duration = Duration::from_millis(100);
+
+/// The boundaries of every scanned file
+#[derive(Default)]
+struct Inventory {
+    files: HashMap<PathBuf, Vec<InventoryEntry>>,
+}
+
+impl Inventory {
+    /// Replaces the boundaries of the changed files (`None` for deleted files) and returns
+    /// the boundaries of these files before and after the change
+    fn update(
+        &mut self,
+        changed: Vec<(PathBuf, Option<Vec<InventoryEntry>>)>,
+    ) -> (Vec<InventoryEntry>, Vec<InventoryEntry>) {
+        let mut old = Vec::new();
+        let mut new = Vec::new();
+        for (path, boundaries) in changed {
+            let previous = match boundaries {
+                Some(boundaries) => {
+                    new.extend(boundaries.iter().cloned());
+                    self.files.insert(path, boundaries)
+                }
+                None => self.files.remove(&path),
+            };
+            old.extend(previous.into_iter().flatten());
+        }
+        (old, new)
+    }
+
+    /// Returns the scanned files at or below `path`, e.g. the files of a deleted directory
+    fn files_below(&self, path: &Path) -> Vec<PathBuf> {
+        let mut files: Vec<PathBuf> = self
+            .files
+            .keys()
+            .filter(|file| file.starts_with(path))
+            .cloned()
+            .collect();
+        files.sort();
+        files
+    }
+
+    fn boundaries(&self) -> usize {
+        self.files.values().map(Vec::len).sum()
+    }
+}
+
+/// Scans all source files below `roots` once and then only re-parses the files reported
+/// by file system events, printing the added, removed and moved boundaries
+pub fn watch(roots: &[PathBuf], config: &Config) -> io::Result<()> {
+    let (sender, receiver) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(sender).map_err(io::Error::other)?;
+    // Watched before the first scan so changes made while scanning aren't lost
+    for root in roots {
+        let result = if root.is_dir() {
+            watcher.watch(root, RecursiveMode::Recursive)
+        } else {
+            // Editors replace files on save, which ends a watch of the file itself
+            let parent = root
+                .parent()
+                .filter(|parent| !parent.as_os_str().is_empty());
+            watcher.watch(
+                parent.unwrap_or(Path::new(".")),
+                RecursiveMode::NonRecursive,
+            )
+        };
+        result.map_err(io::Error::other)?;
+    }
+    let canonical_roots = roots
+        .iter()
+        .map(|root| Ok((fs::canonicalize(root)?, root.clone())))
+        .collect::<io::Result<Vec<_>>>()?;
+
+    let mut inventory = Inventory::default();
+    let files = source_files(roots)?
+        .into_iter()
+        .map(|(path, file)| {
+            let boundaries = scan_file(&path, &file, config);
+            (path, boundaries)
+        })
+        .collect();
+    inventory.update(files);
+    println!(
+        "watching {} boundaries in {} files",
+        inventory.boundaries(),
+        inventory.files.len()
+    );
+
+    while let Ok(event) = receiver.recv() {
+        let mut paths = BTreeSet::new();
+        collect_paths(event, &canonical_roots, &mut paths);
+        while let Ok(event) = receiver.recv_timeout(DEBOUNCE) {
+            collect_paths(event, &canonical_roots, &mut paths);
+        }
+
+        let changed = changed_files(roots, &inventory, paths, config)?;
+        let (old, new) = inventory.update(changed);
+        let changes = diff_entries(
+            old.iter().map(Entry::from).collect(),
+            new.iter().map(Entry::from).collect(),
+        );
+        if !changes.is_empty() {
+            print!("{changes}");
+        }
+    }
+    Ok(())
+}
+
+/// Adds the paths of an event, relative to the root as given on the command line
+///
+/// Some platforms report canonical paths, which are mapped back so the paths match the
+/// ones of the initial scan.
+fn collect_paths(
+    event: notify::Result<Event>,
+    canonical_roots: &[(PathBuf, PathBuf)],
+    paths: &mut BTreeSet<PathBuf>,
+) {
+    let event = match event {
+        Ok(event) => event,
+        Err(error) => {
+            eprintln!("warning: {error}");
+            return;
+        }
+    };
+    if matches!(event.kind, EventKind::Access(_)) {
+        return;
+    }
+    paths.extend(event.paths.into_iter().map(|path| {
+        canonical_roots
+            .iter()
+            .find_map(|(canonical, root)| {
+                let relative = path.strip_prefix(canonical).ok()?;
+                Some(if relative.as_os_str().is_empty() {
+                    root.clone()
+                } else {
+                    root.join(relative)
+                })
+            })
+            .unwrap_or(path)
+    }));
+}
+
+/// Re-parses the source files of the changed paths
+///
+/// A directory (e.g. moved into a root) is scanned completely, a path which is no longer
+/// a source file removes the scanned files at or below it.
+fn changed_files(
+    roots: &[PathBuf],
+    inventory: &Inventory,
+    paths: BTreeSet<PathBuf>,
+    config: &Config,
+) -> io::Result<Vec<(PathBuf, Option<Vec<InventoryEntry>>)>> {
+    let mut changed = Vec::new();
+    for path in paths {
+        if path.is_dir() {
+            for (path, file) in source_files_below(roots, &path)? {
+                let boundaries = scan_file(&path, &file, config);
+                changed.push((path, boundaries));
+            }
+            continue;
+        }
+        match source_filename(roots, &path) {
+            Some(file) if path.is_file() => {
+                let boundaries = scan_file(&path, &file, config);
+                changed.push((path, boundaries));
+            }
+            _ => changed.extend(
+                inventory
+                    .files_below(&path)
+                    .into_iter()
+                    .map(|path| (path, None)),
+            ),
+        }
+    }
+    Ok(changed)
+}
+
+/// Returns the boundaries of a file, `None` if it was removed before it could be read
+fn scan_file(path: &Path, file: &str, config: &Config) -> Option<Vec<InventoryEntry>> {
+    let code = fs::read_to_string(path).ok()?;
+    Some(analyze_file(file, &code, config))
+}
+
+#[cfg(test)]
+mod tests {
+    use react_swc_suspense_tracker::{BoundaryKind, BoundaryMetadata};
+
+    use super::*;
+
+    fn boundary(file: &str, line: usize) -> InventoryEntry {
+        InventoryEntry {
+            file: file.to_string(),
+            boundary: BoundaryMetadata {
+                id: format!("{file}:{line}"),
+                kind: BoundaryKind::Suspense,
+                component: "Suspense".to_string(),
+                from: "react".to_string(),
+                line,
+                enclosing_component: Some("App".to_string()),
+                fallback: None,
+                parent: None,
+                location: None,
+            },
+        }
+    }
+
+    fn changes(
+        inventory: &mut Inventory,
+        changed: Vec<(PathBuf, Option<Vec<InventoryEntry>>)>,
+    ) -> (usize, usize, usize) {
+        let (old, new) = inventory.update(changed);
+        let changes = diff_entries(
+            old.iter().map(Entry::from).collect(),
+            new.iter().map(Entry::from).collect(),
+        );
+        (
+            changes.added.len(),
+            changes.removed.len(),
+            changes.moved.len(),
+        )
+    }
+
+    #[test]
+    fn only_changed_files_are_diffed() {
+        let app = PathBuf::from("src/App.tsx");
+        let cart = PathBuf::from("src/cart/Cart.tsx");
+        let mut inventory = Inventory::default();
+        assert_eq!(
+            changes(
+                &mut inventory,
+                vec![
+                    (app.clone(), Some(vec![boundary("App.tsx", 4)])),
+                    (cart.clone(), Some(vec![boundary("cart/Cart.tsx", 8)])),
+                ]
+            ),
+            (2, 0, 0)
+        );
+
+        // Lines inserted above the boundary, the unchanged file isn't part of the diff
+        assert_eq!(
+            changes(
+                &mut inventory,
+                vec![(app.clone(), Some(vec![boundary("App.tsx", 6)]))]
+            ),
+            (0, 0, 1)
+        );
+        // Saving a file without changing its boundaries
+        assert_eq!(
+            changes(
+                &mut inventory,
+                vec![(app.clone(), Some(vec![boundary("App.tsx", 6)]))]
+            ),
+            (0, 0, 0)
+        );
+        assert_eq!(
+            changes(
+                &mut inventory,
+                vec![(
+                    app.clone(),
+                    Some(vec![boundary("App.tsx", 6), boundary("App.tsx", 20)])
+                )]
+            ),
+            (1, 0, 0)
+        );
+        assert_eq!(changes(&mut inventory, vec![(cart, None)]), (0, 1, 0));
+        assert_eq!(inventory.boundaries(), 2);
+        // Files which were never scanned
+        assert_eq!(
+            changes(&mut inventory, vec![(PathBuf::from("src/Gone.tsx"), None)]),
+            (0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn deleted_directories_remove_their_files() {
+        let mut inventory = Inventory::default();
+        inventory.update(vec![
+            (
+                PathBuf::from("src/App.tsx"),
+                Some(vec![boundary("App.tsx", 4)]),
+            ),
+            (
+                PathBuf::from("src/cart/Cart.tsx"),
+                Some(vec![boundary("cart/Cart.tsx", 8)]),
+            ),
+            (
+                PathBuf::from("src/cart/Summary.tsx"),
+                Some(vec![boundary("cart/Summary.tsx", 3)]),
+            ),
+            (
+                PathBuf::from("src/cartography/Map.tsx"),
+                Some(vec![boundary("cartography/Map.tsx", 5)]),
+            ),
+        ]);
+        let removed = inventory.files_below(Path::new("src/cart"));
+        assert_eq!(
+            removed,
+            [
+                PathBuf::from("src/cart/Cart.tsx"),
+                PathBuf::from("src/cart/Summary.tsx")
+            ]
+        );
+        assert_eq!(
+            changes(
+                &mut inventory,
+                removed.into_iter().map(|path| (path, None)).collect()
+            ),
+            (0, 2, 0)
+        );
+        assert_eq!(inventory.files.len(), 2);
+    }
+
+    #[test]
+    fn event_paths_are_filtered_like_the_scan() {
+        let root = env!("CARGO_MANIFEST_DIR");
+        let roots = [PathBuf::from(root).join("src")];
+        let filename = |path: &str| source_filename(&roots, &Path::new(root).join(path));
+        assert_eq!(filename("src/lib.rs"), None);
+        assert_eq!(
+            filename("src/pages/Checkout.tsx").as_deref(),
+            Some("pages/Checkout.tsx")
+        );
+        assert_eq!(filename("src/types.d.ts"), None);
+        assert_eq!(filename("src/node_modules/react/index.js"), None);
+        assert_eq!(filename("src/.cache/App.tsx"), None);
+        assert_eq!(filename("tests/App.tsx"), None);
+    }
+}
@@ -0,0 +1,193 @@
+use std::collections::{HashMap, HashSet};
+use swc_core::common::{comments::Comments, sync::Lrc, SourceMapper};
+
+use crate::{
+    lines::LineCache,
+    normalize_filename,
+    routes::{default_route_conventions, route_hint},
+    Boundary, BoundaryKind, Config, Context, Environment, Mode, TransformVisitor,
+};
+
+/// Builder for [`TransformVisitor`]
+///
+/// ```
+/// use react_swc_suspense_tracker::{Environment, TransformVisitor};
+/// use swc_core::{
+///     common::{util::take::Take, GLOBALS},
+///     ecma::{ast::{Module, Program}, visit::visit_mut_pass},
+/// };
+///
+/// let visitor = TransformVisitor::builder()
+///     .error_boundary("ErrorBoundary", "react-error-boundary")
+///     .env(Environment::Development)
+///     .filename("src/App.tsx")
+///     .build();
+/// let program = Program::Module(Module::dummy());
+/// GLOBALS.set(&Default::default(), || program.apply(visit_mut_pass(visitor)));
+/// ```
+#[derive(Default)]
+pub struct TransformVisitorBuilder {
+    config: Config,
+    env_name: Option<Environment>,
+    filename: Option<String>,
+    source_map: Option<Lrc<dyn SourceMapper>>,
+    source_text: Option<String>,
+    comments: Option<Box<dyn Comments>>,
+}
+
+impl TransformVisitorBuilder {
+    /// Replaces the whole plugin configuration
+    pub fn config(mut self, config: Config) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Uses the environment and filename of an existing [`Context`]
+    pub fn context(self, context: Context) -> Self {
+        self.env(context.env_name).filename(context.filename)
+    }
+
+    /// Adds a (Suspense) boundary component which should be tracked
+    pub fn boundary(self, component: impl Into<String>, from: impl Into<String>) -> Self {
+        self.add_boundary(component.into(), from.into(), None)
+    }
+
+    /// Adds an error boundary component which should be tracked
+    pub fn error_boundary(self, component: impl Into<String>, from: impl Into<String>) -> Self {
+        self.add_boundary(component.into(), from.into(), Some(BoundaryKind::Error))
+    }
+
+    fn add_boundary(mut self, component: String, from: String, kind: Option<BoundaryKind>) -> Self {
+        self.config.boundaries.insert(Boundary {
+            component,
+            from,
+            kind,
+            prop_map: None,
+            id_strategy: None,
+            id_prefix: None,
+        });
+        self
+    }
+
+    /// Explicitly enables or disables the transformation
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.config.enabled = Some(enabled);
+        self
+    }
+
+    /// Sets how boundaries are processed
+    pub fn mode(mut self, mode: Mode) -> Self {
+        self.config.mode = mode;
+        self
+    }
+
+    /// Sets the target environment (defaults to [`Environment::Development`])
+    pub fn env(mut self, env_name: Environment) -> Self {
+        self.env_name = Some(env_name);
+        self
+    }
+
+    /// Sets the name of the file which is transformed
+    pub fn filename(mut self, filename: impl Into<String>) -> Self {
+        self.filename = Some(filename.into());
+        self
+    }
+
+    /// Sets the source map used to resolve line numbers
+    pub fn source_map(mut self, source_map: Lrc<dyn SourceMapper>) -> Self {
+        self.source_map = Some(source_map);
+        self
+    }
+
+    /// Sets the raw source text of the module to resolve line numbers without a source map
+    pub fn source_text(mut self, source_text: impl Into<String>) -> Self {
+        self.source_text = Some(source_text.into());
+        self
+    }
+
+    /// Sets the comments used to emit comment based output (e.g. in analyze mode)
+    pub fn comments(mut self, comments: impl Comments + 'static) -> Self {
+        self.comments = Some(Box::new(comments));
+        self
+    }
+
+    /// Creates the [`TransformVisitor`]
+    pub fn build(mut self) -> TransformVisitor {
+        let filename = self.filename.unwrap_or_default();
+        let normalized_filename = normalize_filename(&filename);
+        // Resolve the effective config of the file once
+        self.config.apply_overrides(&normalized_filename);
+
+        let mut boundary_contexts = HashSet::new();
+
+        // Always add Suspense from "react" as a default boundary
+        // unless it is configured explicitly (e.g. with its own ID strategy) or opted out
+        let configures_suspense = self
+            .config
+            .boundaries
+            .iter()
+            .any(|boundary| boundary.component == "Suspense" && boundary.from == "react");
+        let includes_default_suspense = self.config.include_default_suspense.unwrap_or(true);
+        if includes_default_suspense && !configures_suspense {
+            boundary_contexts.insert(Boundary {
+                component: "Suspense".to_string(),
+                from: "react".to_string(),
+                kind: Some(BoundaryKind::Suspense),
+                prop_map: None,
+                id_strategy: None,
+                id_prefix: None,
+            });
+        }
+
+        // Add user-configured boundaries
+        for boundary_config in self.config.boundaries.iter() {
+            boundary_contexts.insert(boundary_config.clone());
+        }
+
+        let route_hint = if self.config.emit_route_hint {
+            let conventions = self
+                .config
+                .route_conventions
+                .clone()
+                .unwrap_or_else(default_route_conventions);
+            route_hint(&normalized_filename, &conventions)
+        } else {
+            None
+        };
+        TransformVisitor {
+            config: self.config,
+            normalized_filename,
+            route_hint,
+            context: Context {
+                env_name: self.env_name.unwrap_or(Environment::Development),
+                filename,
+            },
+            boundary_contexts,
+            valid_boundary_idents: HashMap::new(),
+            boundary_imports_added: false,
+            has_boundary_elements: false,
+            imports: HashMap::new(),
+            runtime_imports: Vec::new(),
+            binding_names: HashSet::new(),
+            source_map: self.source_map,
+            source_text: self.source_text,
+            line_cache: LineCache::default(),
+            comments: self.comments,
+            boundaries: Vec::new(),
+            boundary_id_counts: HashMap::new(),
+            chunk_name_counts: HashMap::new(),
+            numeric_id_count: 0,
+            hoisted_ids: Vec::new(),
+            component_stack: Vec::new(),
+            transition_bindings: HashSet::new(),
+            jsx_binding: None,
+            shadowed_boundaries: Vec::new(),
+            diagnostics: Vec::new(),
+            fallback_stack: Vec::new(),
+            boundary_ids: Vec::new(),
+            coverage: Default::default(),
+            boundary_depth: 0,
+            loop_keys: Vec::new(),
+        }
+    }
+}
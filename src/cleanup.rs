@@ -0,0 +1,200 @@
+use std::collections::{HashMap, HashSet};
+
+use swc_core::{
+    common::{util::take::Take, DUMMY_SP},
+    ecma::{
+        ast::*,
+        visit::{VisitMut, VisitMutWith},
+    },
+};
+
+use crate::{
+    imports::{collect_import_bindings, remove_unused_imports, ImportBinding},
+    TransformVisitor, BOUNDARY_TRACKER_PACKAGE_NAME, ERROR_BOUNDARY_TRACKER_PACKAGE_NAME,
+    NATIVE_BOUNDARY_TRACKER_PACKAGE_NAME,
+};
+
+/// The package of the public runtime API (`useBoundaryStack`, ...)
+const RUNTIME_PACKAGE_NAME: &str = "react-swc-suspense-tracker";
+
+/// Runtime functions and the values they return without any boundary tracking
+const INERT_RUNTIME_CALLS: [(&str, InertValue); 13] = [
+    ("useBoundaryStack", InertValue::EmptyArray),
+    ("useSuspenseOwner", InertValue::Null),
+    ("useThrowIfSuspenseMissing", InertValue::Undefined),
+    ("wrapSuspendableHook", InertValue::FirstArgument),
+    ("getRegisteredBoundaries", InertValue::EmptyArray),
+    ("getLazyInfo", InertValue::Undefined),
+    ("getPromiseCallSite", InertValue::Undefined),
+    ("getSuspenders", InertValue::EmptyArray),
+    ("getLastTransition", InertValue::Null),
+    ("getRecentDeferredValues", InertValue::EmptyArray),
+    ("getBoundaryRenderTimings", InertValue::EmptyArray),
+    ("getFallbackTimings", InertValue::EmptyArray),
+    ("getContentMountTimings", InertValue::EmptyArray),
+];
+
+/// A side effect free replacement of a runtime call
+#[derive(Clone, Copy, PartialEq)]
+enum InertValue {
+    Null,
+    Undefined,
+    EmptyArray,
+    /// `wrapSuspendableHook(hook, onSuspense)` returns `hook` itself
+    FirstArgument,
+}
+
+impl TransformVisitor {
+    /// Removes the runtime from modules of disabled environments:
+    /// runtime calls are replaced with inert values and unused tracker imports are dropped
+    pub(crate) fn clean_disabled_module(&mut self, module_items: &mut Vec<ModuleItem>) {
+        // Trackers of custom entry points (`entryPoints`) are dropped as well
+        let tracker_sources = self.tracker_sources();
+        let runtime_imports: HashMap<Id, ImportBinding> = module_items
+            .iter()
+            .filter_map(|item| item.as_module_decl()?.as_import())
+            .flat_map(collect_import_bindings)
+            .filter(|(_, binding)| {
+                is_runtime_package(&binding.src) || tracker_sources.contains(&binding.src)
+            })
+            .collect();
+        if runtime_imports.is_empty() {
+            return;
+        }
+
+        module_items.visit_mut_children_with(&mut InertCallRewriter {
+            imports: &runtime_imports,
+        });
+
+        let candidates: HashSet<Id> = runtime_imports.into_keys().collect();
+        remove_unused_imports(module_items, &candidates);
+    }
+}
+
+/// Checks if a module is part of the tracker runtime
+fn is_runtime_package(src: &str) -> bool {
+    src == RUNTIME_PACKAGE_NAME
+        || src == BOUNDARY_TRACKER_PACKAGE_NAME
+        || src == ERROR_BOUNDARY_TRACKER_PACKAGE_NAME
+        || src == NATIVE_BOUNDARY_TRACKER_PACKAGE_NAME
+}
+
+/// Replaces calls of the runtime with their inert values
+struct InertCallRewriter<'a> {
+    imports: &'a HashMap<Id, ImportBinding>,
+}
+
+impl InertCallRewriter<'_> {
+    /// Returns the inert value of a `useBoundaryStack()` or `Tracker.useBoundaryStack()` call
+    ///
+    /// Calls with spread arguments and `wrapSuspendableHook` calls with further arguments
+    /// which might have side effects are kept
+    fn inert_value(&self, call: &CallExpr) -> Option<InertValue> {
+        let Callee::Expr(callee) = &call.callee else {
+            return None;
+        };
+        let name = match &**callee {
+            Expr::Ident(ident) => {
+                let binding = self.imports.get(&ident.to_id())?;
+                (binding.src == RUNTIME_PACKAGE_NAME).then_some(binding.imported.as_str())?
+            }
+            Expr::Member(MemberExpr {
+                obj,
+                prop: MemberProp::Ident(prop),
+                ..
+            }) => {
+                let binding = self.imports.get(&obj.as_ident()?.to_id())?;
+                binding
+                    .is_module_object(RUNTIME_PACKAGE_NAME)
+                    .then_some(&*prop.sym)?
+            }
+            _ => return None,
+        };
+        let (_, value) = INERT_RUNTIME_CALLS
+            .iter()
+            .find(|(runtime_name, _)| *runtime_name == name)?;
+
+        if call.args.iter().any(|arg| arg.spread.is_some()) {
+            return None;
+        }
+        if *value == InertValue::FirstArgument
+            && (call.args.is_empty() || side_effect_args(&call.args[1..]).next().is_some())
+        {
+            return None;
+        }
+        Some(*value)
+    }
+}
+
+impl VisitMut for InertCallRewriter<'_> {
+    fn visit_mut_expr(&mut self, expr: &mut Expr) {
+        expr.visit_mut_children_with(self);
+
+        let Expr::Call(call) = expr else {
+            return;
+        };
+        let inert = match self.inert_value(call) {
+            None => return,
+            Some(InertValue::Null) => Expr::Lit(Lit::Null(Null { span: DUMMY_SP })),
+            Some(InertValue::Undefined) => Expr::Unary(UnaryExpr {
+                span: DUMMY_SP,
+                op: UnaryOp::Void,
+                arg: Box::new(Expr::Lit(Lit::Num(Number {
+                    span: DUMMY_SP,
+                    value: 0.0,
+                    raw: None,
+                }))),
+            }),
+            Some(InertValue::EmptyArray) => Expr::Array(ArrayLit {
+                span: DUMMY_SP,
+                elems: Vec::new(),
+            }),
+            Some(InertValue::FirstArgument) => {
+                *expr = call.args[0].expr.as_mut().take();
+                return;
+            }
+        };
+        // Arguments with side effects are still evaluated, e.g.
+        // `useThrowIfSuspenseMissing(skipCheck())` becomes `(skipCheck(), void 0)`
+        let mut exprs: Vec<Box<Expr>> = side_effect_args(&call.args).cloned().collect();
+        *expr = if exprs.is_empty() {
+            inert
+        } else {
+            exprs.push(Box::new(inert));
+            Expr::Seq(SeqExpr {
+                span: DUMMY_SP,
+                exprs,
+            })
+        };
+    }
+
+    fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
+        // `useThrowIfSuspenseMissing();` has no effect without tracking
+        stmts.retain(|stmt| match stmt {
+            Stmt::Expr(ExprStmt { expr, .. }) => !matches!(
+                &**expr,
+                Expr::Call(call) if side_effect_args(&call.args).next().is_none() && self
+                    .inert_value(call)
+                    .is_some_and(|value| value != InertValue::FirstArgument)
+            ),
+            _ => true,
+        });
+
+        stmts.visit_mut_children_with(self);
+    }
+}
+
+/// Returns the arguments whose evaluation might have side effects
+fn side_effect_args(args: &[ExprOrSpread]) -> impl Iterator<Item = &Box<Expr>> {
+    args.iter()
+        .map(|arg| &arg.expr)
+        .filter(|expr| !is_side_effect_free(expr))
+}
+
+/// Checks if evaluating an expression can't have side effects
+fn is_side_effect_free(expr: &Expr) -> bool {
+    matches!(
+        expr,
+        Expr::Lit(_) | Expr::Ident(_) | Expr::Arrow(_) | Expr::Fn(_)
+    )
+}
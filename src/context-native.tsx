@@ -0,0 +1,9 @@
+/**
+ * Runtime imported instead of `/context` if the SWC plugin runs with
+ * `platform: "react-native"`.
+ *
+ * The tracker components only rely on React and `performance.now()`, so they are
+ * shared with the web runtime. Native specific replacements belong in this entry
+ * to keep the web runtime free to use DOM APIs.
+ */
+export * from "./context";
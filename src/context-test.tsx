@@ -0,0 +1,63 @@
+import { useCallback } from "react";
+import { BoundaryTrackerSWC as ContextBoundaryTrackerSWC } from "./context";
+
+export * from "./context";
+
+/** Boundaries whose fallback is currently shown */
+const suspendedBoundaries = new Set<string>();
+/** Boundary ids in the order their fallback was shown */
+const suspenseHistory: string[] = [];
+
+const recordSuspend = (boundaryId: string) => {
+  suspendedBoundaries.add(boundaryId);
+  suspenseHistory.push(boundaryId);
+};
+
+const recordResolve = (boundaryId: string) => {
+  suspendedBoundaries.delete(boundaryId);
+};
+
+/**
+ * Internal component imported instead of the `/context` tracker if the SWC plugin runs
+ * with `testMode: true` and `testEntry: true` - records which boundaries suspended.
+ */
+export const BoundaryTrackerSWC = ({
+  onSuspend,
+  onResolve,
+  ...props
+}: React.ComponentProps<typeof ContextBoundaryTrackerSWC>) => {
+  const handleSuspend = useCallback(
+    (boundaryId: string) => {
+      recordSuspend(boundaryId);
+      onSuspend?.(boundaryId);
+    },
+    [onSuspend],
+  );
+  const handleResolve = useCallback(
+    (boundaryId: string) => {
+      recordResolve(boundaryId);
+      onResolve?.(boundaryId);
+    },
+    [onResolve],
+  );
+  return (
+    <ContextBoundaryTrackerSWC
+      {...props}
+      onSuspend={handleSuspend}
+      onResolve={handleResolve}
+    />
+  );
+};
+
+/** Returns the ids of all boundaries whose fallback is currently shown */
+export const getSuspendedBoundaries = (): string[] =>
+  Array.from(suspendedBoundaries);
+
+/** Returns the ids of all boundaries which showed their fallback, in order */
+export const getSuspenseHistory = (): string[] => [...suspenseHistory];
+
+/** Forgets all recorded suspensions, e.g. in `afterEach` */
+export const resetSuspenseTracking = (): void => {
+  suspendedBoundaries.clear();
+  suspenseHistory.length = 0;
+};
@@ -1,28 +1,349 @@
-import { Suspense, use, useMemo } from "react";
-import { SuspenseContext, type BoundaryInfo } from "./internal";
+import { Suspense, useContext, useLayoutEffect } from "react";
+import {
+  SuspenseContext,
+  boundaryRegistry,
+  chunkRegistry,
+  contentMountRegistry,
+  deferredValueRegistry,
+  fallbackRegistry,
+  lazyRegistry,
+  promiseRegistry,
+  renderTimingRegistry,
+  suspenderRegistry,
+  transitionState,
+  resolveBoundaryMeta,
+  useBoundaryTracking,
+  type RegisteredBoundary,
+  type TrackerProps,
+} from "./internal";
 
-interface BoundaryTrackerProps extends React.ComponentProps<typeof Suspense> {
-  boundaryId: string;
-  boundary: React.ComponentType<any>;
+interface BoundaryTrackerProps
+  extends React.ComponentProps<typeof Suspense>,
+    Omit<TrackerProps, "boundary"> {
+  /**
+   * The boundary component or its name if the SWC plugin runs with `boundaryRef: "string"`
+   * (`Suspense` if the SWC plugin runs with `emitStyle: "id"`)
+   */
+  boundary?: TrackerProps["boundary"] | string;
+  /** Called with the boundary id once the fallback is shown */
+  onSuspend?: (boundaryId: string) => void;
+  /** Called with the boundary id once the fallback is replaced */
+  onResolve?: (boundaryId: string) => void;
 }
 
+/** Calls `onSuspend` when the fallback mounts and `onResolve` when it unmounts */
+const SuspendNotifier = ({
+  boundaryId,
+  onSuspend,
+  onResolve,
+  children,
+}: {
+  boundaryId: string;
+  onSuspend?: (boundaryId: string) => void;
+  onResolve?: (boundaryId: string) => void;
+  children?: React.ReactNode;
+}) => {
+  useLayoutEffect(() => {
+    onSuspend?.(boundaryId);
+    return () => onResolve?.(boundaryId);
+  }, [boundaryId, onSuspend, onResolve]);
+  return <>{children}</>;
+};
+
+/** Boundaries passed by name if the SWC plugin runs with `boundaryRef: "string"` */
+const namedBoundaries: Record<string, React.ComponentType<any>> = {
+  "react:Suspense": Suspense,
+};
+
 /**
  * Internal component that replaces boundary components via SWC plugin transformation.
  * Provides tracking context while maintaining all original boundary functionality.
  */
-export const BoundaryTrackerSWC = ({
-  boundaryId,
-  boundary: Boundary,
-  ...boundaryProps
-}: BoundaryTrackerProps) => {
-  const parentContext = use(SuspenseContext);
-  const boundaries = useMemo<BoundaryInfo[]>(
-    () => [[boundaryId, Boundary], ...parentContext],
-    [parentContext, boundaryId, Boundary],
+export const BoundaryTrackerSWC = (props: BoundaryTrackerProps) => {
+  const {
+    boundaryId,
+    boundary,
+    boundaryPropMap,
+    debugName,
+    ownerStack,
+    routeHint,
+    originalName,
+    depth,
+    otelAttributes,
+    __boundarySource,
+    onSuspend,
+    onResolve,
+    ...boundaryProps
+  } = resolveBoundaryMeta(props);
+  const Boundary =
+    typeof boundary === "string"
+      ? namedBoundaries[boundary]
+      : (boundary ?? Suspense);
+  const boundaries = useBoundaryTracking(
+    {
+      boundaryId,
+      boundary: Boundary,
+      boundaryPropMap,
+      debugName,
+      ownerStack,
+      routeHint,
+      originalName,
+      depth,
+      otelAttributes,
+      __boundarySource,
+    },
+    boundaryProps,
   );
+  if (onSuspend || onResolve) {
+    boundaryProps.fallback = (
+      <SuspendNotifier
+        boundaryId={boundaryId}
+        onSuspend={onSuspend}
+        onResolve={onResolve}
+      >
+        {boundaryProps.fallback}
+      </SuspendNotifier>
+    );
+  }
   return (
     <SuspenseContext.Provider value={boundaries}>
       <Boundary {...boundaryProps} />
     </SuspenseContext.Provider>
   );
 };
+
+/**
+ * Internal function called by modules transformed with `emitRegistry: true`
+ * to announce their boundaries before they render.
+ */
+export const registerBoundaries = (boundaries: RegisteredBoundary[]): void => {
+  for (const boundary of boundaries) {
+    boundaryRegistry.set(boundary.id, boundary);
+  }
+};
+
+/**
+ * Internal function wrapping `lazy()` calls if the SWC plugin runs with `trackLazy: true`
+ * to remember where the lazy component was created and which module it loads.
+ */
+export const trackLazy = <T extends object>(
+  lazyComponent: T,
+  lazyId: string,
+  importPath?: string,
+): T => {
+  lazyRegistry.set(lazyComponent, { lazyId, importPath });
+  return lazyComponent;
+};
+
+/**
+ * Internal function wrapping the `import()` of lazy component factories if the SWC plugin
+ * runs with `trackChunks: true` to measure how long the chunk took to load.
+ */
+export const __trackChunk = <T,>(
+  chunk: Promise<T>,
+  lazyId: string,
+  importPath?: string,
+): Promise<T> => {
+  const timing = chunkRegistry.get(lazyId) ?? {
+    lazyId,
+    importPath,
+    loadCount: 0,
+    lastDuration: null,
+    failed: false,
+  };
+  timing.loadCount++;
+  timing.lastDuration = null;
+  chunkRegistry.set(lazyId, timing);
+  const startedAt = performance.now();
+  const settle = (failed: boolean) => () => {
+    timing.lastDuration = performance.now() - startedAt;
+    timing.failed = failed;
+  };
+  chunk.then(settle(false), settle(true));
+  return chunk;
+};
+
+/**
+ * Internal function wrapping `use()` arguments if the SWC plugin runs with `trackUse: true`.
+ * Non-promise values (e.g. contexts) are passed through untouched.
+ */
+export const __trackPromise = <T,>(value: T, callSiteId: string): T => {
+  if (value && typeof value === "object" && "then" in value) {
+    promiseRegistry.set(value, callSiteId);
+  }
+  return value;
+};
+
+/**
+ * Internal function wrapping suspense-mode SWR hook calls if the SWC plugin runs with
+ * `trackSwr: true`. The promise thrown by the hook is remembered before it is rethrown.
+ */
+export const __trackSuspendingHook = <T,>(hook: () => T, callSiteId: string): T => {
+  try {
+    return hook();
+  } catch (thrown) {
+    throw __trackPromise(thrown, callSiteId);
+  }
+};
+
+/**
+ * Internal hook injected into components calling suspending APIs
+ * if the SWC plugin runs with `trackSuspenders: true`.
+ */
+export const useSuspenseTrackerSWC = (
+  componentName: string,
+  componentId: string,
+): void => {
+  const boundaries = useContext(SuspenseContext);
+  suspenderRegistry.set(componentId, {
+    componentName,
+    componentId,
+    boundaryIds: boundaries.map(([boundaryId]) => boundaryId),
+  });
+};
+
+/**
+ * Internal function wrapping `startTransition` callbacks if the SWC plugin runs with
+ * `trackTransitions: true` to remember which transition caused the following renders.
+ */
+export const __trackTransition = <T extends (...args: any[]) => any>(
+  callback: T,
+  transitionId: string,
+): T =>
+  ((...args: Parameters<T>) => {
+    transitionState.last = { transitionId, startTime: performance.now() };
+    return callback(...args);
+  }) as T;
+
+/**
+ * Internal function wrapping `useDeferredValue` calls if the SWC plugin runs with
+ * `trackDeferredValue: true` to remember which deferred values rendered recently.
+ */
+export const __trackDeferredValue = <T,>(deferredValue: T, callSiteId: string): T => {
+  deferredValueRegistry.set(callSiteId, performance.now());
+  return deferredValue;
+};
+
+/**
+ * Internal `<Profiler>` wrapped around boundary children if the SWC plugin runs with
+ * `injectProfiler: true`
+ */
+export { Profiler as BoundaryProfilerSWC } from "react";
+
+/**
+ * Internal `onRender` callback of the injected `<Profiler>`
+ */
+export const __onBoundaryRender: React.ProfilerOnRenderCallback = (
+  boundaryId,
+  phase,
+  actualDuration,
+  baseDuration,
+  startTime,
+  commitTime,
+) => {
+  const commitCount = (renderTimingRegistry.get(boundaryId)?.commitCount ?? 0) + 1;
+  renderTimingRegistry.set(boundaryId, {
+    boundaryId,
+    phase,
+    actualDuration,
+    baseDuration,
+    startTime,
+    commitTime,
+    commitCount,
+  });
+};
+
+/**
+ * Internal component wrapped around boundary fallbacks if the SWC plugin runs with
+ * `trackFallback: true` to measure how long fallbacks are displayed.
+ */
+export const FallbackTrackerSWC = ({
+  id,
+  children,
+}: {
+  id: string;
+  children?: React.ReactNode;
+}) => {
+  useLayoutEffect(() => {
+    const timing = fallbackRegistry.get(id) ?? {
+      boundaryId: id,
+      showCount: 0,
+      visibleSince: null,
+      lastShownAt: null,
+      totalDuration: 0,
+    };
+    const visibleSince = performance.now();
+    fallbackRegistry.set(id, {
+      ...timing,
+      showCount: timing.showCount + 1,
+      visibleSince,
+      lastShownAt: visibleSince,
+    });
+    return () => {
+      const current = fallbackRegistry.get(id) ?? timing;
+      fallbackRegistry.set(id, {
+        ...current,
+        visibleSince: null,
+        totalDuration:
+          current.totalDuration + performance.now() - visibleSince,
+      });
+    };
+  }, [id]);
+  return <>{children}</>;
+};
+
+/**
+ * Internal function wrapped around boundary fallbacks if the SWC plugin runs with
+ * `mode: "inline"` - tracks the fallback without replacing the boundary component.
+ */
+export const __trackFallback = (
+  id: string,
+  fallback: React.ReactNode,
+): React.ReactNode => (
+  <FallbackTrackerSWC id={id}>{fallback}</FallbackTrackerSWC>
+);
+
+/**
+ * Internal component wrapped around boundary children if the SWC plugin runs with
+ * `mode: "provider"` - adds the boundary to the boundary context of its descendants
+ * without replacing the boundary component.
+ */
+export const BoundaryIdProvider = ({
+  value,
+  boundary,
+  children,
+}: {
+  value: string;
+  boundary: React.ComponentType<any>;
+  children?: React.ReactNode;
+}) => {
+  const boundaries = useBoundaryTracking({ boundaryId: value, boundary }, {});
+  return (
+    <SuspenseContext.Provider value={boundaries}>
+      {children}
+    </SuspenseContext.Provider>
+  );
+};
+
+/**
+ * Internal component wrapped around boundary children if the SWC plugin runs with
+ * `trackContentMount: true` to measure the time until the content replaced the fallback.
+ */
+export const ContentTrackerSWC = ({
+  id,
+  children,
+}: {
+  id: string;
+  children?: React.ReactNode;
+}) => {
+  useLayoutEffect(() => {
+    const mountedAt = performance.now();
+    const lastShownAt = fallbackRegistry.get(id)?.lastShownAt ?? null;
+    contentMountRegistry.set(id, {
+      boundaryId: id,
+      mountedAt,
+      timeToContent: lastShownAt === null ? null : mountedAt - lastShownAt,
+    });
+  }, [id]);
+  return <>{children}</>;
+};
@@ -0,0 +1,531 @@
+//! Opt-in lint rules which are reported as swc warnings or errors
+
+use std::collections::{HashMap, HashSet};
+
+use serde::Serialize;
+use swc_core::{
+    common::{errors::HANDLER, EqIgnoreSpan, Span},
+    ecma::{ast::*, visit::VisitWith},
+};
+
+use crate::{
+    glob::{glob_match, path_matches},
+    suspenders::{is_component_name, SuspendingCallFinder},
+    Boundary, BoundaryKind, BoundaryMetadata, RuleSeverity, TransformVisitor,
+};
+
+/// Suspense boundaries without a `fallback` prop
+pub const MISSING_FALLBACK: &str = "missing-fallback";
+/// Suspense boundaries nested in a boundary with the same fallback
+pub const NESTED_DUPLICATE_FALLBACK: &str = "nested-duplicate-fallback";
+/// Boundaries of a file which would receive the same ID (e.g. in minified code)
+pub const DUPLICATE_BOUNDARY_ID: &str = "duplicate-boundary-id";
+/// Boundaries matching a `forbid` entry of the config
+pub const FORBIDDEN_BOUNDARY: &str = "forbidden-boundary";
+/// Files with more boundaries than `maxBoundariesPerFile` or a matching `boundaryBudgets` entry
+pub const BOUNDARY_BUDGET: &str = "boundary-budget";
+/// Components calling a suspending API which are not rendered inside a boundary of the file
+pub const UNWRAPPED_SUSPENDER: &str = "unwrapped-suspender";
+/// Boundaries rendered in an array callback without a `key` which share one ID at runtime
+pub const UNKEYED_LOOP_BOUNDARY: &str = "unkeyed-loop-boundary";
+/// Lazy components which are rendered outside of the boundaries of their module
+pub const UNGUARDED_LAZY: &str = "unguarded-lazy";
+/// Components or hooks which suspend by throwing a promise instead of calling `use()`
+pub const THROWN_PROMISE: &str = "thrown-promise";
+/// Modules for which the plugin host did not provide the environment or filename
+pub const MISSING_METADATA: &str = "missing-metadata";
+
+/// Prefix of the note listing the boundaries of a module (only with `emit_boundary_note`)
+pub const BOUNDARY_NOTE_PREFIX: &str = "[suspense-boundaries]";
+
+/// The JSON payload of the boundary note
+#[derive(Serialize)]
+struct BoundaryNote<'a> {
+    file: &'a str,
+    boundaries: &'a [BoundaryMetadata],
+}
+
+/// How severe a reported diagnostic is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// Reported without failing the build
+    Warning,
+    /// Fails the build
+    Error,
+}
+
+/// A problem found by a diagnostics rule
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Diagnostic {
+    /// The name of the rule (e.g. `missing-fallback`)
+    pub rule: &'static str,
+    /// How severe the problem is
+    pub severity: Severity,
+    /// Human readable description of the problem
+    pub message: String,
+    /// The line of the reported node (0 if unknown)
+    pub line: usize,
+    /// The reported node
+    #[serde(skip)]
+    pub span: Span,
+}
+
+/// Which components suspend and where they are rendered (for [`UNWRAPPED_SUSPENDER`]
+/// and [`UNGUARDED_LAZY`])
+#[derive(Default)]
+pub(crate) struct SuspenderCoverage {
+    /// Components calling a suspending API
+    suspenders: Vec<(String, Span)>,
+    /// Components created by `lazy()`, `dynamic()` or `loadable()`
+    lazy_components: HashSet<String>,
+    /// Components rendered inside a boundary
+    wrapped: HashSet<String>,
+    /// Components rendered (outside of boundaries) by each component
+    renders: HashMap<String, HashSet<String>>,
+    /// Elements rendered outside of boundaries with the rendering component
+    unguarded_renders: Vec<(String, Option<String>, Span)>,
+}
+
+impl TransformVisitor {
+    /// Diagnostics reported while transforming the module
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.diagnostics
+    }
+
+    /// Returns the severity of `rule` or `None` if the rule is turned off
+    ///
+    /// Rules configured in `rules` are reported independently of the `diagnostics`
+    /// option which enables all other rules as warnings. The `forbid` policy is
+    /// always enforced as error, boundary budgets and missing metadata as warning unless
+    /// configured otherwise.
+    fn rule_severity(&self, rule: &str) -> Option<Severity> {
+        match self.config.rules.get(rule) {
+            Some(RuleSeverity::Off) => None,
+            Some(RuleSeverity::Warn) => Some(Severity::Warning),
+            Some(RuleSeverity::Error) => Some(Severity::Error),
+            None if rule == FORBIDDEN_BOUNDARY => Some(Severity::Error),
+            None if rule == BOUNDARY_BUDGET || rule == MISSING_METADATA => Some(Severity::Warning),
+            None => self.config.diagnostics.then_some(Severity::Warning),
+        }
+    }
+
+    /// Whether `rule` is reported
+    pub(crate) fn is_rule_enabled(&self, rule: &str) -> bool {
+        self.rule_severity(rule).is_some()
+    }
+
+    /// Records a diagnostic of `rule` for `span` with the configured severity
+    pub(crate) fn report(&mut self, rule: &'static str, span: Span, message: String) {
+        let Some(severity) = self.rule_severity(rule) else {
+            return;
+        };
+        let line = self.lookup_line(span.lo);
+        self.diagnostics.push(Diagnostic {
+            rule,
+            severity,
+            message,
+            line,
+            span,
+        });
+    }
+
+    /// Reports that the plugin host did not provide the environment or filename
+    ///
+    /// The diagnostic has no location as it concerns the whole module.
+    #[cfg(feature = "embed")]
+    pub(crate) fn report_missing_metadata(&mut self, message: String) {
+        if let Some(severity) = self.rule_severity(MISSING_METADATA) {
+            self.diagnostics.push(Diagnostic {
+                rule: MISSING_METADATA,
+                severity,
+                message,
+                line: 0,
+                span: swc_core::common::DUMMY_SP,
+            });
+        }
+    }
+
+    /// Emits all recorded diagnostics and the boundary note through the swc error handler
+    /// (if one is set)
+    pub(crate) fn emit_diagnostics(&self) {
+        let note = self.boundary_note();
+        if (self.diagnostics.is_empty() && note.is_none()) || !HANDLER.is_set() {
+            return;
+        }
+        HANDLER.with(|handler| {
+            for diagnostic in &self.diagnostics {
+                let message = format!("[{}] {}", diagnostic.rule, diagnostic.message);
+                match diagnostic.severity {
+                    Severity::Warning => handler.struct_span_warn(diagnostic.span, &message).emit(),
+                    Severity::Error => handler.struct_span_err(diagnostic.span, &message).emit(),
+                }
+            }
+            if let Some(note) = &note {
+                handler.note_without_error(note);
+            }
+        });
+    }
+
+    /// Returns the note with the JSON of all boundaries of the module which build tool
+    /// plugins listening to the swc diagnostics aggregate into a project-wide boundary map
+    ///
+    /// The note is independent of the mode and skipped for modules without boundaries.
+    pub(crate) fn boundary_note(&self) -> Option<String> {
+        if !self.config.emit_boundary_note || self.boundaries.is_empty() {
+            return None;
+        }
+        let note = BoundaryNote {
+            file: &self.normalized_filename,
+            boundaries: &self.boundaries,
+        };
+        let json = serde_json::to_string(&note).expect("failed to serialize boundaries");
+        Some(format!("{BOUNDARY_NOTE_PREFIX} {json}"))
+    }
+
+    /// Reports boundaries which are forbidden in the current file
+    pub(crate) fn check_forbidden_boundary(
+        &mut self,
+        jsx_element: &JSXElement,
+        boundary: &Boundary,
+    ) {
+        let Some(forbidden) = self.config.forbid.iter().find(|forbidden| {
+            glob_match(&forbidden.component, &boundary.component)
+                && forbidden
+                    .from
+                    .as_ref()
+                    .is_none_or(|from| glob_match(from, &boundary.from))
+                && forbidden.paths.as_ref().is_none_or(|paths| {
+                    paths
+                        .iter()
+                        .any(|path| path_matches(path, &self.normalized_filename))
+                })
+        }) else {
+            return;
+        };
+
+        let mut message = format!(
+            "<{}> from \"{}\" is forbidden in this file",
+            boundary.component, boundary.from
+        );
+        if let Some(reason) = &forbidden.message {
+            message = format!("{message}: {reason}");
+        }
+        self.report(FORBIDDEN_BOUNDARY, jsx_element.opening.span, message);
+    }
+
+    /// Appends an ordinal suffix (`#2`, `#3`, ...) to an ID which was already given to
+    /// another boundary of the file and reports the collision
+    ///
+    /// This includes IDs without a known line (no source map), which collide for every
+    /// boundary of the file.
+    pub(crate) fn unique_boundary_id(&mut self, id: String, span: Span) -> String {
+        let count = self.boundary_id_counts.entry(id.clone()).or_default();
+        *count += 1;
+        if *count == 1 {
+            return id;
+        }
+        let unique_id = format!("{id}#{count}");
+        self.report(
+            DUPLICATE_BOUNDARY_ID,
+            span,
+            format!("another boundary already has the ID \"{id}\", using \"{unique_id}\" instead"),
+        );
+        unique_id
+    }
+
+    /// Reports the first boundary which exceeds the boundary budget of the current file
+    pub(crate) fn check_boundary_budget(&mut self, jsx_element: &JSXElement) {
+        let budget = self
+            .config
+            .boundary_budgets
+            .iter()
+            .find(|budget| {
+                budget
+                    .paths
+                    .iter()
+                    .any(|path| path_matches(path, &self.normalized_filename))
+            })
+            .map(|budget| budget.max)
+            .or(self.config.max_boundaries_per_file);
+        let Some(max) = budget else {
+            return;
+        };
+        // Only the boundary crossing the budget is reported
+        if self.boundaries.len() == max + 1 {
+            self.report(
+                BOUNDARY_BUDGET,
+                jsx_element.opening.span,
+                format!("this file has more than {max} boundaries"),
+            );
+        }
+    }
+
+    /// Reports Suspense boundaries which have no `fallback` prop
+    pub(crate) fn check_missing_fallback(
+        &mut self,
+        jsx_element: &JSXElement,
+        kind: BoundaryKind,
+        component: &str,
+    ) {
+        if kind != BoundaryKind::Suspense {
+            return;
+        }
+        // A spread might contain the fallback
+        let has_fallback = jsx_element.opening.attrs.iter().any(|attr| match attr {
+            JSXAttrOrSpread::SpreadElement(_) => true,
+            JSXAttrOrSpread::JSXAttr(JSXAttr {
+                name: JSXAttrName::Ident(name),
+                ..
+            }) => &*name.sym == "fallback",
+            _ => false,
+        });
+        if !has_fallback {
+            self.report(
+                MISSING_FALLBACK,
+                jsx_element.opening.span,
+                format!("<{component}> has no `fallback` prop and renders nothing while suspended"),
+            );
+        }
+    }
+
+    /// Reports boundaries rendered in a `.map()` / `.forEach()` callback which neither have
+    /// a `key` nor are rendered inside a keyed element - all rendered instances would report
+    /// the same ID
+    pub(crate) fn check_unkeyed_loop_boundary(
+        &mut self,
+        jsx_element: &JSXElement,
+        component: &str,
+    ) {
+        if self.loop_keys.last() == Some(&None) {
+            self.report(
+                UNKEYED_LOOP_BOUNDARY,
+                jsx_element.opening.span,
+                format!(
+                    "<{component}> is rendered in an array callback without a `key`, all instances share one boundary ID"
+                ),
+            );
+        }
+    }
+
+    /// Reports Suspense boundaries whose fallback is structurally identical to the fallback
+    /// of an enclosing boundary - both fallbacks would flash one after another
+    ///
+    /// The fallback is remembered for the children of the element until
+    /// the fallback stack is truncated again.
+    pub(crate) fn check_nested_duplicate_fallback(
+        &mut self,
+        jsx_element: &JSXElement,
+        kind: BoundaryKind,
+        component: &str,
+    ) {
+        if kind != BoundaryKind::Suspense || !self.is_rule_enabled(NESTED_DUPLICATE_FALLBACK) {
+            return;
+        }
+        let Some(fallback) = fallback_expr(jsx_element) else {
+            return;
+        };
+        if self
+            .fallback_stack
+            .iter()
+            .any(|outer_fallback| outer_fallback.eq_ignore_span(&fallback))
+        {
+            self.report(
+                NESTED_DUPLICATE_FALLBACK,
+                jsx_element.opening.span,
+                format!(
+                    "<{component}> has the same fallback as an enclosing boundary which shows the same skeleton twice"
+                ),
+            );
+        }
+        self.fallback_stack.push(fallback);
+    }
+
+    /// Remembers a function component which calls a suspending API
+    pub(crate) fn collect_suspender_function(
+        &mut self,
+        name: &str,
+        span: Span,
+        function: &Function,
+    ) {
+        if let Some(body) = &function.body {
+            self.collect_suspender(name, span, body);
+        }
+    }
+
+    /// Remembers a `const Name = () => ...` component which calls a suspending API
+    pub(crate) fn collect_suspender_declarator(&mut self, name: &str, declarator: &VarDeclarator) {
+        match declarator.init.as_deref() {
+            Some(Expr::Arrow(arrow)) => self.collect_suspender(name, declarator.span, &*arrow.body),
+            Some(Expr::Fn(fn_expr)) => {
+                self.collect_suspender_function(name, declarator.span, &fn_expr.function)
+            }
+            _ => {}
+        }
+    }
+
+    fn collect_suspender<N>(&mut self, name: &str, span: Span, body: &N)
+    where
+        N: for<'a> VisitWith<SuspendingCallFinder<'a>>,
+    {
+        if is_component_name(name)
+            && self.is_rule_enabled(UNWRAPPED_SUSPENDER)
+            && self.calls_suspending_api(body)
+        {
+            self.coverage.suspenders.push((name.to_string(), span));
+        }
+    }
+
+    /// Whether a rule needs to know which components are rendered inside boundaries,
+    /// which requires walking modules without boundaries as well
+    pub(crate) fn tracks_coverage(&self) -> bool {
+        self.is_rule_enabled(UNWRAPPED_SUSPENDER) || self.is_rule_enabled(UNGUARDED_LAZY)
+    }
+
+    /// Remembers `const Chart = lazy(() => import("./Chart"))` and its `next/dynamic`
+    /// and `@loadable/component` counterparts
+    pub(crate) fn collect_lazy_component(&mut self, declarator: &VarDeclarator) {
+        if !self.is_rule_enabled(UNGUARDED_LAZY) {
+            return;
+        }
+        let (Pat::Ident(binding), Some(Expr::Call(call))) =
+            (&declarator.name, declarator.init.as_deref())
+        else {
+            return;
+        };
+        if self.creates_lazy_component(&call.callee) {
+            self.coverage
+                .lazy_components
+                .insert(binding.id.sym.to_string());
+        }
+    }
+
+    /// Remembers which component renders the element and whether it is inside a boundary
+    pub(crate) fn record_rendered_component(&mut self, jsx_element: &JSXElement) {
+        if !self.tracks_coverage() {
+            return;
+        }
+        let JSXElementName::Ident(name) = &jsx_element.opening.name else {
+            return;
+        };
+        if !is_component_name(&name.sym) {
+            return;
+        }
+        let name = name.sym.to_string();
+        if self.boundary_depth > 0 {
+            self.coverage.wrapped.insert(name);
+            return;
+        }
+        let parent = self.enclosing_component();
+        // Lazy components may be declared after the component rendering them
+        if self.is_rule_enabled(UNGUARDED_LAZY) {
+            self.coverage.unguarded_renders.push((
+                name.clone(),
+                parent.clone(),
+                jsx_element.opening.span,
+            ));
+        }
+        if let Some(parent) = parent {
+            self.coverage
+                .renders
+                .entry(parent)
+                .or_default()
+                .insert(name);
+        }
+    }
+
+    /// Returns the components rendered inside a boundary, directly or (transitively)
+    /// by a component rendered inside a boundary
+    fn wrapped_components(&self) -> HashSet<String> {
+        let mut wrapped = self.coverage.wrapped.clone();
+        let mut pending: Vec<String> = wrapped.iter().cloned().collect();
+        while let Some(component) = pending.pop() {
+            for child in self.coverage.renders.get(&component).into_iter().flatten() {
+                if wrapped.insert(child.clone()) {
+                    pending.push(child.clone());
+                }
+            }
+        }
+        wrapped
+    }
+
+    /// Reports suspending components which are neither rendered inside a boundary nor
+    /// (transitively) by a component rendered inside a boundary
+    pub(crate) fn check_unwrapped_suspenders(&mut self) {
+        if self.coverage.suspenders.is_empty() {
+            return;
+        }
+        let wrapped = self.wrapped_components();
+
+        let unwrapped: Vec<(String, Span)> = self
+            .coverage
+            .suspenders
+            .iter()
+            .filter(|(name, _)| !wrapped.contains(name))
+            .cloned()
+            .collect();
+        for (name, span) in unwrapped {
+            self.report(
+                UNWRAPPED_SUSPENDER,
+                span,
+                format!("<{name}> calls a suspending API but is not rendered inside a boundary in this file"),
+            );
+        }
+    }
+
+    /// Reports lazy components rendered by a component which is not (transitively)
+    /// rendered inside a boundary of the module - they suspend up to the next boundary
+    /// of another module or crash without one
+    pub(crate) fn check_unguarded_lazy(&mut self) {
+        if self.coverage.lazy_components.is_empty() {
+            return;
+        }
+        let wrapped = self.wrapped_components();
+
+        let unguarded: Vec<(String, Span)> = self
+            .coverage
+            .unguarded_renders
+            .iter()
+            .filter(|(name, parent, _)| {
+                self.coverage.lazy_components.contains(name)
+                    && !parent
+                        .as_ref()
+                        .is_some_and(|parent| wrapped.contains(parent))
+            })
+            .map(|(name, _, span)| (name.clone(), *span))
+            .collect();
+        for (name, span) in unguarded {
+            self.report(
+                UNGUARDED_LAZY,
+                span,
+                format!("<{name}> is a lazy component but is not rendered inside a Suspense boundary in this file"),
+            );
+        }
+    }
+}
+
+/// Returns the value of the `fallback` prop as an expression
+fn fallback_expr(jsx_element: &JSXElement) -> Option<Expr> {
+    jsx_element
+        .opening
+        .attrs
+        .iter()
+        .find_map(|attr| match attr {
+            JSXAttrOrSpread::JSXAttr(JSXAttr {
+                name: JSXAttrName::Ident(name),
+                value: Some(value),
+                ..
+            }) if &*name.sym == "fallback" => Some(value),
+            _ => None,
+        })
+        .and_then(|value| match value {
+            JSXAttrValue::Str(str_lit) => Some(Expr::Lit(Lit::Str(str_lit.clone()))),
+            JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                expr: JSXExpr::Expr(expr),
+                ..
+            }) => Some((**expr).clone()),
+            JSXAttrValue::JSXElement(element) => Some(Expr::JSXElement(element.clone())),
+            _ => None,
+        })
+}
@@ -0,0 +1,88 @@
+import {
+  SuspenseContext,
+  boundaryErrorRegistry,
+  resolveBoundaryMeta,
+  useBoundaryTracking,
+  type TrackerProps,
+} from "./internal";
+
+interface ErrorBoundaryTrackerProps extends TrackerProps {
+  [prop: string]: unknown;
+}
+
+/**
+ * Internal component that replaces error boundary components via SWC plugin transformation.
+ * Provides tracking context while maintaining all original boundary functionality.
+ */
+export const ErrorBoundaryTrackerSWC = (props: ErrorBoundaryTrackerProps) => {
+  const {
+    boundaryId,
+    boundary: Boundary,
+    boundaryPropMap,
+    debugName,
+    ownerStack,
+    routeHint,
+    originalName,
+    depth,
+    otelAttributes,
+    __boundarySource,
+    ...boundaryProps
+  } = resolveBoundaryMeta(props);
+  const boundaries = useBoundaryTracking(
+    {
+      boundaryId,
+      boundary: Boundary,
+      boundaryPropMap,
+      debugName,
+      ownerStack,
+      routeHint,
+      originalName,
+      depth,
+      otelAttributes,
+      __boundarySource,
+    },
+    boundaryProps,
+  );
+  return (
+    <SuspenseContext.Provider value={boundaries}>
+      <Boundary {...boundaryProps} />
+    </SuspenseContext.Provider>
+  );
+};
+
+/**
+ * Internal `onError` handler added to error boundaries if the SWC plugin runs with
+ * `reportErrors: true` - records the error and calls the original `onError` handler
+ */
+export const __reportBoundaryError =
+  (
+    boundaryId: string,
+    onError?: (error: unknown, info: { componentStack?: string | null }) => void,
+  ) =>
+  (error: unknown, info: { componentStack?: string | null }) => {
+    boundaryErrorRegistry.set(boundaryId, {
+      boundaryId,
+      error,
+      componentStack: info?.componentStack ?? null,
+      caughtAt: performance.now(),
+    });
+    onError?.(error, info);
+  };
+
+/** The part of the Sentry scope used to tag captured errors */
+type CaptureScope = { setTag(key: string, value: string): void };
+
+/**
+ * Internal `beforeCapture` handler added to error boundaries if the SWC plugin runs with
+ * `sentry` - tags the captured error with the boundary id and calls the original handler
+ */
+export const __tagBoundaryCapture =
+  (
+    tag: string,
+    boundaryId: string,
+    beforeCapture?: (scope: CaptureScope, ...args: unknown[]) => void,
+  ) =>
+  (scope: CaptureScope, ...args: unknown[]) => {
+    scope.setTag(tag, boundaryId);
+    beforeCapture?.(scope, ...args);
+  };
@@ -0,0 +1,44 @@
+//! Minimal glob matching for component names and file paths
+//!
+//! `*` matches any characters except `/`, `**` matches across directories
+//! and `?` matches a single character except `/`.
+
+/// Checks if `text` matches the glob `pattern`
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    matches(pattern.as_bytes(), text.as_bytes())
+}
+
+/// Checks if a file path matches the glob `pattern`
+///
+/// Patterns which don't start with `/` also match at any directory level, so
+/// `src/routes/**` matches both `src/routes/a.tsx` and `/home/app/src/routes/a.tsx`.
+pub fn path_matches(pattern: &str, path: &str) -> bool {
+    glob_match(pattern, path)
+        || (!pattern.starts_with('/')
+            && path
+                .match_indices('/')
+                .any(|(index, _)| glob_match(pattern, &path[index + 1..])))
+}
+
+fn matches(pattern: &[u8], text: &[u8]) -> bool {
+    match pattern {
+        [] => text.is_empty(),
+        [b'*', b'*', rest @ ..] => {
+            // `a/**/b` also matches `a/b`
+            (0..=text.len()).any(|index| matches(rest, &text[index..]))
+                || rest
+                    .strip_prefix(b"/")
+                    .is_some_and(|rest| matches(rest, text))
+        }
+        [b'*', rest @ ..] => {
+            let segment_end = text.iter().position(|&c| c == b'/').unwrap_or(text.len());
+            (0..=segment_end).any(|index| matches(rest, &text[index..]))
+        }
+        [b'?', rest @ ..] => text
+            .split_first()
+            .is_some_and(|(&c, text)| c != b'/' && matches(rest, text)),
+        [c, rest @ ..] => text
+            .split_first()
+            .is_some_and(|(t, text)| t == c && matches(rest, text)),
+    }
+}
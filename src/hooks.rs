@@ -0,0 +1,290 @@
+use swc_core::{
+    common::{util::take::Take, DUMMY_SP},
+    ecma::ast::*,
+};
+
+use crate::{
+    ast_utils::{object_expr, str_expr},
+    imports::{DEFAULT_IMPORT, NAMESPACE_IMPORT},
+    HookInjection, TransformVisitor,
+};
+
+pub(crate) const TRACK_PROMISE_IMPORT_NAME: &str = "__trackPromise";
+const TRACK_DEFERRED_VALUE_IMPORT_NAME: &str = "__trackDeferredValue";
+pub(crate) const TANSTACK_QUERY_PACKAGE_NAME: &str = "@tanstack/react-query";
+pub(crate) const SWR_PACKAGE_NAME: &str = "swr";
+/// The hooks SWR exports as default export of its entry points
+const SWR_DEFAULT_EXPORTS: [(&str, &str); 3] = [
+    ("swr", "useSWR"),
+    ("swr/immutable", "useSWRImmutable"),
+    ("swr/infinite", "useSWRInfinite"),
+];
+const TRACKER_ID_FIELD_PATH: [&str; 2] = ["meta", "suspenseTrackerId"];
+
+impl TransformVisitor {
+    /// Rewrites `use(promise)` into `use(__trackPromise(promise, "file:line"))`
+    pub(crate) fn track_use_call(&mut self, call: &mut CallExpr) {
+        if !self.config.track_use || !self.is_imported_call(&call.callee, "react", "use") {
+            return;
+        }
+        let [arg] = call.args.as_mut_slice() else {
+            return;
+        };
+        if arg.spread.is_some() {
+            return;
+        }
+
+        let id = self.generate_boundary_id(self.lookup_line(call.span.lo));
+        let promise = arg.expr.as_mut().take();
+        *arg.expr =
+            Expr::Call(self.pure_call(TRACK_PROMISE_IMPORT_NAME, vec![promise, str_expr(&id)]));
+        self.add_runtime_import(TRACK_PROMISE_IMPORT_NAME);
+    }
+
+    /// Adds `meta: { suspenseTrackerId: "file:line" }` to the options of TanStack Query suspense hooks
+    pub(crate) fn track_tanstack_query_call(&mut self, call: &mut CallExpr) {
+        if !self.config.track_tanstack_query {
+            return;
+        }
+        let Some(hook) =
+            self.config.tanstack_query_hooks().into_iter().find(|hook| {
+                self.is_imported_call(&call.callee, TANSTACK_QUERY_PACKAGE_NAME, hook)
+            })
+        else {
+            return;
+        };
+        let is_queries_hook = hook == "useSuspenseQueries";
+        let id = self.generate_boundary_id(self.lookup_line(call.span.lo));
+
+        let Some(options) = call.args.first_mut().filter(|arg| arg.spread.is_none()) else {
+            return;
+        };
+
+        // `useSuspenseQueries({ queries: [...] })` takes the options of every query in a list
+        if is_queries_hook {
+            if let Some(Expr::Array(queries)) = get_object_field_mut(&mut options.expr, "queries") {
+                for query in queries.elems.iter_mut().flatten() {
+                    if query.spread.is_none() {
+                        set_options_field(&mut query.expr, &TRACKER_ID_FIELD_PATH, str_expr(&id));
+                    }
+                }
+            }
+            return;
+        }
+
+        set_options_field(&mut options.expr, &TRACKER_ID_FIELD_PATH, str_expr(&id));
+    }
+
+    /// Rewrites `useSWR(key, fetcher, { suspense: true })` into
+    /// `__trackSuspendingHook(() => useSWR(key, fetcher, { suspense: true }), "file:line")`
+    ///
+    /// SWR keeps no per call metadata, so the promise thrown by the hook is attributed instead.
+    pub(crate) fn track_swr_call(&mut self, call: &mut CallExpr) {
+        if !self.config.track_swr {
+            return;
+        }
+        let is_swr_hook = self
+            .swr_hook_name(&call.callee)
+            .is_some_and(|name| self.config.swr_hooks().contains(&name));
+        // The options follow the key and the optional fetcher
+        let is_suspense_mode = call
+            .args
+            .iter()
+            .skip(1)
+            .any(|arg| enables_suspense(&arg.expr));
+        if !is_swr_hook || !is_suspense_mode {
+            return;
+        }
+
+        self.inject_hook_call_id(call, &[HookInjection::WrapCall]);
+    }
+
+    /// Returns the name of an SWR hook (`import useSWR from "swr"` under any local name)
+    /// or the imported name of any other hook (e.g. a wrapper of `useSWR`)
+    fn swr_hook_name<'a>(&'a self, callee: &'a Callee) -> Option<&'a str> {
+        let default_export = callee
+            .as_expr()
+            .and_then(|callee| callee.as_ident())
+            .and_then(|ident| self.imports.get(&ident.to_id()))
+            .and_then(|binding| {
+                SWR_DEFAULT_EXPORTS
+                    .into_iter()
+                    .find(|(src, _)| binding.is(src, DEFAULT_IMPORT))
+            });
+        match default_export {
+            Some((_, hook)) => Some(hook),
+            None => self.imported_name(callee),
+        }
+    }
+
+    /// Rewrites `useDeferredValue(value)` into `__trackDeferredValue(useDeferredValue(value), "file:line")`
+    pub(crate) fn track_deferred_value_call(&mut self, call: &mut CallExpr) {
+        if !self.config.track_deferred_value {
+            return;
+        }
+        let is_deferred_value_hook = self
+            .imported_name(&call.callee)
+            .is_some_and(|name| self.config.deferred_value_hooks().contains(&name));
+        if !is_deferred_value_hook {
+            return;
+        }
+
+        let id = self.generate_boundary_id(self.lookup_line(call.span.lo));
+        let deferred_value = Expr::Call(call.take());
+        *call = self.pure_call(
+            TRACK_DEFERRED_VALUE_IMPORT_NAME,
+            vec![deferred_value, str_expr(&id)],
+        );
+
+        match self.config.deferred_value_tracker.clone() {
+            Some(src) => self.add_runtime_import_from(&src, TRACK_DEFERRED_VALUE_IMPORT_NAME),
+            None => self.add_runtime_import(TRACK_DEFERRED_VALUE_IMPORT_NAME),
+        }
+    }
+
+    /// Returns the imported name of a callee (`useX(...)` or `Module.useX(...)`) from any module
+    fn imported_name<'a>(&'a self, callee: &'a Callee) -> Option<&'a str> {
+        let Callee::Expr(callee) = callee else {
+            return None;
+        };
+        match &**callee {
+            Expr::Ident(ident) => self
+                .imports
+                .get(&ident.to_id())
+                .filter(|binding| {
+                    binding.imported != DEFAULT_IMPORT && binding.imported != NAMESPACE_IMPORT
+                })
+                .map(|binding| binding.imported.as_str()),
+            Expr::Member(MemberExpr {
+                obj,
+                prop: MemberProp::Ident(prop),
+                ..
+            }) => {
+                let binding = self.imports.get(&obj.as_ident()?.to_id())?;
+                (binding.imported == DEFAULT_IMPORT || binding.imported == NAMESPACE_IMPORT)
+                    .then_some(&*prop.sym)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Checks if an options object literal sets `suspense: true`
+fn enables_suspense(options: &Expr) -> bool {
+    let Expr::Object(object) = options else {
+        return false;
+    };
+    object.props.iter().any(|prop| match prop {
+        PropOrSpread::Prop(prop) => matches!(
+            &**prop,
+            Prop::KeyValue(KeyValueProp { key, value })
+                if prop_name_is(key, "suspense")
+                    && matches!(**value, Expr::Lit(Lit::Bool(Bool { value: true, .. })))
+        ),
+        _ => false,
+    })
+}
+
+/// Returns the value of the `key` field of an object literal
+fn get_object_field_mut<'a>(object: &'a mut Expr, key: &str) -> Option<&'a mut Expr> {
+    let Expr::Object(object) = object else {
+        return None;
+    };
+    object.props.iter_mut().find_map(|prop| match prop {
+        PropOrSpread::Prop(prop) => match &mut **prop {
+            Prop::KeyValue(key_value) if prop_name_is(&key_value.key, key) => {
+                Some(&mut *key_value.value)
+            }
+            _ => None,
+        },
+        _ => None,
+    })
+}
+
+/// Sets a (nested) field like `meta.suspenseTrackerId` of an options expression
+///
+/// Object literals are modified in place, identifiers are merged into a new object
+/// (`options` becomes `{ ...options, meta: { ...options.meta, suspenseTrackerId } }`).
+/// Other expressions are left untouched as evaluating them twice might have side effects.
+pub(crate) fn set_options_field(options: &mut Expr, path: &[&str], value: Expr) {
+    let Some((key, rest)) = path.split_first() else {
+        return;
+    };
+
+    match options {
+        Expr::Object(_) => match get_object_field_mut(options, key) {
+            Some(field) if rest.is_empty() => *field = value,
+            Some(field) => set_options_field(field, rest, value),
+            None => {
+                let Expr::Object(object) = options else {
+                    return;
+                };
+                object
+                    .props
+                    .push(key_value_prop(key, nested_field(rest, value)));
+            }
+        },
+        Expr::Ident(_) => {
+            let base = options.take();
+            let field = if rest.is_empty() {
+                value
+            } else {
+                let mut nested = spread_object(Expr::Member(MemberExpr {
+                    span: DUMMY_SP,
+                    obj: Box::new(base.clone()),
+                    prop: MemberProp::Ident(IdentName {
+                        span: DUMMY_SP,
+                        sym: (*key).into(),
+                    }),
+                }));
+                set_options_field(&mut nested, rest, value);
+                nested
+            };
+            let mut merged = spread_object(base);
+            if let Expr::Object(object) = &mut merged {
+                object.props.push(key_value_prop(key, field));
+            }
+            *options = merged;
+        }
+        _ => {}
+    }
+}
+
+/// Creates `{ a: { b: value } }` for the path `["a", "b"]` (or `value` for an empty path)
+fn nested_field(path: &[&str], value: Expr) -> Expr {
+    match path.split_first() {
+        Some((key, rest)) => object_expr(vec![(*key, nested_field(rest, value))]),
+        None => value,
+    }
+}
+
+/// Creates `{ ...expr }`
+fn spread_object(expr: Expr) -> Expr {
+    Expr::Object(ObjectLit {
+        span: DUMMY_SP,
+        props: vec![PropOrSpread::Spread(SpreadElement {
+            dot3_token: DUMMY_SP,
+            expr: Box::new(expr),
+        })],
+    })
+}
+
+/// Creates a `key: value` property
+fn key_value_prop(key: &str, value: Expr) -> PropOrSpread {
+    PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+        key: PropName::Ident(IdentName {
+            span: DUMMY_SP,
+            sym: key.into(),
+        }),
+        value: Box::new(value),
+    })))
+}
+
+/// Checks if a property name is `name` (`name: ...` or `"name": ...`)
+fn prop_name_is(prop_name: &PropName, name: &str) -> bool {
+    match prop_name {
+        PropName::Ident(ident) => &*ident.sym == name,
+        PropName::Str(str_lit) => str_lit.value.as_str() == Some(name),
+        _ => false,
+    }
+}
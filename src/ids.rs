@@ -0,0 +1,230 @@
+//! Boundary ID strategies
+
+use swc_core::ecma::ast::*;
+
+use crate::{
+    ast_utils::{const_item, ident, jsx_attr_value, str_expr, tpl_expr},
+    strip::is_attr,
+    Boundary, IdStrategy, TransformVisitor,
+};
+
+/// Prefix of the module level constants of hoisted IDs (`__bid1`, `__bid2`, ...)
+const HOISTED_ID_PREFIX: &str = "__bid";
+
+/// Prefix of the `{prop:name}` placeholders of the `idFormat` template
+const PROP_PLACEHOLDER_PREFIX: &str = "prop:";
+
+impl TransformVisitor {
+    /// Generates the ID of a boundary element with the strategy and prefix of its
+    /// [`Boundary`] entry, falling back to the global `idStrategy` and `idPrefix`
+    ///
+    /// An explicit `trackId="checkout-items"` prop is used as is.
+    pub(crate) fn boundary_element_id(
+        &mut self,
+        boundary: &Boundary,
+        line: usize,
+        jsx_element: &JSXElement,
+    ) -> String {
+        if let Some(id) = self.explicit_boundary_id(jsx_element) {
+            return id;
+        }
+        let span = jsx_element.opening.span;
+        let id = match self.id_strategy(boundary) {
+            IdStrategy::Line => {
+                let id = match &self.config.id_format {
+                    Some(format) => self.format_boundary_id(format, line, jsx_element),
+                    None => self.generate_boundary_id(line),
+                };
+                self.unique_boundary_id(id, span)
+            }
+            IdStrategy::Component => self.generate_structural_boundary_id(&boundary.component),
+            IdStrategy::Hash => {
+                let id = self.generate_boundary_id(line);
+                hash_id(&self.unique_boundary_id(id, span))
+            }
+            IdStrategy::Numeric => {
+                self.numeric_id_count += 1;
+                self.numeric_id_count.to_string()
+            }
+        };
+        match boundary
+            .id_prefix
+            .as_deref()
+            .or(self.config.id_prefix.as_deref())
+        {
+            Some(prefix) => format!("{prefix}{id}"),
+            None => id,
+        }
+    }
+
+    /// Returns the string value of the `trackId` prop of a boundary element
+    fn explicit_boundary_id(&self, jsx_element: &JSXElement) -> Option<String> {
+        match jsx_attr_value(jsx_element, self.config.track_id_prop())? {
+            JSXAttrValue::Str(value) => value.value.as_str().map(str::to_string),
+            _ => None,
+        }
+    }
+
+    /// Removes the `trackId` prop once it became the ID of the boundary
+    pub(crate) fn strip_explicit_boundary_id(&self, jsx_element: &mut JSXElement) {
+        if self.explicit_boundary_id(jsx_element).is_none() {
+            return;
+        }
+        let prop = self.config.track_id_prop();
+        jsx_element
+            .opening
+            .attrs
+            .retain(|attr| !is_attr(attr, prop));
+    }
+
+    /// Returns the ID strategy of a boundary
+    pub(crate) fn id_strategy(&self, boundary: &Boundary) -> IdStrategy {
+        boundary
+            .id_strategy
+            .or(self.config.id_strategy)
+            .unwrap_or(if self.config.test_mode {
+                IdStrategy::Component
+            } else {
+                IdStrategy::Line
+            })
+    }
+
+    /// Resolves the `{file}`, `{line}`, `{column}` and `{prop:name}` placeholders of the
+    /// `idFormat` template
+    ///
+    /// Placeholders of props with an expression value are kept and turned into a template
+    /// literal by [`Self::dynamic_boundary_id`], missing props resolve to an empty string.
+    fn format_boundary_id(&self, format: &str, line: usize, jsx_element: &JSXElement) -> String {
+        let mut id = String::new();
+        let mut rest = format;
+        while let Some((before, placeholder, after)) = split_placeholder(rest) {
+            id.push_str(before);
+            match placeholder {
+                "file" => id.push_str(&self.normalized_filename),
+                "line" => id.push_str(&line.to_string()),
+                "column" => id.push_str(&self.lookup_column(jsx_element.span.lo).to_string()),
+                _ => match placeholder
+                    .strip_prefix(PROP_PLACEHOLDER_PREFIX)
+                    .map(|name| jsx_attr_value(jsx_element, name))
+                {
+                    Some(Some(JSXAttrValue::Str(value))) => {
+                        id.push_str(value.value.as_str().unwrap_or_default())
+                    }
+                    // No trailing separator is left for a missing prop
+                    // (`{file}:{line}:{prop:name}` becomes `src/App.tsx:12`)
+                    Some(None) => {
+                        let kept = id.trim_end_matches(is_separator).len();
+                        if kept > 0 {
+                            id.truncate(kept);
+                        } else {
+                            rest = after.trim_start_matches(is_separator);
+                            continue;
+                        }
+                    }
+                    _ => id.push_str(&format!("{{{placeholder}}}")),
+                },
+            }
+            rest = after;
+        }
+        id.push_str(rest);
+        id
+    }
+
+    /// Creates a template literal for an ID with `{prop:name}` placeholders of dynamic props
+    /// (`` `src/App.tsx:12:${name}` ``), `None` for static IDs
+    pub(crate) fn dynamic_boundary_id(&self, id: &str, jsx_element: &JSXElement) -> Option<Expr> {
+        self.config.id_format.as_ref()?;
+        let mut quasis = vec![String::new()];
+        let mut exprs = Vec::new();
+        let mut rest = id;
+        while let Some((before, placeholder, after)) = split_placeholder(rest) {
+            let expr = placeholder
+                .strip_prefix(PROP_PLACEHOLDER_PREFIX)
+                .and_then(|name| match jsx_attr_value(jsx_element, name)? {
+                    JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                        expr: JSXExpr::Expr(expr),
+                        ..
+                    }) => Some(*expr.clone()),
+                    _ => None,
+                });
+            let quasi = quasis.last_mut().unwrap();
+            quasi.push_str(before);
+            match expr {
+                Some(expr) => {
+                    exprs.push(expr);
+                    quasis.push(String::new());
+                }
+                None => quasi.push_str(&format!("{{{placeholder}}}")),
+            }
+            rest = after;
+        }
+        if exprs.is_empty() {
+            return None;
+        }
+        quasis.last_mut().unwrap().push_str(rest);
+        let quasis: Vec<&str> = quasis.iter().map(String::as_str).collect();
+        Some(tpl_expr(&quasis, exprs))
+    }
+
+    /// Returns a reference to the module level constant of a boundary ID (`__bid1`)
+    /// for `hoistIds` - boundaries with the same ID share the constant
+    pub(crate) fn hoisted_id(&mut self, id: &str) -> Expr {
+        let index = match self.hoisted_ids.iter().position(|hoisted| hoisted == id) {
+            Some(index) => index,
+            None => {
+                self.hoisted_ids.push(id.to_string());
+                self.hoisted_ids.len() - 1
+            }
+        };
+        Expr::Ident(ident(&format!("{HOISTED_ID_PREFIX}{}", index + 1)))
+    }
+
+    /// Creates the `const __bid1 = "src/App.tsx:12";` declarations of all hoisted IDs
+    pub(crate) fn create_hoisted_id_items(&self) -> Vec<ModuleItem> {
+        self.hoisted_ids
+            .iter()
+            .enumerate()
+            .map(|(index, id)| {
+                const_item(&format!("{HOISTED_ID_PREFIX}{}", index + 1), str_expr(id))
+            })
+            .collect()
+    }
+
+    /// Generates an ID from the enclosing component and the boundary component
+    /// (e.g. `src/App.tsx:App/Suspense`) which does not change if unrelated lines are edited
+    ///
+    /// Further boundaries with the same ID get an ordinal suffix (`#2`, `#3`, ...).
+    fn generate_structural_boundary_id(&mut self, component: &str) -> String {
+        let id = match self.enclosing_component() {
+            Some(enclosing) => format!("{}:{enclosing}/{component}", self.normalized_filename),
+            None => format!("{}:{component}", self.normalized_filename),
+        };
+        let count = self.boundary_id_counts.entry(id.clone()).or_default();
+        *count += 1;
+        if *count == 1 {
+            id
+        } else {
+            format!("{id}#{count}")
+        }
+    }
+}
+
+/// Checks if a character of an ID format separates its placeholders (`:`, `/`, `-`, ...)
+fn is_separator(c: char) -> bool {
+    !c.is_alphanumeric() && !matches!(c, '{' | '}')
+}
+
+/// Splits `before{placeholder}after` at its first placeholder
+fn split_placeholder(text: &str) -> Option<(&str, &str, &str)> {
+    let (before, rest) = text.split_once('{')?;
+    let (placeholder, after) = rest.split_once('}')?;
+    Some((before, placeholder, after))
+}
+
+/// Hashes an ID into 8 hex digits (32 bit FNV-1a) which don't reveal the file path
+fn hash_id(id: &str) -> String {
+    let hash = id.bytes().fold(0x811c_9dc5_u32, |hash, byte| {
+        (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+    });
+    format!("{hash:08x}")
+}
@@ -0,0 +1,126 @@
+use std::collections::HashSet;
+
+use swc_core::ecma::{
+    ast::*,
+    visit::{Visit, VisitWith},
+};
+
+/// Imported name used for default imports
+pub const DEFAULT_IMPORT: &str = "default";
+/// Imported name used for namespace imports
+pub const NAMESPACE_IMPORT: &str = "*";
+
+/// What a local binding was imported as
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportBinding {
+    /// The module the binding was imported from
+    pub src: String,
+    /// The imported (external) name, [`DEFAULT_IMPORT`] or [`NAMESPACE_IMPORT`]
+    pub imported: String,
+}
+
+impl ImportBinding {
+    /// Checks if the binding is the export `name` of `src`
+    pub fn is(&self, src: &str, name: &str) -> bool {
+        self.src == src && self.imported == name
+    }
+
+    /// Checks if the binding is the default or namespace import of `src`
+    pub fn is_module_object(&self, src: &str) -> bool {
+        self.src == src && (self.imported == DEFAULT_IMPORT || self.imported == NAMESPACE_IMPORT)
+    }
+}
+
+/// Returns the local bindings of an import declaration
+///
+/// Specifiers unknown to this version of `swc_ecma_ast` (`swc_ast_unknown`) are skipped.
+#[cfg_attr(not(swc_ast_unknown), allow(clippy::unnecessary_filter_map))]
+pub fn collect_import_bindings(import_decl: &ImportDecl) -> Vec<(Id, ImportBinding)> {
+    let Some(src) = import_decl.src.value.as_str() else {
+        return Vec::new();
+    };
+
+    import_decl
+        .specifiers
+        .iter()
+        .filter_map(|spec| {
+            let (local, imported) = match spec {
+                ImportSpecifier::Named(named) => (
+                    &named.local,
+                    match &named.imported {
+                        Some(ModuleExportName::Ident(ident)) => ident.sym.to_string(),
+                        Some(ModuleExportName::Str(str_lit)) => {
+                            str_lit.value.as_str().unwrap_or_default().to_string()
+                        }
+                        #[cfg(swc_ast_unknown)]
+                        Some(_) => String::new(),
+                        None => named.local.sym.to_string(),
+                    },
+                ),
+                ImportSpecifier::Default(default) => (&default.local, DEFAULT_IMPORT.to_string()),
+                ImportSpecifier::Namespace(namespace) => {
+                    (&namespace.local, NAMESPACE_IMPORT.to_string())
+                }
+                #[cfg(swc_ast_unknown)]
+                _ => return None,
+            };
+            Some((
+                local.to_id(),
+                ImportBinding {
+                    src: src.to_string(),
+                    imported,
+                },
+            ))
+        })
+        .collect()
+}
+
+/// Removes the import specifiers of `candidates` which are not referenced outside of imports
+///
+/// Imports which become empty are removed entirely, side effect imports are kept.
+pub fn remove_unused_imports(module_items: &mut Vec<ModuleItem>, candidates: &HashSet<Id>) {
+    let mut finder = UsageFinder {
+        candidates,
+        used: HashSet::new(),
+    };
+    for item in module_items.iter() {
+        if !matches!(item, ModuleItem::ModuleDecl(ModuleDecl::Import(_))) {
+            item.visit_with(&mut finder);
+        }
+    }
+
+    module_items.retain_mut(|item| {
+        let ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)) = item else {
+            return true;
+        };
+        if import_decl.specifiers.is_empty() {
+            return true;
+        }
+        import_decl.specifiers.retain(|spec| {
+            let id = match spec {
+                ImportSpecifier::Named(named) => named.local.to_id(),
+                ImportSpecifier::Default(default) => default.local.to_id(),
+                ImportSpecifier::Namespace(namespace) => namespace.local.to_id(),
+                #[cfg(swc_ast_unknown)]
+                _ => return true,
+            };
+            !candidates.contains(&id) || finder.used.contains(&id)
+        });
+        !import_decl.specifiers.is_empty()
+    });
+}
+
+/// Finds references of the candidate bindings
+struct UsageFinder<'a> {
+    candidates: &'a HashSet<Id>,
+    used: HashSet<Id>,
+}
+
+impl Visit for UsageFinder<'_> {
+    fn visit_ident(&mut self, ident: &Ident) {
+        let id = ident.to_id();
+        if self.candidates.contains(&id) {
+            self.used.insert(id);
+        }
+    }
+}
@@ -1,5 +1,39 @@
 import { Suspense, useContext, useDebugValue } from "react";
-import { SuspenseContext, type BoundaryInfo } from "./internal";
+import {
+  SuspenseContext,
+  boundaryErrorRegistry,
+  boundaryPropsRegistry,
+  boundaryRegistry,
+  boundarySourceRegistry,
+  chunkRegistry,
+  contentMountRegistry,
+  debugNameRegistry,
+  depthRegistry,
+  deferredValueRegistry,
+  fallbackRegistry,
+  lazyRegistry,
+  originalNameRegistry,
+  otelAttributesRegistry,
+  ownerStackRegistry,
+  promiseRegistry,
+  routeHintRegistry,
+  renderTimingRegistry,
+  suspenderRegistry,
+  transitionState,
+  type BoundaryError,
+  type BoundaryInfo,
+  type BoundaryRenderTiming,
+  type BoundarySource,
+  type ChunkTiming,
+  type ContentMountTiming,
+  type NormalizedBoundaryProps,
+  type OtelAttributes,
+  type FallbackTiming,
+  type LazyInfo,
+  type RegisteredBoundary,
+  type SuspenderInfo,
+  type TransitionInfo,
+} from "./internal";
 
 /**
  * Returns information about all boundary components above this component
@@ -35,6 +69,180 @@ export const useBoundaryStack = (): BoundaryInfo[] => {
   return boundaryStack;
 };
 
+/**
+ * Returns all boundaries registered by modules transformed with `emitRegistry: true`
+ *
+ * Boundaries are registered as soon as their module is evaluated,
+ * even if they have never been rendered.
+ */
+export const getRegisteredBoundaries = (): RegisteredBoundary[] => [
+  ...boundaryRegistry.values(),
+];
+
+/**
+ * Returns where a lazy component was created and which module it loads
+ *
+ * Requires the `trackLazy` plugin option - returns `undefined` otherwise.
+ */
+export const getLazyInfo = (
+  lazyComponent: React.ComponentType<any>,
+): LazyInfo | undefined => lazyRegistry.get(lazyComponent);
+
+/**
+ * Returns how long the chunks of lazy components took to load, keyed by the
+ * `lazyId` of the lazy component (see `getLazyInfo()`)
+ *
+ * Requires the `trackChunks` plugin option - returns an empty array otherwise.
+ */
+export const getChunkTimings = (): ChunkTiming[] =>
+  Array.from(chunkRegistry.values(), (timing) => ({ ...timing }));
+
+/**
+ * Returns the ID of the `use()` call which received the given promise
+ *
+ * Requires the `trackUse` plugin option - returns `undefined` otherwise.
+ */
+export const getPromiseCallSite = (promise: object): string | undefined =>
+  promiseRegistry.get(promise);
+
+/**
+ * Returns all rendered components which call a suspending API
+ * together with the boundaries above them
+ *
+ * Requires the `trackSuspenders` plugin option - returns an empty array otherwise.
+ */
+export const getSuspenders = (): SuspenderInfo[] => [
+  ...suspenderRegistry.values(),
+];
+
+/**
+ * Returns the latest transition started by a tracked `startTransition` call
+ *
+ * Suspensions inside a transition keep the previous UI instead of showing a fallback.
+ * Requires the `trackTransitions` plugin option - returns `null` otherwise.
+ */
+export const getLastTransition = (): TransitionInfo | null =>
+  transitionState.last;
+
+/**
+ * Returns the IDs of all `useDeferredValue` calls which rendered within the last `withinMs`
+ *
+ * Requires the `trackDeferredValue` plugin option - returns an empty array otherwise.
+ */
+export const getRecentDeferredValues = (withinMs = 1000): string[] => {
+  const now = performance.now();
+  return [...deferredValueRegistry]
+    .filter(([, renderTime]) => now - renderTime <= withinMs)
+    .map(([callSiteId]) => callSiteId);
+};
+
+/**
+ * Returns the latest commit timings of every boundary
+ *
+ * Requires the `injectProfiler` plugin option - returns an empty array otherwise.
+ */
+export const getBoundaryRenderTimings = (): BoundaryRenderTiming[] => [
+  ...renderTimingRegistry.values(),
+];
+
+/**
+ * Returns how often and how long the fallback of every boundary was displayed
+ *
+ * Requires the `trackFallback` plugin option - returns an empty array otherwise.
+ */
+export const getFallbackTimings = (): FallbackTiming[] => [
+  ...fallbackRegistry.values(),
+];
+
+/**
+ * Returns when the content of every boundary was mounted and how long its fallback was shown before
+ *
+ * `timeToContent` requires the `trackFallback` plugin option as well.
+ * Requires the `trackContentMount` plugin option - returns an empty array otherwise.
+ */
+export const getContentMountTimings = (): ContentMountTiming[] => [
+  ...contentMountRegistry.values(),
+];
+
+/**
+ * Returns the props of a rendered boundary in a normalized shape
+ * (e.g. `FallbackComponent` of `react-error-boundary` becomes `fallback`)
+ *
+ * Only boundaries with a `propMap` (or a known library) are recorded.
+ */
+export const getBoundaryProps = (
+  boundaryId: string,
+): NormalizedBoundaryProps | undefined => boundaryPropsRegistry.get(boundaryId);
+
+/**
+ * Returns the location of a rendered boundary (`{ fileName, lineNumber, columnNumber }`)
+ *
+ * Requires the `emitBoundarySource` plugin option or the `__source` prop of the JSX dev transform.
+ */
+export const getBoundarySource = (
+  boundaryId: string,
+): BoundarySource | undefined => boundarySourceRegistry.get(boundaryId);
+
+/**
+ * Returns the name of the variable or component a boundary belongs to
+ *
+ * Requires the `emitDebugName` plugin option.
+ */
+export const getBoundaryDebugName = (boundaryId: string): string | undefined =>
+  debugNameRegistry.get(boundaryId);
+
+/**
+ * Returns the names of the components enclosing a boundary in its file (outermost first)
+ *
+ * Requires the `emitOwnerStack` plugin option.
+ */
+export const getBoundaryOwnerStack = (
+  boundaryId: string,
+): string[] | undefined => ownerStackRegistry.get(boundaryId);
+
+/**
+ * Returns the route of the file a boundary is defined in (e.g. `/products/[id]`)
+ *
+ * Requires the `emitRouteHint` plugin option.
+ */
+export const getBoundaryRouteHint = (boundaryId: string): string | undefined =>
+  routeHintRegistry.get(boundaryId);
+
+/**
+ * Returns the imported name of a boundary component (e.g. `Suspense` or `ErrorBoundary`)
+ *
+ * Requires the `emitOriginalName` plugin option.
+ */
+export const getBoundaryOriginalName = (
+  boundaryId: string,
+): string | undefined => originalNameRegistry.get(boundaryId);
+
+/**
+ * Returns the number of boundaries enclosing a boundary in its file (`0` for the outermost)
+ *
+ * Requires the `emitDepth` plugin option.
+ */
+export const getBoundaryDepth = (boundaryId: string): number | undefined =>
+  depthRegistry.get(boundaryId);
+
+/**
+ * Returns the OpenTelemetry attributes of a boundary (e.g. `code.filepath` and `code.lineno`)
+ *
+ * Requires the `otel` plugin option with the default `otelPropName`.
+ */
+export const getBoundaryOtelAttributes = (
+  boundaryId: string,
+): OtelAttributes | undefined => otelAttributesRegistry.get(boundaryId);
+
+/**
+ * Returns the last error caught by every error boundary
+ *
+ * Requires the `reportErrors` plugin option - returns an empty array otherwise.
+ */
+export const getBoundaryErrors = (): BoundaryError[] => [
+  ...boundaryErrorRegistry.values(),
+];
+
 /**
  * Returns information about the nearest boundary above this component
  *
@@ -1,7 +1,268 @@
-import { createContext } from "react";
+import { createContext, use, useMemo } from "react";
 
 /** Boundary information: [boundaryId, BoundaryComponent] */
 export type BoundaryInfo = [string, React.ComponentType<any>];
 
 /** For internal use only */
 export const SuspenseContext = createContext<BoundaryInfo[]>([]);
+
+/** Statically known boundary information registered by the SWC plugin */
+export type RegisteredBoundary = {
+  id: string;
+  kind: "suspense" | "error";
+  component: string;
+};
+
+/** For internal use only */
+export const boundaryRegistry = new Map<string, RegisteredBoundary>();
+
+/** Lazy component information recorded by the SWC plugin */
+export type LazyInfo = {
+  lazyId: string;
+  importPath?: string;
+};
+
+/** For internal use only */
+export const lazyRegistry = new WeakMap<object, LazyInfo>();
+
+/** Download timing of the chunk loaded by a lazy component */
+export type ChunkTiming = {
+  /** ID of the `lazy()`, `dynamic()` or `loadable()` call loading the chunk */
+  lazyId: string;
+  importPath?: string;
+  /** How often the chunk was requested */
+  loadCount: number;
+  /** Duration of the latest load in milliseconds (`null` while pending) */
+  lastDuration: number | null;
+  /** Whether the latest load failed */
+  failed: boolean;
+};
+
+/** For internal use only */
+export const chunkRegistry = new Map<string, ChunkTiming>();
+
+/** For internal use only - maps promises to the ID of the `use()` call which received them */
+export const promiseRegistry = new WeakMap<object, string>();
+
+/** Components which call a suspending API, recorded by the SWC plugin */
+export type SuspenderInfo = {
+  componentName: string;
+  componentId: string;
+  /** IDs of the boundaries above the component, innermost first */
+  boundaryIds: string[];
+};
+
+/** For internal use only - maps component IDs to their latest render */
+export const suspenderRegistry = new Map<string, SuspenderInfo>();
+
+/** The latest transition started through a tracked `startTransition` call */
+export type TransitionInfo = {
+  transitionId: string;
+  startTime: number;
+};
+
+/** For internal use only */
+export const transitionState: { last: TransitionInfo | null } = { last: null };
+
+/** For internal use only - maps `useDeferredValue` call sites to the time of their latest render */
+export const deferredValueRegistry = new Map<string, number>();
+
+/** Commit timings of a boundary measured by the injected `<Profiler>` */
+export type BoundaryRenderTiming = {
+  boundaryId: string;
+  phase: "mount" | "update" | "nested-update";
+  actualDuration: number;
+  baseDuration: number;
+  startTime: number;
+  commitTime: number;
+  commitCount: number;
+};
+
+/** For internal use only - maps boundary IDs to their latest commit */
+export const renderTimingRegistry = new Map<string, BoundaryRenderTiming>();
+
+/** How long the fallback of a boundary was displayed */
+export type FallbackTiming = {
+  boundaryId: string;
+  /** How often the fallback was shown */
+  showCount: number;
+  /** Start of the currently visible fallback (`null` if hidden) */
+  visibleSince: number | null;
+  /** Start of the latest fallback display */
+  lastShownAt: number | null;
+  /** Total duration of all fallback displays which already ended */
+  totalDuration: number;
+};
+
+/** For internal use only */
+export const fallbackRegistry = new Map<string, FallbackTiming>();
+
+/** When the content of a boundary was mounted */
+export type ContentMountTiming = {
+  boundaryId: string;
+  mountedAt: number;
+  /** Time between showing the fallback and mounting the content (`null` if no fallback was shown) */
+  timeToContent: number | null;
+};
+
+/** For internal use only */
+export const contentMountRegistry = new Map<string, ContentMountTiming>();
+
+/** Library specific boundary props (e.g. `FallbackComponent`) in a normalized shape */
+export type NormalizedBoundaryProps = {
+  fallback?: unknown;
+  onError?: (...args: any[]) => void;
+  onReset?: (...args: any[]) => void;
+  resetKeys?: unknown[];
+};
+
+/** For internal use only */
+export const boundaryPropsRegistry = new Map<string, NormalizedBoundaryProps>();
+
+export type BoundaryError = {
+  boundaryId: string;
+  error: unknown;
+  componentStack: string | null;
+  caughtAt: number;
+};
+
+/** For internal use only */
+export const boundaryErrorRegistry = new Map<string, BoundaryError>();
+
+/** Location of a boundary in the shape of the `__source` prop of React's dev runtime */
+export type BoundarySource = {
+  fileName: string;
+  lineNumber: number;
+  columnNumber: number;
+};
+
+/** For internal use only */
+export const boundarySourceRegistry = new Map<string, BoundarySource>();
+
+/** For internal use only */
+export const debugNameRegistry = new Map<string, string>();
+
+/** For internal use only */
+export const ownerStackRegistry = new Map<string, string[]>();
+
+/** For internal use only */
+export const routeHintRegistry = new Map<string, string>();
+
+/** For internal use only */
+export const originalNameRegistry = new Map<string, string>();
+
+/** For internal use only */
+export const depthRegistry = new Map<string, number>();
+
+/** OpenTelemetry attributes of a boundary (e.g. `code.filepath` and `code.lineno`) */
+export type OtelAttributes = Record<string, string | number | null>;
+
+/** For internal use only */
+export const otelAttributesRegistry = new Map<string, OtelAttributes>();
+
+/** Props shared by the tracker components of all boundary kinds */
+/** Metadata passed as a single prop if the SWC plugin runs with `emitStyle: "object"` */
+export type BoundaryMeta = {
+  id: string;
+  kind: "suspense" | "error";
+  /** Name of the component rendering the boundary */
+  owner: string | null;
+  /** Name of the fallback element (e.g. `Spinner` or `div`) */
+  fallbackName: string | null;
+  boundary: React.ComponentType<any> | string;
+};
+
+/**
+ * For internal use only - resolves the `boundaryId` and `boundary` props (and the owner
+ * as `debugName`) from `__boundaryMeta` if the SWC plugin runs with `emitStyle: "object"`
+ */
+export const resolveBoundaryMeta = <P extends { __boundaryMeta?: BoundaryMeta }>({
+  __boundaryMeta,
+  ...props
+}: P) =>
+  (__boundaryMeta
+    ? {
+        debugName: __boundaryMeta.owner ?? undefined,
+        ...props,
+        boundaryId: __boundaryMeta.id,
+        boundary: __boundaryMeta.boundary,
+      }
+    : props) as Omit<P, "__boundaryMeta">;
+
+export type TrackerProps = {
+  boundaryId: string;
+  boundary: React.ComponentType<any>;
+  /** Maps library specific props to the normalized boundary props */
+  boundaryPropMap?: Record<string, keyof NormalizedBoundaryProps>;
+  /** Name of the variable or component the boundary belongs to */
+  debugName?: string;
+  /** Names of the components enclosing the boundary (outermost first) */
+  ownerStack?: string[];
+  /** Route of the file the boundary is defined in (e.g. `/products/[id]`) */
+  routeHint?: string;
+  /** Imported name of the boundary component (e.g. `Suspense`) */
+  originalName?: string;
+  /** Number of boundaries enclosing the boundary in its file */
+  depth?: number;
+  /** OpenTelemetry attributes of the boundary if the SWC plugin runs with `otel: true` */
+  otelAttributes?: OtelAttributes;
+  /** Location of the boundary if the JSX dev transform didn't add `__source` */
+  __boundarySource?: BoundarySource;
+  /** Replaces `boundaryId` and `boundary` if the SWC plugin runs with `emitStyle: "object"` */
+  __boundaryMeta?: BoundaryMeta;
+};
+
+/**
+ * For internal use only - records the static information of a tracked boundary
+ * and returns the boundary stack for the `SuspenseContext` of its children
+ */
+export const useBoundaryTracking = (
+  {
+    boundaryId,
+    boundary,
+    boundaryPropMap,
+    debugName,
+    ownerStack,
+    routeHint,
+    originalName,
+    depth,
+    otelAttributes,
+    __boundarySource,
+  }: TrackerProps,
+  boundaryProps: Record<string, any>,
+): BoundaryInfo[] => {
+  if (debugName) {
+    debugNameRegistry.set(boundaryId, debugName);
+  }
+  if (ownerStack) {
+    ownerStackRegistry.set(boundaryId, ownerStack);
+  }
+  if (routeHint) {
+    routeHintRegistry.set(boundaryId, routeHint);
+  }
+  if (originalName) {
+    originalNameRegistry.set(boundaryId, originalName);
+  }
+  if (depth !== undefined) {
+    depthRegistry.set(boundaryId, depth);
+  }
+  if (otelAttributes) {
+    otelAttributesRegistry.set(boundaryId, otelAttributes);
+  }
+  const source = __boundarySource ?? boundaryProps.__source;
+  if (source) {
+    boundarySourceRegistry.set(boundaryId, source);
+  }
+  if (boundaryPropMap) {
+    const normalizedProps: NormalizedBoundaryProps = {};
+    for (const [prop, normalizedProp] of Object.entries(boundaryPropMap)) {
+      normalizedProps[normalizedProp] = boundaryProps[prop];
+    }
+    boundaryPropsRegistry.set(boundaryId, normalizedProps);
+  }
+  const parentContext = use(SuspenseContext);
+  return useMemo<BoundaryInfo[]>(
+    () => [[boundaryId, boundary], ...parentContext],
+    [parentContext, boundaryId, boundary],
+  );
+};
@@ -0,0 +1,215 @@
+use swc_core::{
+    common::{
+        comments::{Comment, CommentKind},
+        util::take::Take,
+        DUMMY_SP,
+    },
+    ecma::{
+        ast::*,
+        visit::{Visit, VisitMut, VisitMutWith, VisitWith},
+    },
+};
+
+use crate::{
+    ast_utils::{call_expr, ident, str_expr},
+    imports::DEFAULT_IMPORT,
+    TransformVisitor,
+};
+
+const TRACK_LAZY_IMPORT_NAME: &str = "trackLazy";
+const TRACK_CHUNK_IMPORT_NAME: &str = "__trackChunk";
+/// Prefix of the webpack chunk names derived from boundary IDs
+const CHUNK_NAME_PREFIX: &str = "b__";
+const WEBPACK_CHUNK_NAME_COMMENT: &str = "webpackChunkName";
+
+impl TransformVisitor {
+    /// Wraps `lazy(() => import("./x"))` into `trackLazy(lazy(...), "file:line", "./x")`
+    ///
+    /// Also handles `next/dynamic` in both of its call signatures:
+    /// `dynamic(() => import("./x"), options)` and `dynamic({ loader: () => import("./x") })`
+    /// and `@loadable/component` factories (`loadable(() => import("./x"))`)
+    pub(crate) fn track_lazy_call(&mut self, expr: &mut Expr) {
+        let Expr::Call(call) = expr else {
+            return;
+        };
+        let tracks_lazy = self.is_lazy_factory(&call.callee);
+        let tracks_chunks = self.config.track_chunks && self.creates_lazy_component(&call.callee);
+        if !tracks_lazy && !tracks_chunks {
+            return;
+        }
+
+        let line = self.lookup_line(call.span.lo);
+        let source = dynamic_import_source(call);
+        let id = self.generate_boundary_id(line);
+        if self.config.emit_chunk_names {
+            self.add_chunk_name_comment(call, &id);
+        }
+        if tracks_chunks {
+            self.track_chunk_imports(call, &id);
+        }
+        if !tracks_lazy {
+            return;
+        }
+
+        let mut args = vec![expr.take(), str_expr(&id)];
+        if let Some(source) = source {
+            args.push(str_expr(&source));
+        }
+        self.add_runtime_import(TRACK_LAZY_IMPORT_NAME);
+        *expr = Expr::Call(self.pure_call(TRACK_LAZY_IMPORT_NAME, args));
+    }
+
+    /// Wraps the `import("./x")` of a lazy factory into `__trackChunk(import("./x"), "file:line", "./x")`
+    /// which measures the download of the chunk for the lazy component with the same ID
+    fn track_chunk_imports(&mut self, call: &mut CallExpr, id: &str) {
+        let mut wrapper = ChunkImportWrapper { id, found: false };
+        call.args.visit_mut_with(&mut wrapper);
+        if wrapper.found {
+            self.add_runtime_import(TRACK_CHUNK_IMPORT_NAME);
+        }
+    }
+
+    /// Adds `/* webpackChunkName: "b__src_App_tsx_30_15" */` (the ID and the column of the
+    /// factory) to the first `import("./x")` of a lazy factory so the chunk can be mapped to
+    /// the ID, unless it already has a chunk name
+    ///
+    /// Only the first import is named as webpack merges the imports of one name into one chunk,
+    /// for the same reason repeated names (e.g. without known lines and columns) get a `_2`,
+    /// `_3`, ... suffix.
+    fn add_chunk_name_comment(&mut self, call: &CallExpr, id: &str) {
+        let Some(comments) = &self.comments else {
+            return;
+        };
+        let Some(specifier) = dynamic_import_specifier(call) else {
+            return;
+        };
+        if specifier.span.is_dummy() {
+            return;
+        }
+        let has_chunk_name = comments
+            .get_leading(specifier.span.lo)
+            .into_iter()
+            .chain(comments.get_trailing(specifier.span.hi))
+            .flatten()
+            .any(|comment| comment.text.contains(WEBPACK_CHUNK_NAME_COMMENT));
+        if has_chunk_name {
+            return;
+        }
+        let mut name = chunk_name(id, self.lookup_column(call.span.lo));
+        let count = self.chunk_name_counts.entry(name.clone()).or_default();
+        *count += 1;
+        if *count > 1 {
+            name = format!("{name}_{count}");
+        }
+        let Some(comments) = &self.comments else {
+            return;
+        };
+        comments.add_leading(
+            specifier.span.lo,
+            Comment {
+                kind: CommentKind::Block,
+                span: DUMMY_SP,
+                text: format!(" {WEBPACK_CHUNK_NAME_COMMENT}: \"{name}\" ").into(),
+            },
+        );
+    }
+
+    /// Checks if a callee creates a lazy component, independent of the tracking options
+    pub(crate) fn creates_lazy_component(&self, callee: &Callee) -> bool {
+        self.is_imported_call(callee, "react", "lazy")
+            || self.is_imported_call(callee, "next/dynamic", DEFAULT_IMPORT)
+            || self.is_imported_call(callee, "@loadable/component", DEFAULT_IMPORT)
+            || self.is_imported_call(callee, "@loadable/component", "lazy")
+    }
+
+    /// Checks if a callee creates a lazy component and is tracked
+    fn is_lazy_factory(&self, callee: &Callee) -> bool {
+        (self.config.track_lazy && self.is_imported_call(callee, "react", "lazy"))
+            || (self.config.track_dynamic
+                && self.is_imported_call(callee, "next/dynamic", DEFAULT_IMPORT))
+            || (self.config.track_loadable
+                && (self.is_imported_call(callee, "@loadable/component", DEFAULT_IMPORT)
+                    || self.is_imported_call(callee, "@loadable/component", "lazy")))
+    }
+}
+
+/// Returns the module specifier of the first `import("...")` inside a lazy factory call
+pub(crate) fn dynamic_import_source(call: &CallExpr) -> Option<String> {
+    dynamic_import_specifier(call)?
+        .value
+        .as_str()
+        .map(str::to_string)
+}
+
+/// Returns the string literal specifier of the first `import("...")` inside a lazy factory call
+fn dynamic_import_specifier(call: &CallExpr) -> Option<Str> {
+    let mut finder = DynamicImportFinder { specifier: None };
+    call.args.visit_with(&mut finder);
+    finder.specifier
+}
+
+/// Returns `b__src_App_tsx_30_15` for the ID `src/App.tsx:30` and the column 15
+/// (`b__src_App_tsx_30` if the column is unknown)
+fn chunk_name(id: &str, column: usize) -> String {
+    let id = if column > 0 {
+        format!("{id}:{column}")
+    } else {
+        id.to_string()
+    };
+    let name: String = id
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    format!("{CHUNK_NAME_PREFIX}{name}")
+}
+
+/// Finds the first dynamic `import("...")` with a string literal specifier
+struct DynamicImportFinder {
+    specifier: Option<Str>,
+}
+
+impl Visit for DynamicImportFinder {
+    fn visit_call_expr(&mut self, call: &CallExpr) {
+        if self.specifier.is_some() {
+            return;
+        }
+        if let Callee::Import(_) = &call.callee {
+            if let Some(Lit::Str(specifier)) = call.args.first().and_then(|arg| arg.expr.as_lit()) {
+                self.specifier = Some(specifier.clone());
+                return;
+            }
+        }
+        call.visit_children_with(self);
+    }
+}
+
+/// Wraps the dynamic `import()` calls of a lazy factory with `__trackChunk`
+struct ChunkImportWrapper<'a> {
+    id: &'a str,
+    found: bool,
+}
+
+impl VisitMut for ChunkImportWrapper<'_> {
+    fn visit_mut_expr(&mut self, expr: &mut Expr) {
+        let Expr::Call(CallExpr {
+            callee: Callee::Import(_),
+            args,
+            ..
+        }) = expr
+        else {
+            expr.visit_mut_children_with(self);
+            return;
+        };
+        let source = match args.first().and_then(|arg| arg.expr.as_lit()) {
+            Some(Lit::Str(specifier)) => specifier.value.as_str().map(str::to_string),
+            _ => None,
+        };
+
+        let mut call_args = vec![expr.take(), str_expr(self.id)];
+        if let Some(source) = source {
+            call_args.push(str_expr(&source));
+        }
+        *expr = call_expr(ident(TRACK_CHUNK_IMPORT_NAME), call_args);
+        self.found = true;
+    }
+}
@@ -1,274 +1,1132 @@
 #![allow(clippy::not_unsafe_ptr_arg_deref)]
 
-use std::collections::HashSet;
-use swc_core::common::{BytePos, SourceMapper};
-use swc_core::plugin::proxies::PluginSourceMapProxy;
+use ast_utils::{
+    array_expr, call_expr, call_node, export_const_item, expr_stmt_item, ident, jsx_expr_attr,
+    jsx_str_attr, named_import, num_expr, object_expr, str_expr,
+};
+use imports::{collect_import_bindings, ImportBinding};
+use lines::LineCache;
+use loops::is_array_callback_call;
+use props::fallback_name;
+use refresh::is_refresh_registration;
+use scopes::{arrow_bindings, block_bindings, catch_bindings, function_bindings};
+use std::collections::{HashMap, HashSet};
+use swc_core::common::{
+    comments::{Comment, CommentKind, Comments},
+    sync::Lrc,
+    BytePos, SourceMapper, Span, Spanned,
+};
+#[cfg(feature = "plugin")]
+use swc_core::plugin::plugin_transform;
 use swc_core::{
     common::DUMMY_SP,
     ecma::{
         ast::*,
-        visit::{visit_mut_pass, VisitMut, VisitMutWith},
+        visit::{VisitMut, VisitMutWith},
     },
+};
+#[cfg(feature = "embed")]
+use swc_core::{
+    ecma::visit::visit_mut_pass,
     plugin::{
-        metadata::TransformPluginMetadataContextKind, plugin_transform,
-        proxies::TransformPluginProgramMetadata,
+        metadata::TransformPluginMetadataContextKind, proxies::TransformPluginProgramMetadata,
     },
 };
 
+mod apollo;
+mod ast_utils;
+mod awaits;
+mod builder;
+mod cleanup;
+mod diagnostics;
+mod glob;
+mod hooks;
+mod ids;
+mod imports;
+mod lazy;
+#[cfg(feature = "library")]
+mod library;
+mod lines;
+mod loops;
+mod metadata;
+#[cfg(feature = "napi")]
+mod node;
+mod prescan;
+mod props;
+mod refresh;
+mod relay;
+mod routes;
+mod scopes;
 mod settings;
+mod strip;
+mod suspenders;
+mod suspending_hooks;
+mod throws;
+mod transitions;
+mod wrappers;
 
-pub use settings::{Boundary, Config, Context, Environment};
+pub use builder::TransformVisitorBuilder;
+use diagnostics::SuspenderCoverage;
+pub use diagnostics::{Diagnostic, Severity};
+#[cfg(feature = "library")]
+pub use library::{analyze_source, check_source, transform_source, TransformOutput};
+pub use metadata::BoundaryMetadata;
+pub use settings::{
+    ApolloHook, ApolloInstrumentation, Boundary, BoundaryBudget, BoundaryKind, BoundaryRef,
+    CallArgument, Callbacks, Config, ConfigOverride, Context, EmitStyle, EntryPoints, Environment,
+    ForbiddenBoundary, HookInjection, IdStrategy, ImportPlacement, InjectCall, Mode, ModuleExport,
+    Platform, RelayHook, RouteConvention, RouteStyle, RuleSeverity, SentryTagging, SuspendingHook,
+};
 
 const BOUNDARY_TRACKER_PACKAGE_NAME: &str = "react-swc-suspense-tracker/context";
+const TEST_BOUNDARY_TRACKER_PACKAGE_NAME: &str = "react-swc-suspense-tracker/context-test";
+const NATIVE_BOUNDARY_TRACKER_PACKAGE_NAME: &str = "react-swc-suspense-tracker/context-native";
 const BOUNDARY_TRACKER_IMPORT_NAME: &str = "BoundaryTrackerSWC";
+const ERROR_BOUNDARY_TRACKER_PACKAGE_NAME: &str = "react-swc-suspense-tracker/error-context";
+const ERROR_BOUNDARY_TRACKER_IMPORT_NAME: &str = "ErrorBoundaryTrackerSWC";
 const BOUNDARY_ID_PROPERTY_NAME: &str = "boundaryId";
 const BOUNDARY_NAME_PROPERTY_NAME: &str = "boundary";
+const REGISTER_BOUNDARIES_IMPORT_NAME: &str = "registerBoundaries";
+const BOUNDARIES_EXPORT_NAME: &str = "__SUSPENSE_BOUNDARIES__";
+/// Comments which only apply to the next line and therefore stay with the first module item
+const NEXT_LINE_COMMENT_MARKERS: [&str; 4] = [
+    "eslint-disable-next-line",
+    "@ts-ignore",
+    "@ts-expect-error",
+    "prettier-ignore",
+];
 
-struct TransformVisitor {
+/// Visitor which replaces boundary elements with the boundary tracker
+pub struct TransformVisitor {
     config: Config,
     context: Context,
     /// Set of boundary configurations
     boundary_contexts: HashSet<Boundary>,
-    /// Valid Boundary Idents
-    valid_boundary_idents: HashSet<Ident>,
+    /// Valid Boundary Idents (interned symbol and syntax context)
+    /// and the boundary configuration they were imported for
+    valid_boundary_idents: HashMap<Id, Boundary>,
     /// Track if boundary imports have been added (plugin only adds one import)
     boundary_imports_added: bool,
     /// Track if we have any boundary elements to transform
     has_boundary_elements: bool,
+    /// All imported bindings of the module
+    imports: HashMap<Id, ImportBinding>,
+    /// Names which have to be imported as `(imported, local)`, grouped by module
+    /// (the tracker package comes first)
+    runtime_imports: Vec<(String, Vec<(String, String)>)>,
+    /// Names of all bindings of the module, only collected for `callbacks` as the runtime
+    /// names of the tracker don't collide with user code
+    binding_names: HashSet<String>,
     /// Optional source map for line number mapping
-    source_map: Option<PluginSourceMapProxy>,
+    source_map: Option<Lrc<dyn SourceMapper>>,
+    /// Raw source text for line numbers if there is no source map
+    source_text: Option<String>,
+    /// Line starts of the file resolved by the first source map lookup
+    line_cache: LineCache,
+    /// The filename used for boundary IDs (relative, with `/` separators)
+    normalized_filename: String,
+    /// The route of the file (only with `emit_route_hint`)
+    route_hint: Option<String>,
+    /// Optional comments for comment based output
+    comments: Option<Box<dyn Comments>>,
+    /// Boundaries found while transforming the module
+    boundaries: Vec<BoundaryMetadata>,
+    /// How often each generated boundary ID was given to a boundary of the module
+    boundary_id_counts: HashMap<String, usize>,
+    /// How often each webpack chunk name was given to a lazy factory (only with `emit_chunk_names`)
+    chunk_name_counts: HashMap<String, usize>,
+    /// The last numeric boundary ID given to a boundary of the module
+    numeric_id_count: usize,
+    /// Boundary IDs hoisted into module level constants (only with `hoist_ids`)
+    hoisted_ids: Vec<String>,
+    /// Names of the functions and classes enclosing the current node
+    component_stack: Vec<String>,
+    /// `startTransition` functions returned by `useTransition()`
+    transition_bindings: HashSet<Id>,
+    /// The span of a JSX element assigned to a variable and the variable name
+    jsx_binding: Option<(Span, String)>,
+    /// Local bindings which shadow a boundary import (only without resolver)
+    shadowed_boundaries: Vec<Id>,
+    /// Problems found by the diagnostics rules
+    diagnostics: Vec<Diagnostic>,
+    /// Fallbacks of the enclosing Suspense boundaries (only with `nested-duplicate-fallback`)
+    fallback_stack: Vec<Expr>,
+    /// IDs of the boundaries enclosing the current element
+    boundary_ids: Vec<String>,
+    /// Suspending components and where they are rendered (only with `unwrapped-suspender`
+    /// or `unguarded-lazy`)
+    coverage: SuspenderCoverage,
+    /// Number of boundary elements enclosing the current element
+    boundary_depth: usize,
+    /// The `key` of every enclosing array callback (`None` until a keyed element is entered)
+    loop_keys: Vec<Option<Expr>>,
 }
 
 impl TransformVisitor {
-    pub fn new(config: Config, context: Context, source_map: Option<PluginSourceMapProxy>) -> Self {
-        let mut boundary_contexts = HashSet::new();
+    pub fn new(
+        config: Config,
+        context: Context,
+        source_map: Option<Lrc<dyn SourceMapper>>,
+    ) -> Self {
+        let mut builder = Self::builder().config(config).context(context);
+        if let Some(source_map) = source_map {
+            builder = builder.source_map(source_map);
+        }
+        builder.build()
+    }
 
-        // Always add Suspense from "react" as a default boundary
-        boundary_contexts.insert(Boundary {
-            component: "Suspense".to_string(),
-            from: "react".to_string(),
-        });
+    /// Creates a [`TransformVisitorBuilder`]
+    pub fn builder() -> TransformVisitorBuilder {
+        TransformVisitorBuilder::default()
+    }
+
+    /// Boundaries found while transforming the module
+    pub fn boundaries(&self) -> &[BoundaryMetadata] {
+        &self.boundaries
+    }
+
+    /// Generates a unique ID for a custom boundary element based on boundary name, file and line
+    fn generate_boundary_id(&self, line: usize) -> String {
+        format!("{}:{line}", self.normalized_filename)
+    }
 
-        // Add user-configured boundaries
-        for boundary_config in config.boundaries.iter() {
-            boundary_contexts.insert(boundary_config.clone());
+    /// Looks up the line of a position (0 if neither a source map nor the source text is available)
+    fn lookup_line(&self, pos: BytePos) -> usize {
+        match &self.source_map {
+            Some(source_map) => self.line_cache.lookup_line(source_map.as_ref(), pos),
+            None => self.line_cache.source_line(pos),
         }
+    }
 
-        Self {
-            config,
-            context,
-            boundary_contexts,
-            valid_boundary_idents: HashSet::new(),
-            boundary_imports_added: false,
-            has_boundary_elements: false,
-            source_map,
+    /// Returns the 1-based column of a position (0 without a source map or source text)
+    fn lookup_column(&self, pos: BytePos) -> usize {
+        match &self.source_map {
+            Some(source_map) => self.line_cache.lookup_column(source_map.as_ref(), pos),
+            None => self.line_cache.source_column(pos),
         }
     }
 
-    /// Generates a unique ID for a custom boundary element based on boundary name, file and line
-    fn generate_boundary_id(&self, pos: BytePos) -> String {
-        let filename = self.context.filename.clone();
-        let cleaned = filename
-            .strip_prefix("./")
-            .or_else(|| filename.strip_prefix("/"))
-            .unwrap_or(&filename)
-            .replace('\\', "/");
-
-        let line = self
-            .source_map
-            .as_ref()
-            .map_or(0, |source_map| source_map.lookup_char_pos(pos).line);
-        format!("{cleaned}:{line}")
-    }
-
-    /// Creates the BoundaryTracker import if needed
-    fn create_boundary_tracker_import(&self) -> ModuleItem {
-        ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
-            span: DUMMY_SP,
-            specifiers: vec![ImportSpecifier::Named(ImportNamedSpecifier {
+    /// Adds a `/* <marker> [...] */` comment with all found boundaries at `pos`
+    fn add_manifest_comment(&self, pos: BytePos) {
+        let Some(comments) = &self.comments else {
+            return;
+        };
+        let manifest = serde_json::to_string(&self.boundaries)
+            .expect("failed to serialize boundaries")
+            // Keep the JSON from closing the comment
+            .replace("*/", "*\\/");
+        comments.add_trailing(
+            pos,
+            Comment {
+                kind: CommentKind::Block,
                 span: DUMMY_SP,
-                local: Ident {
-                    ctxt: Default::default(),
-                    span: DUMMY_SP,
-                    sym: BOUNDARY_TRACKER_IMPORT_NAME.into(),
-                    optional: false,
-                },
-                imported: None,
-                is_type_only: false,
-            })],
-            src: Box::new(Str {
+                text: format!(" {} {manifest} ", self.config.manifest_marker()).into(),
+            },
+        );
+    }
+
+    /// Adds a `/* suspense-tracker: ... */` comment summarizing the transformation at `pos`
+    fn add_stats_comment(&self, pos: BytePos) {
+        let Some(comments) = &self.comments else {
+            return;
+        };
+        let imports = if self.boundary_imports_added {
+            self.runtime_imports.len()
+        } else {
+            0
+        };
+        let plural = |count: usize, singular: &str, plural: &str| {
+            format!("{count} {}", if count == 1 { singular } else { plural })
+        };
+        comments.add_trailing(
+            pos,
+            Comment {
+                kind: CommentKind::Block,
                 span: DUMMY_SP,
-                value: BOUNDARY_TRACKER_PACKAGE_NAME.into(),
-                raw: None,
-            }),
-            type_only: false,
-            with: None,
-            phase: ImportPhase::Evaluation,
-        }))
+                text: format!(
+                    " suspense-tracker: {}, {} injected ",
+                    plural(self.boundaries.len(), "boundary", "boundaries"),
+                    plural(imports, "import", "imports"),
+                )
+                .into(),
+            },
+        );
+    }
+
+    /// Adds a `/* @boundary id=src/App.tsx:12 kind=suspense */` comment at `pos`
+    fn add_boundary_annotation(&self, pos: BytePos, id: &str, kind: BoundaryKind) {
+        let Some(comments) = &self.comments else {
+            return;
+        };
+        comments.add_leading(
+            pos,
+            Comment {
+                kind: CommentKind::Block,
+                span: DUMMY_SP,
+                // Keep the ID from closing the comment
+                text: format!(
+                    " @boundary id={} kind={} ",
+                    id.replace("*/", "*\\/"),
+                    kind.as_str()
+                )
+                .into(),
+            },
+        );
+    }
+
+    /// Creates the BoundaryTracker import (and imports of custom runtime modules)
+    /// with all required runtime names
+    fn create_boundary_tracker_imports(&self) -> Vec<ModuleItem> {
+        self.runtime_imports
+            .iter()
+            .map(|(src, names)| {
+                let names: Vec<(&str, &str)> = names
+                    .iter()
+                    .map(|(imported, local)| (imported.as_str(), local.as_str()))
+                    .collect();
+                named_import(&names, src)
+            })
+            .collect()
+    }
+
+    /// Moves the banner and pragma comments (e.g. a license or `// @ts-nocheck`) of the first
+    /// module item at `first_item_lo` to the tracker import which is inserted before it
+    ///
+    /// Comments which only apply to the next line (e.g. `// eslint-disable-next-line`)
+    /// stay with the item.
+    fn move_banner_comments(&self, first_item_lo: BytePos, tracker_import: &mut ModuleItem) {
+        let Some(comments) = &self.comments else {
+            return;
+        };
+        let ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)) = tracker_import else {
+            return;
+        };
+        let Some(leading) = comments.take_leading(first_item_lo) else {
+            return;
+        };
+        let (next_line, banner): (Vec<Comment>, Vec<Comment>) =
+            leading.into_iter().partition(applies_to_next_line);
+        if !next_line.is_empty() {
+            comments.add_leading_comments(first_item_lo, next_line);
+        }
+        if !banner.is_empty() {
+            import_decl.span = Span::dummy_with_cmt();
+            comments.add_leading_comments(import_decl.span.lo, banner);
+        }
+    }
+
+    /// Marks a name of the tracker package as used so it gets imported
+    fn add_runtime_import(&mut self, name: &'static str) {
+        self.add_runtime_import_from(self.tracker_package(), name);
+    }
+
+    /// Returns the tracker package of the target platform
+    fn tracker_package(&self) -> &'static str {
+        match self.config.platform {
+            Platform::Web => BOUNDARY_TRACKER_PACKAGE_NAME,
+            Platform::ReactNative => NATIVE_BOUNDARY_TRACKER_PACKAGE_NAME,
+        }
+    }
+
+    /// Marks a name of a (custom) runtime module as used so it gets imported
+    fn add_runtime_import_from(&mut self, src: &str, name: &str) {
+        self.add_runtime_import_as(src, name, name);
+    }
+
+    /// Marks a name of a (custom) runtime module as used so it gets imported as `local`
+    /// (a name which is already imported keeps its first local name)
+    fn add_runtime_import_as(&mut self, src: &str, name: &str, local: &str) {
+        let import = (name.to_string(), local.to_string());
+        match self
+            .runtime_imports
+            .iter_mut()
+            .find(|(import_src, _)| import_src == src)
+        {
+            Some((_, names)) if names.iter().any(|(imported, _)| imported == name) => {}
+            Some((_, names)) => names.push(import),
+            None => self.runtime_imports.push((src.to_string(), vec![import])),
+        }
+    }
+
+    /// Returns the local binding of a runtime export and marks it for import unless
+    /// the module already imports it (e.g. `import { BoundaryTrackerSWC as Tracker } from "..."`)
+    ///
+    /// The import is renamed (e.g. `import { report as _report } from "..."`) if the module
+    /// has another binding with the same name.
+    fn runtime_binding(&mut self, src: &str, name: &str) -> Ident {
+        let existing = self
+            .imports
+            .iter()
+            .find(|(_, binding)| binding.is(src, name))
+            .map(|((sym, ctxt), _)| Ident::new(sym.clone(), DUMMY_SP, *ctxt));
+        if let Some(existing) = existing {
+            return existing;
+        }
+        let local = self
+            .runtime_imports
+            .iter()
+            .filter(|(import_src, _)| import_src == src)
+            .flat_map(|(_, names)| names)
+            .find(|(imported, _)| imported == name)
+            .map(|(_, local)| local.clone())
+            .unwrap_or_else(|| self.unused_binding_name(name));
+        self.add_runtime_import_as(src, name, &local);
+        ident(&local)
+    }
+
+    /// Returns `name` or, if the module already has a binding with that name,
+    /// `_name`, `_name2`, ... whichever is not bound yet
+    fn unused_binding_name(&self, name: &str) -> String {
+        if !self.binding_names.contains(name) {
+            return name.to_string();
+        }
+        (1..)
+            .map(|index| match index {
+                1 => format!("_{name}"),
+                index => format!("_{name}{index}"),
+            })
+            .find(|candidate| !self.binding_names.contains(candidate))
+            .expect("an unused name")
+    }
+
+    /// Returns the module and the name of the tracker component of a boundary kind
+    fn tracker_import(&self, kind: BoundaryKind) -> (String, &'static str) {
+        let entry_points = &self.config.entry_points;
+        match kind {
+            BoundaryKind::Suspense => (
+                entry_points
+                    .suspense
+                    .as_deref()
+                    .unwrap_or(if self.config.test_mode && self.config.test_entry {
+                        TEST_BOUNDARY_TRACKER_PACKAGE_NAME
+                    } else {
+                        self.tracker_package()
+                    })
+                    .to_string(),
+                BOUNDARY_TRACKER_IMPORT_NAME,
+            ),
+            BoundaryKind::Error => (
+                entry_points
+                    .error
+                    .as_deref()
+                    .unwrap_or(ERROR_BOUNDARY_TRACKER_PACKAGE_NAME)
+                    .to_string(),
+                ERROR_BOUNDARY_TRACKER_IMPORT_NAME,
+            ),
+        }
+    }
+
+    /// Returns the modules of the Suspense and error tracker components
+    /// (the configured entry points or the default runtime modules)
+    fn tracker_sources(&self) -> [String; 2] {
+        [BoundaryKind::Suspense, BoundaryKind::Error].map(|kind| self.tracker_import(kind).0)
+    }
+
+    /// Whether any boundary or (if calls are tracked) any other binding was imported
+    /// or async components or thrown promises are tracked
+    fn has_trackable_imports(&self) -> bool {
+        // Async components and thrown promises don't need to import anything
+        (self.config.track_async_components && self.tracks_calls())
+            || self.tracks_thrown_promises()
+            || !self.valid_boundary_idents.is_empty()
+            || (!self.imports.is_empty() && (self.tracks_calls() || self.tracks_coverage()))
+    }
+
+    /// Whether any call expressions (e.g. `lazy()`) should be instrumented
+    fn tracks_calls(&self) -> bool {
+        // Analyzing never changes the code
+        !self.config.mode.is_read_only()
+            && (self.config.track_lazy
+                || self.config.track_dynamic
+                || self.config.track_loadable
+                || self.config.track_chunks
+                || self.config.track_use
+                || self.config.track_tanstack_query
+                || self.config.track_swr
+                || self.config.track_relay
+                || self.config.track_apollo
+                || !self.config.suspending_hooks.is_empty()
+                || self.config.track_suspenders
+                || self.config.track_transitions
+                || self.config.track_deferred_value
+                || self.config.track_async_components
+                || self.config.track_thrown_promises)
+    }
+
+    /// Checks if a callee is the export `name` of `src`, either imported directly
+    /// (`lazy(...)`) or accessed through a default or namespace import (`React.lazy(...)`)
+    fn is_imported_call(&self, callee: &Callee, src: &str, name: &str) -> bool {
+        let Callee::Expr(callee) = callee else {
+            return false;
+        };
+        match &**callee {
+            Expr::Ident(ident) => self
+                .imports
+                .get(&ident.to_id())
+                .is_some_and(|binding| binding.is(src, name)),
+            Expr::Member(MemberExpr {
+                obj,
+                prop: MemberProp::Ident(prop),
+                ..
+            }) => {
+                &*prop.sym == name
+                    && obj
+                        .as_ident()
+                        .and_then(|obj| self.imports.get(&obj.to_id()))
+                        .is_some_and(|binding| binding.is_module_object(src))
+            }
+            _ => false,
+        }
+    }
+
+    /// Creates the call `callee(...args)` annotated with `/*#__PURE__*/`
+    /// so bundlers can drop it together with its unused result
+    fn pure_call(&self, callee: &str, args: Vec<Expr>) -> CallExpr {
+        let mut call = call_node(ident(callee), args);
+        if let Some(comments) = &self.comments {
+            call.span = Span::dummy_with_cmt();
+            comments.add_pure_comment(call.span.lo);
+        }
+        call
+    }
+
+    /// Creates the static `[{ id, kind, component }, ...]` list of all found boundaries
+    fn create_boundaries_array(&self) -> Expr {
+        array_expr(
+            self.boundaries
+                .iter()
+                .map(|boundary| {
+                    object_expr(vec![
+                        ("id", str_expr(&boundary.id)),
+                        ("kind", str_expr(boundary.kind.as_str())),
+                        ("component", str_expr(&boundary.component)),
+                    ])
+                })
+                .collect(),
+        )
+    }
+
+    /// Creates the `registerBoundaries([{ id, kind, component }, ...])` call
+    fn create_registry_call(&self) -> ModuleItem {
+        expr_stmt_item(call_expr(
+            ident(REGISTER_BOUNDARIES_IMPORT_NAME),
+            vec![self.create_boundaries_array()],
+        ))
+    }
+
+    /// Creates a `name(...args)` statement for every boundary of the module
+    fn create_injected_calls(&self, inject_call: &InjectCall) -> Vec<ModuleItem> {
+        self.boundaries
+            .iter()
+            .map(|boundary| {
+                let args = inject_call
+                    .args
+                    .iter()
+                    .map(|arg| self.boundary_field(boundary, *arg))
+                    .collect();
+                expr_stmt_item(call_expr(ident(&inject_call.name), args))
+            })
+            .collect()
+    }
+
+    /// Creates the value of a boundary field (e.g. `"src/App.tsx"` for `file`)
+    fn boundary_field(&self, boundary: &BoundaryMetadata, field: CallArgument) -> Expr {
+        match field {
+            CallArgument::Id => str_expr(&boundary.id),
+            CallArgument::Kind => str_expr(boundary.kind.as_str()),
+            CallArgument::Component => str_expr(&boundary.component),
+            CallArgument::From => str_expr(&boundary.from),
+            CallArgument::Line => num_expr(boundary.line),
+            CallArgument::File => str_expr(&self.normalized_filename),
+            CallArgument::EnclosingComponent => boundary
+                .enclosing_component
+                .as_deref()
+                .map_or(Expr::Lit(Lit::Null(Null { span: DUMMY_SP })), str_expr),
+        }
     }
 
     /// Processes boundary imports: collects boundary identifier contexts
-    fn process_boundary_import(&mut self, import_decl: &mut ImportDecl) {
-        let Str { value, .. } = *import_decl.src.clone();
+    fn process_boundary_import(&mut self, import_decl: &ImportDecl) {
+        let Some(src) = import_decl.src.value.as_str() else {
+            return;
+        };
 
         // Check each configured boundary (including the default Suspense) to see if this import matches
         for boundary_config in &self.boundary_contexts {
-            if value.as_str() == Some(boundary_config.from.as_str()) {
-                // This import is from a package that has boundaries
-                for spec in &import_decl.specifiers {
-                    if let ImportSpecifier::Named(named) = spec {
-                        // Check if the imported name matches the boundary component
-                        let is_match = named
-                            .imported
-                            .as_ref()
-                            .map(|imported| match imported {
-                                ModuleExportName::Ident(ident) => {
-                                    *ident.sym == *boundary_config.component
-                                }
-                                ModuleExportName::Str(str_lit) => {
-                                    str_lit.value.as_str()
-                                        == Some(boundary_config.component.as_str())
-                                }
-                                #[cfg(swc_ast_unknown)]
-                                _ => false,
-                            })
-                            .unwrap_or(*named.local.sym == *boundary_config.component);
-
-                        if is_match {
-                            self.valid_boundary_idents.insert(named.local.clone());
+            if src != boundary_config.from {
+                continue;
+            }
+            // This import is from a package that has boundaries
+            for spec in &import_decl.specifiers {
+                if let ImportSpecifier::Named(named) = spec {
+                    // Check if the imported name matches the boundary component
+                    let is_match = match &named.imported {
+                        Some(ModuleExportName::Ident(ident)) => {
+                            *ident.sym == *boundary_config.component
                         }
+                        Some(ModuleExportName::Str(str_lit)) => {
+                            str_lit.value.as_str() == Some(boundary_config.component.as_str())
+                        }
+                        #[cfg(swc_ast_unknown)]
+                        Some(_) => false,
+                        None => *named.local.sym == *boundary_config.component,
+                    };
+
+                    if is_match {
+                        self.valid_boundary_idents
+                            .insert(named.local.to_id(), boundary_config.clone());
                     }
                 }
             }
         }
     }
 
+    /// Returns the innermost enclosing function or class which looks like a component
+    fn enclosing_component(&self) -> Option<String> {
+        self.component_stack
+            .iter()
+            .rev()
+            .find(|name| name.starts_with(|c: char| c.is_ascii_uppercase()))
+            .cloned()
+    }
+
     /// Checks if a JSX element is a boundary that should be transformed
-    fn get_element_boundary_ident(&self, jsx_element: &JSXElement) -> Option<Ident> {
-        if let JSXElementName::Ident(ident) = &jsx_element.opening.name {
-            // Check if this is a valid boundary identifier
-            if self
-                .valid_boundary_idents
-                .iter()
-                .any(|valid_ident| *valid_ident.sym == ident.sym && valid_ident.ctxt == ident.ctxt)
-            {
-                return Some(ident.clone());
+    ///
+    /// Only matching elements are cloned, all other elements cost a single hash lookup
+    fn get_element_boundary_ident(&self, jsx_element: &JSXElement) -> Option<(Ident, Boundary)> {
+        let JSXElementName::Ident(ident) = &jsx_element.opening.name else {
+            return None;
+        };
+        self.valid_boundary_idents
+            .get(&ident.to_id())
+            .filter(|_| !self.is_shadowed(ident))
+            .map(|boundary| (ident.clone(), boundary.clone()))
+    }
+
+    /// Replaces the boundary element with its tracker (or tracks it in place depending
+    /// on the mode), its children are visited afterwards
+    fn transform_boundary_element(
+        &mut self,
+        jsx_element: &mut JSXElement,
+        boundary_ident: Ident,
+        boundary: Boundary,
+    ) {
+        let boundary_depth = self.boundary_depth;
+        // Boundaries nested deeper than `maxDepth` are left untouched
+        if self
+            .config
+            .max_depth()
+            .is_some_and(|max_depth| boundary_depth >= max_depth)
+        {
+            self.boundary_depth += 1;
+            return;
+        }
+
+        let line = self.lookup_line(jsx_element.span.lo);
+        let id_value = self.boundary_element_id(&boundary, line, jsx_element);
+        let kind = boundary.kind();
+        self.boundaries.push(BoundaryMetadata {
+            id: id_value.clone(),
+            kind,
+            component: boundary.component.clone(),
+            from: boundary.from.clone(),
+            line,
+            enclosing_component: self.enclosing_component(),
+            fallback: fallback_name(jsx_element),
+            parent: self.boundary_ids.last().cloned(),
+            location: (self.id_strategy(&boundary) == IdStrategy::Numeric)
+                .then(|| self.generate_boundary_id(line)),
+        });
+        self.check_forbidden_boundary(jsx_element, &boundary);
+        self.check_boundary_budget(jsx_element);
+        self.check_missing_fallback(jsx_element, kind, &boundary.component);
+        self.check_nested_duplicate_fallback(jsx_element, kind, &boundary.component);
+        self.check_unkeyed_loop_boundary(jsx_element, &boundary.component);
+        self.boundary_depth += 1;
+        self.boundary_ids.push(id_value.clone());
+
+        // Only report the boundary without touching the code
+        if self.config.mode.is_read_only() {
+            if self.config.mode == Mode::Annotate {
+                self.add_boundary_annotation(jsx_element.span.lo, &id_value, kind);
+            }
+            return;
+        }
+
+        self.has_boundary_elements = true;
+        self.strip_explicit_boundary_id(jsx_element);
+
+        // Keep the boundary and only track when its fallback is shown
+        if self.config.mode == Mode::Inline {
+            if kind == BoundaryKind::Suspense {
+                self.inline_track_fallback(jsx_element, &id_value);
+            }
+            return;
+        }
+
+        // Keep the exact boundary element and only provide its ID to the children
+        if self.config.mode == Mode::Provider {
+            self.provide_boundary_id(jsx_element, &id_value, boundary_ident);
+            return;
+        }
+
+        // Reuse a tracker the module already imports instead of importing it twice
+        let (tracker_src, tracker_name) = self.tracker_import(kind);
+        let tracker = self.runtime_binding(&tracker_src, tracker_name);
+
+        // Transform all boundaries to the tracker of their kind
+        // Change the element name to the tracker but keep the original position
+        // so source maps and component stacks still point to the boundary
+        let name_span = boundary_ident.span;
+        jsx_element.opening.name = JSXElementName::Ident(Ident {
+            span: name_span,
+            ..tracker.clone()
+        });
+
+        // Also update closing tag if it exists
+        if let Some(ref mut closing) = jsx_element.closing {
+            let closing_span = match &closing.name {
+                JSXElementName::Ident(closing_ident) => closing_ident.span,
+                _ => closing.span,
+            };
+            closing.name = JSXElementName::Ident(Ident {
+                span: closing_span,
+                ..tracker
+            });
+        }
+
+        // Add the id prop and the original boundary component
+        // at the position of the boundary name
+        let id = match self.dynamic_boundary_id(&id_value, jsx_element) {
+            Some(id) => id,
+            None if self.config.hoist_ids => self.hoisted_id(&id_value),
+            None => str_expr(&id_value),
+        };
+        let id = self.keyed_loop_id(&id_value, id);
+        if self.config.emit_style == EmitStyle::Object {
+            let meta =
+                self.boundary_meta_attr(jsx_element, id, &boundary, boundary_ident, name_span);
+            jsx_element.opening.attrs.push(meta);
+        } else {
+            let id_attr = match id {
+                Expr::Lit(Lit::Str(_)) => {
+                    jsx_str_attr(BOUNDARY_ID_PROPERTY_NAME, &id_value, name_span)
+                }
+                id => jsx_expr_attr(BOUNDARY_ID_PROPERTY_NAME, id, name_span),
+            };
+            jsx_element.opening.attrs.push(id_attr);
+            if !self.omits_boundary_prop(&boundary) {
+                let boundary_ref = match self.boundary_name_ref(&boundary) {
+                    Some(name) => jsx_str_attr(BOUNDARY_NAME_PROPERTY_NAME, &name, name_span),
+                    None => jsx_expr_attr(
+                        BOUNDARY_NAME_PROPERTY_NAME,
+                        Expr::Ident(boundary_ident),
+                        name_span,
+                    ),
+                };
+                jsx_element.opening.attrs.push(boundary_ref);
             }
         }
-        None
+
+        self.add_debug_name(jsx_element);
+        self.add_owner_stack(jsx_element);
+        self.add_route_hint(jsx_element);
+        self.add_original_name(jsx_element, &boundary);
+        self.add_depth(jsx_element, boundary_depth);
+        self.add_otel_attributes(jsx_element);
+        self.add_boundary_source(jsx_element, line);
+        self.add_boundary_prop_map(jsx_element, &boundary);
+
+        if kind == BoundaryKind::Suspense {
+            self.inject_callbacks(jsx_element);
+            self.add_fallback_attributes(jsx_element, &id_value);
+            self.track_content_mount(jsx_element, &id_value);
+            self.track_fallback(jsx_element, &id_value);
+        } else if kind == BoundaryKind::Error {
+            self.inject_error_reporter(jsx_element, &id_value);
+            self.inject_sentry_tag(jsx_element, &id_value);
+        }
+        self.inject_profiler(jsx_element, &id_value);
     }
 }
 
 impl VisitMut for TransformVisitor {
+    fn visit_mut_module(&mut self, module: &mut Module) {
+        if self.source_map.is_none() {
+            if let (Some(source), Some(first_item)) = (self.source_text.take(), module.body.first())
+            {
+                self.line_cache
+                    .init_from_source(&source, first_item.span().lo);
+            }
+        }
+        // Runtime bindings (callbacks, Apollo's `skipToken`) must not be shadowed
+        if !self.config.callbacks.props().is_empty() || self.config.track_apollo {
+            self.binding_names = scopes::binding_names(module);
+        }
+        module.visit_mut_children_with(self);
+        self.check_unwrapped_suspenders();
+        self.check_unguarded_lazy();
+        self.emit_diagnostics();
+
+        // Numeric IDs are only resolvable with the location mapping of the manifest
+        let emit_manifest = self.config.mode == Mode::Analyze
+            || self.config.emit_manifest_comment
+            || self
+                .boundaries
+                .iter()
+                .any(|boundary| boundary.location.is_some());
+        if emit_manifest && !self.boundaries.is_empty() {
+            self.add_manifest_comment(module.span.hi);
+        }
+        // Modules which were left untouched are not reported
+        if self.config.emit_stats && (!self.boundaries.is_empty() || self.boundary_imports_added) {
+            self.add_stats_comment(module.span.hi);
+        }
+    }
+
     fn visit_mut_module_items(&mut self, module_items: &mut Vec<ModuleItem>) {
-        let is_enabled = self
-            .config
-            .enabled
-            .unwrap_or(self.context.env_name == Environment::Development);
+        // Stripping removes hand-written trackers from production builds
+        // and therefore runs in every environment
+        if self.config.strip {
+            self.strip_boundary_trackers(module_items);
+            return;
+        }
+
+        // Analyzing does not change the output and is therefore enabled in all environments
+        let is_enabled = self.config.enabled.unwrap_or(
+            self.context.env_name == Environment::Development
+                || (self.context.env_name == Environment::Test && self.config.test_mode)
+                || self.config.mode.is_read_only(),
+        );
 
         // Skip transformation if the plugin is disabled
         // or if the environment is not Development and the config does not explicitly enable it
         if !is_enabled {
+            if self.config.clean_disabled_imports {
+                self.clean_disabled_module(module_items);
+            }
             return;
         }
 
-        // First pass: collect boundary imports (including Suspense from React)
-        for module_item in module_items.iter_mut() {
+        // Imports are hoisted, so all of them are collected before any code is visited
+        // (components may be declared above the import of the boundary they render)
+        let mut first_import_index = None;
+        let mut last_import_index = None;
+        let mut last_react_import_index = None;
+        for (index, module_item) in module_items.iter_mut().enumerate() {
             if let ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)) = module_item {
+                // Collect boundary imports (including Suspense from React)
+                // and all other imported bindings
                 self.process_boundary_import(import_decl);
+                self.imports.extend(collect_import_bindings(import_decl));
+                first_import_index.get_or_insert(index);
+                last_import_index = Some(index);
+                if is_react_import(import_decl) {
+                    last_react_import_index = Some(index);
+                }
             }
         }
 
-        // If no valid boundary identifiers were found, skip further processing
-        if self.valid_boundary_idents.is_empty() {
-            return;
+        // Replace the boundary elements with BoundaryTrackerSWC
+        // (nothing can be tracked as long as nothing trackable was imported)
+        let has_trackable_imports = self.has_trackable_imports();
+        let mut last_refresh_registration_index = None;
+        for (index, module_item) in module_items.iter_mut().enumerate() {
+            if matches!(module_item, ModuleItem::ModuleDecl(ModuleDecl::Import(_))) {
+                continue;
+            }
+            if is_refresh_registration(module_item) {
+                last_refresh_registration_index = Some(index);
+            }
+            if has_trackable_imports {
+                module_item.visit_mut_with(self);
+            }
         }
 
-        // Replace the boundary elements with BoundaryTrackerSWC
-        module_items.visit_mut_children_with(self);
+        // Banner comments are attached to the first item, even once items are inserted before it
+        let first_item_lo = module_items
+            .first()
+            .map(|module_item| module_item.span().lo)
+            .filter(|lo| !lo.is_dummy());
 
-        // Add required import if needed
         if self.has_boundary_elements {
-            let insert_index = get_first_import_index(module_items).unwrap_or(0);
+            // Register the boundaries right after the imports
+            // (or after the Fast Refresh registrations to leave them untouched)
+            let mut registry_index = last_refresh_registration_index
+                .filter(|_| self.config.fast_refresh)
+                .or(last_import_index)
+                .map_or(directive_count(module_items), |index| index + 1);
+            if self.config.emit_registry {
+                self.add_runtime_import(REGISTER_BOUNDARIES_IMPORT_NAME);
+                module_items.insert(registry_index, self.create_registry_call());
+                registry_index += 1;
+            }
+            if let Some(inject_call) = &self.config.inject_call {
+                let calls = self.create_injected_calls(inject_call);
+                let (from, name) = (inject_call.from.clone(), inject_call.name.clone());
+                self.add_runtime_import_from(&from, &name);
+                module_items.splice(registry_index..registry_index, calls);
+            }
+
+            if self.config.export_boundaries {
+                module_items.push(export_const_item(
+                    BOUNDARIES_EXPORT_NAME,
+                    self.create_boundaries_array(),
+                ));
+            }
+
+            // The hoisted IDs are declared right after the imports
+            if !self.hoisted_ids.is_empty() {
+                let index =
+                    last_import_index.map_or(directive_count(module_items), |index| index + 1);
+                module_items.splice(index..index, self.create_hoisted_id_items());
+            }
+        }
 
-            if !self.boundary_imports_added {
-                let tracker_import = self.create_boundary_tracker_import();
-                module_items.insert(insert_index, tracker_import);
-                self.boundary_imports_added = true;
+        // Add required import if needed (after `"use client"` and other directives of
+        // modules without imports, the shebang is not a module item)
+        if !self.runtime_imports.is_empty() && !self.boundary_imports_added {
+            // The registry, injected calls and hoisted IDs were inserted behind the imports
+            // so the recorded import indices are still valid
+            let after = |index: Option<usize>| index.map(|index| index + 1);
+            let insert_index = match self.config.import_placement {
+                ImportPlacement::First => first_import_index,
+                ImportPlacement::AfterReact => {
+                    after(last_react_import_index).or(first_import_index)
+                }
+                ImportPlacement::Last => after(last_import_index),
+            }
+            .unwrap_or_else(|| directive_count(module_items));
+            let mut tracker_imports = self.create_boundary_tracker_imports();
+            if let (0, Some(first_item_lo), Some(tracker_import)) =
+                (insert_index, first_item_lo, tracker_imports.first_mut())
+            {
+                self.move_banner_comments(first_item_lo, tracker_import);
             }
+            module_items.splice(insert_index..insert_index, tracker_imports);
+            self.boundary_imports_added = true;
         }
     }
 
     fn visit_mut_jsx_element(&mut self, jsx_element: &mut JSXElement) {
+        let fallback_depth = self.fallback_stack.len();
+        let boundary_ids_len = self.boundary_ids.len();
+        let boundary_depth = self.boundary_depth;
+        let keyed = self.enter_keyed_element(jsx_element);
+        self.record_rendered_component(jsx_element);
+
         // Check if this is a boundary element (including Suspense)
-        if let Some(boundary_ident) = self.get_element_boundary_ident(jsx_element) {
-            self.has_boundary_elements = true;
+        if let Some((boundary_ident, boundary)) = self.get_element_boundary_ident(jsx_element) {
+            self.transform_boundary_element(jsx_element, boundary_ident, boundary);
+        }
 
-            // Transform all boundaries to BoundaryTrackerSWC
-            // Change the element name to BoundaryTrackerSWC
-            jsx_element.opening.name = JSXElementName::Ident(Ident {
-                ctxt: Default::default(),
-                span: DUMMY_SP,
-                sym: BOUNDARY_TRACKER_IMPORT_NAME.into(),
-                optional: false,
-            });
+        jsx_element.visit_mut_children_with(self);
+        self.fallback_stack.truncate(fallback_depth);
+        self.boundary_ids.truncate(boundary_ids_len);
+        self.boundary_depth = boundary_depth;
+        self.exit_keyed_element(keyed);
+    }
+
+    fn visit_mut_expr(&mut self, expr: &mut Expr) {
+        expr.visit_mut_children_with(self);
 
-            // Also update closing tag if it exists
-            if let Some(ref mut closing) = jsx_element.closing {
-                closing.name = JSXElementName::Ident(Ident {
-                    ctxt: Default::default(),
-                    span: DUMMY_SP,
-                    sym: BOUNDARY_TRACKER_IMPORT_NAME.into(),
-                    optional: false,
-                });
+        if self.tracks_calls() {
+            self.track_lazy_call(expr);
+        }
+    }
+
+    fn visit_mut_call_expr(&mut self, call: &mut CallExpr) {
+        let is_array_callback = is_array_callback_call(call);
+        if is_array_callback {
+            self.loop_keys.push(None);
+        }
+        call.visit_mut_children_with(self);
+        if is_array_callback {
+            self.loop_keys.pop();
+        }
+
+        if self.tracks_calls() {
+            self.track_use_call(call);
+            self.track_tanstack_query_call(call);
+            self.track_swr_call(call);
+            self.track_relay_call(call);
+            self.track_apollo_call(call);
+            self.track_suspending_hook_call(call);
+            self.track_transition_call(call);
+            self.track_deferred_value_call(call);
+        }
+    }
+
+    fn visit_mut_fn_decl(&mut self, fn_decl: &mut FnDecl) {
+        let name = fn_decl.ident.sym.to_string();
+        self.component_stack.push(name.clone());
+        fn_decl.visit_mut_children_with(self);
+        self.component_stack.pop();
+
+        self.collect_suspender_function(&name, fn_decl.ident.span, &fn_decl.function);
+        if self.tracks_calls() && self.config.track_suspenders {
+            self.track_suspender_function(&name, &mut fn_decl.function);
+        }
+        if self.tracks_calls() && self.config.track_async_components {
+            self.track_async_function(Some(&name), &mut fn_decl.function);
+        }
+        self.track_thrown_promises_function(&name, &mut fn_decl.function);
+    }
+
+    fn visit_mut_export_default_decl(&mut self, export_default: &mut ExportDefaultDecl) {
+        // `export default function Page() {}` and `export default class Page {}` are expressions
+        let name = match &export_default.decl {
+            DefaultDecl::Fn(FnExpr {
+                ident: Some(ident), ..
+            })
+            | DefaultDecl::Class(ClassExpr {
+                ident: Some(ident), ..
+            }) => Some(ident.sym.to_string()),
+            _ => None,
+        };
+        if let Some(name) = &name {
+            self.component_stack.push(name.clone());
+        }
+        export_default.visit_mut_children_with(self);
+        if name.is_some() {
+            self.component_stack.pop();
+        }
+
+        // `export default async function Page() {}` is no `FnDecl`
+        if let DefaultDecl::Fn(fn_expr) = &mut export_default.decl {
+            if self.tracks_calls() && self.config.track_async_components {
+                let name = fn_expr.ident.as_ref().map(|ident| ident.sym.to_string());
+                self.track_async_function(name.as_deref(), &mut fn_expr.function);
             }
+        }
+    }
 
-            // Add the id prop
-            let id_value = self.generate_boundary_id(jsx_element.span.lo);
+    fn visit_mut_function(&mut self, function: &mut Function) {
+        let depth = self.enter_scope(|| function_bindings(function));
+        function.visit_mut_children_with(self);
+        self.exit_scope(depth);
+    }
 
-            let id_attr = JSXAttrOrSpread::JSXAttr(JSXAttr {
-                span: DUMMY_SP,
-                name: JSXAttrName::Ident(IdentName {
-                    span: DUMMY_SP,
-                    sym: BOUNDARY_ID_PROPERTY_NAME.into(),
-                }),
-                value: Some(JSXAttrValue::Str(Str {
-                    span: DUMMY_SP,
-                    value: id_value.into(),
-                    raw: None,
-                })),
-            });
+    fn visit_mut_arrow_expr(&mut self, arrow: &mut ArrowExpr) {
+        let depth = self.enter_scope(|| arrow_bindings(arrow));
+        arrow.visit_mut_children_with(self);
+        self.exit_scope(depth);
+    }
 
-            let boundary_attr = JSXAttrOrSpread::JSXAttr(JSXAttr {
-                span: DUMMY_SP,
-                name: JSXAttrName::Ident(IdentName {
-                    span: DUMMY_SP,
-                    sym: BOUNDARY_NAME_PROPERTY_NAME.into(),
-                }),
-                value: Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
-                    span: DUMMY_SP,
-                    expr: JSXExpr::Expr(Box::new(Expr::Ident(boundary_ident))),
-                })),
-            });
+    fn visit_mut_block_stmt(&mut self, block: &mut BlockStmt) {
+        let depth = self.enter_scope(|| block_bindings(block));
+        block.visit_mut_children_with(self);
+        self.exit_scope(depth);
+    }
 
-            jsx_element.opening.attrs.push(id_attr);
-            jsx_element.opening.attrs.push(boundary_attr);
+    fn visit_mut_catch_clause(&mut self, catch_clause: &mut CatchClause) {
+        let depth = self.enter_scope(|| catch_bindings(catch_clause));
+        catch_clause.visit_mut_children_with(self);
+        self.exit_scope(depth);
+    }
+
+    fn visit_mut_class_decl(&mut self, class_decl: &mut ClassDecl) {
+        self.component_stack.push(class_decl.ident.sym.to_string());
+        class_decl.visit_mut_children_with(self);
+        self.component_stack.pop();
+    }
+
+    fn visit_mut_var_declarator(&mut self, declarator: &mut VarDeclarator) {
+        if self.tracks_calls() {
+            self.collect_transition_binding(declarator);
+        }
+        self.collect_lazy_component(declarator);
+        if self.config.emit_debug_name {
+            self.collect_jsx_binding(declarator);
         }
 
-        jsx_element.visit_mut_children_with(self);
+        // `const App = () => ...`, `const App = function() {...}` or `const App = memo(...)`
+        let name = match (&declarator.name, declarator.init.as_deref()) {
+            (Pat::Ident(binding), Some(Expr::Arrow(_) | Expr::Fn(_) | Expr::Call(_))) => {
+                Some(binding.id.sym.to_string())
+            }
+            _ => None,
+        };
+
+        let Some(name) = name else {
+            declarator.visit_mut_children_with(self);
+            return;
+        };
+
+        self.component_stack.push(name.clone());
+        declarator.visit_mut_children_with(self);
+        self.component_stack.pop();
+
+        self.collect_suspender_declarator(&name, declarator);
+        if self.tracks_calls() && self.config.track_suspenders {
+            match declarator.init.as_deref_mut() {
+                Some(Expr::Arrow(arrow)) => self.track_suspender_arrow(&name, arrow),
+                Some(Expr::Fn(fn_expr)) => {
+                    self.track_suspender_function(&name, &mut fn_expr.function)
+                }
+                _ => {}
+            }
+        }
+        if self.tracks_calls() && self.config.track_async_components {
+            match declarator.init.as_deref_mut() {
+                Some(Expr::Arrow(arrow)) => self.track_async_arrow(&name, arrow),
+                Some(Expr::Fn(fn_expr)) => {
+                    self.track_async_function(Some(&name), &mut fn_expr.function)
+                }
+                _ => {}
+            }
+        }
+        match declarator.init.as_deref_mut() {
+            Some(Expr::Arrow(arrow)) => self.track_thrown_promises_arrow(&name, arrow),
+            Some(Expr::Fn(fn_expr)) => {
+                self.track_thrown_promises_function(&name, &mut fn_expr.function)
+            }
+            _ => {}
+        }
     }
 }
 
-/// Returns the index of the first import within the module items if one exists.
-fn get_first_import_index(module_items: &[ModuleItem]) -> Option<usize> {
+/// Checks if a comment only applies to the line after it
+fn applies_to_next_line(comment: &Comment) -> bool {
+    NEXT_LINE_COMMENT_MARKERS
+        .iter()
+        .any(|marker| comment.text.contains(marker))
+}
+
+/// Checks if an import declaration imports `react`, `react-dom` or one of their entry points
+fn is_react_import(import_decl: &ImportDecl) -> bool {
+    import_decl.src.value.as_str().is_some_and(|src| {
+        ["react", "react-dom"].iter().any(|package| {
+            src == *package
+                || src
+                    .strip_prefix(package)
+                    .is_some_and(|entry| entry.starts_with('/'))
+        })
+    })
+}
+
+/// Returns the number of directives (e.g. `"use client"` or `"use strict"`) at the start of
+/// a module, which stop being directives once anything is inserted before them
+fn directive_count(module_items: &[ModuleItem]) -> usize {
     module_items
         .iter()
-        .position(|module_item| is_import_decl(module_item).unwrap_or(false))
+        .take_while(|module_item| {
+            matches!(
+                module_item,
+                ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. }))
+                    if matches!(**expr, Expr::Lit(Lit::Str(_)))
+            )
+        })
+        .count()
 }
 
-/// Checks whether a module item is an import declaration.
-fn is_import_decl(module_item: &ModuleItem) -> Option<bool> {
-    module_item.as_module_decl()?.as_import().map(|_| true)
+/// Strips leading `./` or `/` and normalizes separators of a filename used in boundary IDs
+fn normalize_filename(filename: &str) -> String {
+    filename
+        .strip_prefix("./")
+        .or_else(|| filename.strip_prefix("/"))
+        .unwrap_or(filename)
+        .replace('\\', "/")
 }
 
 /// Transforms a [`Program`].
@@ -277,42 +1135,93 @@ fn is_import_decl(module_item: &ModuleItem) -> Option<bool> {
 ///
 /// - `program` - The SWC [`Program`] to transform.
 /// - `config` - [`Config`] as JSON.
+#[cfg(feature = "plugin")]
 #[plugin_transform]
 pub fn process_transform(program: Program, metadata: TransformPluginProgramMetadata) -> Program {
-    let config: Config = serde_json::from_str(
+    // The config is the same for every module and is only parsed once
+    let config = Config::from_json_cached(
         &metadata
             .get_transform_plugin_config()
             .expect("failed to get plugin config for swc-plugin-suspense-tracker"),
     )
     .expect("failed to parse plugin config");
 
-    let context = Context {
-        filename: metadata
-            .get_context(&TransformPluginMetadataContextKind::Filename)
-            .expect("failed to get filename"),
-        env_name: Environment::try_from(
-            metadata
-                .get_context(&TransformPluginMetadataContextKind::Env)
-                .expect("failed to get env")
-                .as_str(),
-        )
-        .expect("failed to parse environment"),
-    };
+    // Overrides are applied per file, so every module gets its own copy
+    transform_program(program, Config::clone(&config), &metadata)
+}
+
+/// Transforms a [`Program`] with the metadata of the plugin host.
+///
+/// Lets other plugins run the transform inside their own wasm module (with the `embed`
+/// feature instead of `plugin`, which would export a second plugin entry point).
+///
+/// # Arguments
+///
+/// - `program` - The SWC [`Program`] to transform.
+/// - `config` - The [`Config`] of the transform.
+/// - `metadata` - The metadata of the host, provides the filename, the environment,
+///   the source map and the comments.
+#[cfg(feature = "embed")]
+pub fn transform_program(
+    program: Program,
+    config: Config,
+    metadata: &TransformPluginProgramMetadata,
+) -> Program {
+    // Some hosts (e.g. Turbopack) don't provide all metadata
+    let (context, missing_metadata) = Context::from_metadata(
+        metadata
+            .get_context(&TransformPluginMetadataContextKind::Env)
+            .as_deref(),
+        metadata.get_context(&TransformPluginMetadataContextKind::Filename),
+        &config,
+    );
+
+    let source_map: Lrc<dyn SourceMapper> = Lrc::new(metadata.source_map.clone());
+    let mut builder = TransformVisitor::builder()
+        .config(config)
+        .context(context)
+        .source_map(source_map.clone());
+    if let Some(comments) = metadata.comments {
+        builder = builder.comments(comments);
+    }
+    let mut visitor = builder.build();
+    if let Some(message) = missing_metadata {
+        visitor.report_missing_metadata(message);
+    }
+
+    // Most modules contain no boundary at all - skip walking their AST
+    let source = source_map.span_to_snippet(program.span());
+    if source.is_ok_and(|source| !visitor.may_transform(&source)) {
+        visitor.emit_diagnostics();
+        return program;
+    }
 
-    program.apply(visit_mut_pass(
-        &mut (TransformVisitor::new(config, context, Some(metadata.source_map))),
-    ))
+    program.apply(visit_mut_pass(&mut visitor))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use swc_core::ecma::{
-        parser::{Syntax, TsSyntax},
-        transforms::testing::test,
-        visit::{visit_mut_pass, VisitMutPass},
+    use std::{collections::BTreeMap, rc::Rc};
+    use swc_core::{
+        common::{comments::SingleThreadedComments, FileName, SourceMap, Spanned},
+        ecma::{
+            parser::{parse_file_as_module, Syntax, TsSyntax},
+            transforms::testing::test,
+            visit::{visit_mut_pass, Visit, VisitMutPass, VisitWith},
+        },
     };
 
+    const IMPORT_AFTER_COMPONENT: &str = r#"export function App() {
+  return (
+    <Suspense fallback={<Loading />}>
+      <Content />
+    </Suspense>
+  );
+}
+
+import { Suspense } from "react";"#;
+
     const BASIC_SUSPENSE: &str = r#"import { useEffect, Suspense } from "react";
 function App() {
   return (
@@ -405,44 +1314,563 @@ function App() {
   );
 }"#;
 
-    fn transform_visitor(environment: Environment) -> VisitMutPass<TransformVisitor> {
-        visit_mut_pass(TransformVisitor::new(
-            Config {
-                enabled: None,
-                boundaries: HashSet::new(),
-            },
-            Context {
-                env_name: environment,
-                filename: "my/file.tsx".into(),
-            },
-            None,
-        ))
-    }
-
-    fn transform_visitor_with_boundaries(
-        environment: Environment,
-    ) -> VisitMutPass<TransformVisitor> {
-        let mut boundaries = HashSet::new();
-        boundaries.insert(Boundary {
-            component: "ErrorBoundary".to_string(),
-            from: "my-package-name".to_string(),
-        });
-        boundaries.insert(Boundary {
-            component: "LoadingBoundary".to_string(),
-            from: "another-package".to_string(),
-        });
+    const SUSPENSE_AND_ROUTE_BOUNDARY: &str = r#"import { Suspense } from "react";
+import { RouteBoundary } from "@acme/router";
+export const App = () => <RouteBoundary fallback={null}><Suspense fallback={null}><Content /></Suspense></RouteBoundary>;"#;
 
-        visit_mut_pass(TransformVisitor::new(
-            Config {
-                enabled: None,
-                boundaries,
-            },
-            Context {
-                env_name: environment,
-                filename: "my/file.tsx".into(),
-            },
+    const LAZY_COMPONENT: &str = r#"import React, { lazy, Suspense } from "react";
+const Product = lazy(() => import("./Product"));
+const Cart = React.lazy(() => import("./Cart"));
+function App() {
+  return (
+    <Suspense fallback={<Loading />}>
+      <Product />
+      <Cart />
+    </Suspense>
+  );
+}"#;
+
+    const MAGIC_COMMENT_IMPORTS: &str = r#"import { lazy } from "react";
+const Product = lazy(() => import("./Product"));
+const Cart = lazy(() => import(/* webpackChunkName: "cart" */ "./Cart"));
+const Reviews = lazy(() => import(/* webpackPrefetch: true */ "./Reviews"));"#;
+
+    const SAME_LINE_LAZY_IMPORTS: &str = r#"import { lazy } from "react";
+const Product = lazy(() => import("./Product")), Reviews = lazy(() => import("./Reviews"));"#;
+
+    const FRAMEWORK_IMPORTS: &str = r#"import "./polyfills";
+import { Suspense } from "react";
+import { createRoot } from "react-dom/client";
+import { Header } from "./Header";
+export const App = () => <Suspense fallback={null}><Header /></Suspense>;"#;
+
+    const BANNER_COMMENTS: &str = r#"// Copyright (c) Acme Inc.
+// @ts-nocheck
+// eslint-disable-next-line import/order
+import { Suspense } from "react";
+export const App = () => <Suspense fallback={null}><Header /></Suspense>;"#;
+
+    const DIRECTIVE_PROLOGUE: &str = r#"#!/usr/bin/env node
+"use client";
+"use strict";
+export function Profile({ userPromise }) {
+  if (!userPromise.value) {
+    throw userPromise;
+  }
+  return null;
+}"#;
+
+    const NEXT_DYNAMIC: &str = r#"import dynamic from "next/dynamic";
+const Chart = dynamic(() => import("./Chart"), { ssr: false });
+const Map = dynamic({ loader: () => import("./Map") });
+function App() {
+  return (
+    <div>
+      <Chart />
+      <Map />
+    </div>
+  );
+}"#;
+
+    const LOADABLE_COMPONENT: &str = r#"import loadable, { lazy } from "@loadable/component";
+const Product = loadable(() => import("./Product"), { fallback: <Loading /> });
+const Cart = lazy(() => import("./Cart"));"#;
+
+    const USE_PROMISE: &str = r#"import { use } from "react";
+function Cart(props) {
+  const cart = use(props.cartPromise);
+  return <div>{cart.items}</div>;
+}"#;
+
+    const TANSTACK_QUERY: &str = r#"import { useSuspenseQuery, useSuspenseQueries } from "@tanstack/react-query";
+function Product(props) {
+  const product = useSuspenseQuery({ queryKey: props.key, queryFn: fetchProduct });
+  const reviews = useSuspenseQuery({ queryKey: props.key, meta: { owner: "reviews" } });
+  const stock = useSuspenseQuery(props.stockOptions);
+  const prices = useSuspenseQuery(priceOptions);
+  const related = useSuspenseQueries({ queries: [{ queryKey: props.key }] });
+  return null;
+}"#;
+
+    const SWR_HOOKS: &str = r#"import useSWR from "swr";
+import useImmutable from "swr/immutable";
+import { useApi } from "./api";
+function Product(props) {
+  const { data: product } = useSWR(props.key, fetchProduct, { suspense: true });
+  const { data: reviews } = useSWR(props.reviewsKey, fetchReviews);
+  const { data: stock } = useImmutable(props.stockKey, { suspense: true });
+  const { data: prices } = useApi(props.priceKey, fetchPrices, { suspense: true });
+  return null;
+}"#;
+
+    const RELAY_HOOKS: &str = r#"import { graphql, useFragment, useLazyLoadQuery, usePreloadedQuery } from "react-relay";
+function Product(props) {
+  const data = useLazyLoadQuery(ProductQuery, { id: props.id });
+  const cached = useLazyLoadQuery(ProductQuery, { id: props.id }, { fetchPolicy: "store-only" });
+  const preloaded = usePreloadedQuery(ProductQuery, props.queryRef);
+  const reviews = useFragment(graphql`fragment Product_reviews on Product { reviews @defer { id } }`, data.product);
+  const price = useFragment(graphql`fragment Product_price on Product { price }`, data.product);
+  return null;
+}"#;
+
+    const APOLLO_HOOKS: &str = r#"import { skipToken, useBackgroundQuery, useReadQuery, useSuspenseQuery } from "@apollo/client";
+function Product(props) {
+  const { data } = useSuspenseQuery(PRODUCT_QUERY, { variables: { id: props.id } });
+  const { data: user } = useSuspenseQuery(USER_QUERY);
+  const { data: cart } = useSuspenseQuery(CART_QUERY, props.cartId ? { variables: { id: props.cartId } } : skipToken);
+  const { data: skipped } = useSuspenseQuery(CART_QUERY, skipToken);
+  const [queryRef] = useBackgroundQuery(REVIEWS_QUERY, reviewsOptions);
+  const { data: reviews } = useReadQuery(queryRef);
+  return null;
+}"#;
+
+    const APOLLO_OPTION_VARIABLES: &str = r#"import { useBackgroundQuery, useSuspenseQuery } from "@apollo/client/react";
+function Product({ productOptions, reviewsOptions }) {
+  const skipToken = props.skip;
+  const { data } = useSuspenseQuery(PRODUCT_QUERY, productOptions);
+  const [queryRef] = useBackgroundQuery(REVIEWS_QUERY, reviewsOptions);
+  return null;
+}"#;
+
+    const CUSTOM_SUSPENDING_HOOKS: &str = r#"import { useQuery } from "urql";
+import useResource from "@acme/resources";
+function Product(props) {
+  const [result] = useQuery({ query: ProductQuery, variables: { id: props.id } });
+  const stock = useResource(`/stock/${props.id}`);
+  return null;
+}"#;
+
+    const SUSPENDING_HOOKS_CONFIG: &str = r#"{
+  "suspendingHooks": [
+    { "name": "useQuery", "from": "urql", "inject": { "kind": "optionsField", "arg": 0, "path": "context.suspenseTrackerId" } },
+    { "name": "default", "from": "@acme/resources", "inject": { "kind": "wrapCall" } }
+  ]
+}"#;
+
+    const SUSPENDER_COMPONENTS: &str = r#"import { use } from "react";
+import { useSuspenseQuery } from "@tanstack/react-query";
+function Cart(props) {
+  const cart = use(props.cartPromise);
+  return <div>{cart.items}</div>;
+}
+const Price = (props) => use(props.pricePromise);
+const Product = function(props) {
+  const { data } = useSuspenseQuery(props.query);
+  const onClick = () => use(props.promise);
+  return <button onClick={onClick}>{data.name}</button>;
+};
+function Static() {
+  return <div />;
+}
+function formatPrice(price) {
+  return use(price);
+}"#;
+
+    const TRANSITIONS: &str = r#"import { startTransition, useTransition } from "react";
+function Search(props) {
+  const [isPending, startSearch] = useTransition();
+  const onChange = (event) => startSearch(() => props.onSearch(event.target.value));
+  const onReset = () => startTransition(() => props.onSearch(""));
+  return <input onChange={onChange} onReset={onReset} />;
+}"#;
+
+    const DEFERRED_VALUE: &str = r#"import * as React from "react";
+import { useDeferredValue } from "react";
+import { useDebouncedValue } from "./hooks";
+function SearchResults(props) {
+  const query = useDeferredValue(props.query);
+  const page = React.useDeferredValue(props.page, 0);
+  const filter = useDebouncedValue(props.filter);
+  return <Results query={query} page={page} filter={filter} />;
+}"#;
+
+    const SELF_CLOSING_SUSPENSE: &str = r#"import { Suspense } from "react";
+function App() {
+  return <Suspense fallback={<Loading />} />;
+}"#;
+
+    const FALLBACK_EXPRESSIONS: &str = r#"import { Suspense } from "react";
+function App(props) {
+  return (
+    <div>
+      <Suspense fallback={<Loading />}>
+        <Component1 />
+      </Suspense>
+      <Suspense fallback={props.loading}>
+        <Component2 />
+      </Suspense>
+      <Suspense fallback="Loading...">
+        <Component3 />
+      </Suspense>
+      <Suspense fallback={<>Loading...</>}>
+        <Component4 />
+      </Suspense>
+    </div>
+  );
+}"#;
+
+    const NESTED_SUSPENSE: &str = r#"import { Suspense } from "react";
+function App() {
+  return (
+    <Suspense fallback={<Loading />}>
+      <Header />
+      <Suspense fallback={<Spinner />}>
+        <Content />
+      </Suspense>
+    </Suspense>
+  );
+}"#;
+
+    const DEEPLY_NESTED_SUSPENSE: &str = r#"import { Suspense } from "react";
+function Page() {
+  return (
+    <Suspense fallback={<PageSkeleton />}>
+      <Suspense fallback={<WidgetSkeleton />}>
+        <Suspense fallback={<RowSkeleton />}><Row /></Suspense>
+      </Suspense>
+      <Suspense fallback={<WidgetSkeleton />}><Widget /></Suspense>
+    </Suspense>
+  );
+}"#;
+
+    const LOOPED_SUSPENSE: &str = r#"import { Suspense } from "react";
+function List({ items }) {
+  return (
+    <ul>
+      {items.map((item) => (
+        <Suspense key={item.id} fallback={<RowSkeleton />}><Row item={item} /></Suspense>
+      ))}
+      {items.map((item) => (
+        <li key={item.id}>
+          <Suspense fallback={<RowSkeleton />}><Row item={item} /></Suspense>
+        </li>
+      ))}
+      <Suspense fallback={<TotalSkeleton />}><Total /></Suspense>
+    </ul>
+  );
+}"#;
+
+    const NAMED_SUSPENSE: &str = r#"import { Suspense } from "react";
+function Dashboard({ section }) {
+  return (
+    <>
+      <Suspense name="chart" fallback={<Spinner />}><Chart /></Suspense>
+      <Suspense name={section} fallback={<Spinner />}><Section /></Suspense>
+      <Suspense fallback={<Spinner />}><Footer /></Suspense>
+    </>
+  );
+}"#;
+
+    const TRACK_ID_SUSPENSE: &str = r#"import { Suspense } from "react";
+function Checkout() {
+  return (
+    <Suspense trackId="checkout-items" fallback={<Spinner />}>
+      <Items />
+      <Suspense fallback={<Spinner />}><Total /></Suspense>
+    </Suspense>
+  );
+}"#;
+
+    const MANUAL_TRACKERS: &str = r#"import { Suspense } from "react";
+import * as Boundaries from "./boundaries";
+import { BoundaryTrackerSWC, registerBoundaries } from "react-swc-suspense-tracker/context";
+registerBoundaries([]);
+function App() {
+  return (
+    <BoundaryTrackerSWC fallback={<Loading />} boundaryId="app" boundary={Suspense}>
+      <BoundaryTrackerSWC boundary={Boundaries.Error} boundaryId="app-error">
+        <Content />
+      </BoundaryTrackerSWC>
+    </BoundaryTrackerSWC>
+  );
+}"#;
+
+    const RUNTIME_HOOKS: &str = r#"import { useBoundaryStack, useSuspenseOwner, useThrowIfSuspenseMissing, wrapSuspendableHook } from "react-swc-suspense-tracker";
+import * as Tracker from "react-swc-suspense-tracker";
+import { BoundaryTrackerSWC } from "react-swc-suspense-tracker/context";
+import { useQuery } from "./query";
+const useTrackedQuery = wrapSuspendableHook(useQuery, (boundaries) => console.log(boundaries));
+function Product() {
+  const boundaries = useBoundaryStack();
+  const owner = useSuspenseOwner();
+  const lazyInfo = Tracker.getLazyInfo(Product);
+  useThrowIfSuspenseMissing();
+  useThrowIfSuspenseMissing(skipCheck());
+  return <div>{owner}</div>;
+}"#;
+
+    const CUSTOM_ENTRY_POINT_IMPORTS: &str = r#"import { BoundaryTrackerSWC } from "@acme/tracker";
+import { ErrorBoundaryTrackerSWC } from "@acme/error-tracker";
+import { format } from "./format";
+export const label = format("ready");"#;
+
+    const FAST_REFRESH: &str = r#"import { Suspense } from "react";
+var _c;
+export default function App() {
+  return (
+    <Suspense fallback={<Loading />}>
+      <Content />
+    </Suspense>
+  );
+}
+_c = App;
+$RefreshReg$(_c, "App");"#;
+
+    const ERROR_BOUNDARY_PROPS: &str = r#"import { ErrorBoundary } from "react-error-boundary";
+function App(props) {
+  return (
+    <ErrorBoundary FallbackComponent={ErrorFallback} onError={props.onError} resetKeys={props.resetKeys} className="app">
+      <Content />
+    </ErrorBoundary>
+  );
+}"#;
+
+    const ERROR_REPORTING: &str = r#"import { ErrorBoundary } from "react-error-boundary";
+function App(props) {
+  return (
+    <ErrorBoundary fallback={<Error />}>
+      <ErrorBoundary fallback={<Error />} onError={(error) => console.error(error)}>
+        <Content />
+      </ErrorBoundary>
+      <ErrorBoundary {...props} />
+    </ErrorBoundary>
+  );
+}"#;
+
+    const JSX_SOURCE: &str = r#"import { Suspense } from "react";
+function App() {
+  return <Suspense fallback={<Loading />}><MyComponent /></Suspense>;
+}
+function Compiled() {
+  return <Suspense fallback={<Loading />} __source={source}><MyComponent /></Suspense>;
+}"#;
+
+    const DEBUG_NAMES: &str = r#"import { Suspense } from "react";
+const ProductSection = () => (
+  <Suspense fallback={<Loading />}>
+    <Products />
+  </Suspense>
+);
+const reviews = <Suspense fallback={<Loading />}><Reviews /></Suspense>;
+export default function () {
+  return <Suspense fallback={<Loading />}>{reviews}</Suspense>;
+}"#;
+
+    const OWNER_STACK: &str = r#"import { Suspense } from "react";
+export function CheckoutPage() {
+  const PaymentSection = () => (
+    <Suspense fallback={<Loading />}>
+      <Payment />
+    </Suspense>
+  );
+  return <Suspense fallback={<Loading />}><PaymentSection /></Suspense>;
+}
+export const Fallback = <Suspense fallback={null}><Content /></Suspense>;"#;
+
+    const TEST_ATTRIBUTES: &str = r#"import { Suspense } from "react";
+function App() {
+  return (
+    <>
+      <Suspense fallback={<div className="skeleton" />}><Content /></Suspense>
+      <Suspense fallback={<span data-boundary-id="custom" />}><Content /></Suspense>
+      <Suspense fallback={<Skeleton />}><Content /></Suspense>
+    </>
+  );
+}"#;
+
+    const EXISTING_TRACKER_IMPORT: &str = r#"import { Suspense } from "react";
+import { BoundaryTrackerSWC as Tracker } from "react-swc-suspense-tracker/context";
+export const Manual = () => <Tracker boundary={Suspense} boundaryId="manual"><Content /></Tracker>;
+export const App = () => <Suspense fallback={null}><Content /></Suspense>;"#;
+
+    const METADATA_OBJECT: &str = r#"import { Suspense } from "react";
+function App() {
+  return (
+    <>
+      <Suspense fallback={<Row.Skeleton />}><Content /></Suspense>
+      <Suspense fallback="Loading..."><Content /></Suspense>
+    </>
+  );
+}"#;
+
+    const ALIASED_BOUNDARIES: &str = r#"import { Suspense as Loader } from "react";
+import { ErrorBoundary as Guard } from "react-error-boundary";
+export const App = () => <Guard fallback={null}><Loader fallback={null}><Content /></Loader></Guard>;"#;
+
+    const SENTRY_BOUNDARIES: &str = r#"import { ErrorBoundary } from "@sentry/react";
+export const App = () => (
+  <>
+    <ErrorBoundary fallback={<p>Error</p>}><Content /></ErrorBoundary>
+    <ErrorBoundary beforeCapture={(scope) => scope.setTag("page", "app")}><Content /></ErrorBoundary>
+  </>
+);"#;
+
+    const INLINE_FALLBACKS: &str = r#"import { Suspense } from "react";
+function App(props) {
+  return (
+    <div>
+      <Suspense fallback={<Loading />}><Component1 /></Suspense>
+      <Suspense fallback="Loading..."><Component2 /></Suspense>
+      <Suspense><Component3 /></Suspense>
+      <Suspense {...props}><Component4 /></Suspense>
+    </div>
+  );
+}"#;
+
+    const ASYNC_COMPONENTS: &str = r#"import { db } from "./db";
+export default async function Page({ id }) {
+  const product = await db.product(id);
+  if (product.hasReviews) {
+    const reviews = await db.reviews(id);
+  }
+  const load = async () => await db.related(id);
+  return <Product product={product} load={load} />;
+}
+export const Reviews = async ({ id }) => {
+  const reviews = await db.reviews(id);
+  return <List items={reviews} />;
+};
+async function loadUser(id) {
+  return await db.user(id);
+}"#;
+
+    const THROWN_PROMISES: &str = r#"import { fetchUser } from "./api";
+const cache = new Map();
+function useUser(id) {
+  const user = cache.get(id);
+  if (!user) {
+    throw fetchUser(id).then((user) => cache.set(id, user));
+  }
+  return user;
+}
+export function Profile({ userPromise }) {
+  if (!userPromise.value) {
+    throw userPromise;
+  }
+  if (userPromise.value.banned) {
+    throw new Error("banned");
+  }
+  const refresh = () => {
+    throw fetch("/api/user");
+  };
+  return <Avatar user={userPromise.value} onClick={refresh} />;
+}
+function loadUser(id) {
+  throw fetchUser(id);
+}"#;
+
+    const SHADOWED_SUSPENSE: &str = r#"import { Suspense } from "react";
+function App() {
+  return <Suspense fallback={<Loading />}><Inner /></Suspense>;
+}
+function Inner() {
+  const Suspense = ({ children }) => <div>{children}</div>;
+  return <Suspense><Content /></Suspense>;
+}
+const Param = ({ Suspense }) => <Suspense><Content /></Suspense>;
+function Hoisted() {
+  if (enabled) {
+    var Suspense = Custom;
+  }
+  return <Suspense><Content /></Suspense>;
+}"#;
+
+    const EXISTING_CALLBACKS: &str = r#"import { Suspense } from "react";
+import { reportSuspend as onSuspend } from "@acme/telemetry";
+export const App = ({ props }) => (
+  <>
+    <Suspense fallback={null} onResolve={done}><Content /></Suspense>
+    <Suspense fallback={null} {...props}><Content /></Suspense>
+    <Suspense fallback={null}><Content /></Suspense>
+  </>
+);"#;
+
+    const COLLIDING_CALLBACKS: &str = r#"import { Suspense } from "react";
+import { reportResolve } from "./local-telemetry";
+const reportSuspend = (id) => console.log(id);
+export const App = () => (
+  <>
+    <Suspense fallback={null}><Content /></Suspense>
+    <Suspense fallback={null}><Content /></Suspense>
+  </>
+);"#;
+
+    fn transform_visitor(environment: Environment) -> VisitMutPass<TransformVisitor> {
+        visit_mut_pass(
+            TransformVisitor::builder()
+                .env(environment)
+                .filename("my/file.tsx")
+                .build(),
+        )
+    }
+
+    fn transform_visitor_with_boundaries(
+        environment: Environment,
+    ) -> VisitMutPass<TransformVisitor> {
+        visit_mut_pass(
+            TransformVisitor::builder()
+                .boundary("ErrorBoundary", "my-package-name")
+                .boundary("LoadingBoundary", "another-package")
+                .env(environment)
+                .filename("my/file.tsx")
+                .build(),
+        )
+    }
+
+    fn transform_visitor_with_config(config: Config) -> VisitMutPass<TransformVisitor> {
+        visit_mut_pass(
+            TransformVisitor::builder()
+                .config(config)
+                .env(Environment::Development)
+                .filename("my/file.tsx")
+                .build(),
+        )
+    }
+
+    /// Resolves lines from the source text as the plugin does without a source map
+    fn transform_visitor_with_source(code: &str) -> VisitMutPass<TransformVisitor> {
+        visit_mut_pass(
+            TransformVisitor::builder()
+                .env(Environment::Development)
+                .filename("my/file.tsx")
+                .source_text(code)
+                .build(),
+        )
+    }
+
+    fn with_bom(code: &str) -> String {
+        format!("\u{feff}{code}")
+    }
+
+    fn with_leading_comments(code: &str) -> String {
+        format!("/**\n * The product page\n */\n// @ts-check\n\n{code}")
+    }
+
+    /// Collects all JSX elements of a module
+    #[derive(Default)]
+    struct JsxElementCollector(Vec<JSXElement>);
+
+    impl Visit for JsxElementCollector {
+        fn visit_jsx_element(&mut self, jsx_element: &JSXElement) {
+            self.0.push(jsx_element.clone());
+            jsx_element.visit_children_with(self);
+        }
+    }
+
+    fn parse_tsx(code: &str) -> Module {
+        let source_map: Lrc<SourceMap> = Default::default();
+        let source_file = source_map.new_source_file(
+            FileName::Custom("my/file.tsx".into()).into(),
+            code.to_string(),
+        );
+        parse_file_as_module(
+            &source_file,
+            tsx_syntax(),
+            EsVersion::latest(),
             None,
-        ))
+            &mut Vec::new(),
+        )
+        .expect("failed to parse module")
     }
 
     fn tsx_syntax() -> Syntax {
@@ -463,6 +1891,46 @@ function App() {
         BASIC_SUSPENSE
     );
 
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_source(MULTIPLE_SUSPENSE),
+        source_text_lines_transform,
+        MULTIPLE_SUSPENSE
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_source(&with_bom(MULTIPLE_SUSPENSE)),
+        source_text_bom_transform,
+        &with_bom(MULTIPLE_SUSPENSE)
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_source(&with_leading_comments(MULTIPLE_SUSPENSE)),
+        source_text_leading_comments_transform,
+        &with_leading_comments(MULTIPLE_SUSPENSE)
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_source(&with_bom(&with_leading_comments(MULTIPLE_SUSPENSE))),
+        source_text_bom_and_leading_comments_transform,
+        &with_bom(&with_leading_comments(MULTIPLE_SUSPENSE))
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor(Environment::Development),
+        import_after_component_transform,
+        IMPORT_AFTER_COMPONENT
+    );
+
     test!(
         module,
         tsx_syntax(),
@@ -534,4 +2002,1895 @@ function App() {
         multiple_custom_boundaries_transform,
         MULTIPLE_CUSTOM_BOUNDARIES
     );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| visit_mut_pass(
+            TransformVisitor::builder()
+                .config(Config {
+                    include_default_suspense: Some(false),
+                    ..Default::default()
+                })
+                .boundary("RouteBoundary", "@acme/router")
+                .env(Environment::Development)
+                .filename("my/file.tsx")
+                .build()
+        ),
+        exclude_default_suspense_transform,
+        SUSPENSE_AND_ROUTE_BOUNDARY
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| visit_mut_pass(
+            TransformVisitor::builder()
+                .mode(Mode::Analyze)
+                .env(Environment::Production)
+                .filename("my/file.tsx")
+                .build()
+        ),
+        analyze_mode_no_transform,
+        BASIC_SUSPENSE
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            emit_registry: true,
+            ..Default::default()
+        }),
+        emit_registry_transform,
+        MULTIPLE_SUSPENSE
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            mode: Mode::Inline,
+            ..Default::default()
+        }),
+        inline_mode_transform,
+        INLINE_FALLBACKS
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| visit_mut_pass(
+            TransformVisitor::builder()
+                .mode(Mode::Provider)
+                .error_boundary("ErrorBoundary", "react-error-boundary")
+                .env(Environment::Development)
+                .filename("my/file.tsx")
+                .build()
+        ),
+        provider_mode_transform,
+        ALIASED_BOUNDARIES
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            overrides: vec![
+                ConfigOverride {
+                    files: "my/**".to_string(),
+                    enabled: None,
+                    boundaries: Some(HashSet::from([Boundary {
+                        component: "ErrorBoundary".to_string(),
+                        from: "react-error-boundary".to_string(),
+                        kind: None,
+                        prop_map: None,
+                        id_strategy: None,
+                        id_prefix: None,
+                    }])),
+                    mode: Some(Mode::Provider),
+                },
+                ConfigOverride {
+                    files: "src/admin/**".to_string(),
+                    enabled: Some(false),
+                    boundaries: None,
+                    mode: None,
+                },
+            ],
+            ..Default::default()
+        }),
+        config_overrides_transform,
+        ALIASED_BOUNDARIES
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            track_async_components: true,
+            ..Default::default()
+        }),
+        track_async_components_transform,
+        ASYNC_COMPONENTS
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            track_thrown_promises: true,
+            ..Default::default()
+        }),
+        track_thrown_promises_transform,
+        THROWN_PROMISES
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            track_thrown_promises: true,
+            ..Default::default()
+        }),
+        directive_prologue_transform,
+        DIRECTIVE_PROLOGUE
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            import_placement: ImportPlacement::AfterReact,
+            ..Default::default()
+        }),
+        import_placement_after_react_transform,
+        FRAMEWORK_IMPORTS
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            import_placement: ImportPlacement::Last,
+            ..Default::default()
+        }),
+        import_placement_last_transform,
+        FRAMEWORK_IMPORTS
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            inject_call: Some(InjectCall {
+                name: "__registerBoundary".into(),
+                from: "@acme/telemetry".into(),
+                args: vec![
+                    CallArgument::Id,
+                    CallArgument::Kind,
+                    CallArgument::EnclosingComponent,
+                ],
+            }),
+            ..Default::default()
+        }),
+        inject_call_transform,
+        MULTIPLE_SUSPENSE
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            export_boundaries: true,
+            ..Default::default()
+        }),
+        export_boundaries_transform,
+        BASIC_SUSPENSE
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            track_lazy: true,
+            ..Default::default()
+        }),
+        track_lazy_transform,
+        LAZY_COMPONENT
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            track_chunks: true,
+            track_dynamic: true,
+            ..Default::default()
+        }),
+        track_chunks_transform,
+        NEXT_DYNAMIC
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |tester| visit_mut_pass(
+            TransformVisitor::builder()
+                .config(Config {
+                    track_lazy: true,
+                    emit_chunk_names: true,
+                    ..Default::default()
+                })
+                .env(Environment::Development)
+                .filename("src/App.tsx")
+                .source_text(MAGIC_COMMENT_IMPORTS)
+                .comments(tester.comments.clone())
+                .build()
+        ),
+        emit_chunk_names_transform,
+        MAGIC_COMMENT_IMPORTS
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |tester| visit_mut_pass(
+            TransformVisitor::builder()
+                .config(Config {
+                    track_lazy: true,
+                    emit_chunk_names: true,
+                    ..Default::default()
+                })
+                .env(Environment::Development)
+                .filename("src/App.tsx")
+                .source_text(SAME_LINE_LAZY_IMPORTS)
+                .comments(tester.comments.clone())
+                .build()
+        ),
+        emit_chunk_names_same_line_transform,
+        SAME_LINE_LAZY_IMPORTS
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |tester| visit_mut_pass(
+            TransformVisitor::builder()
+                .config(Config {
+                    track_lazy: true,
+                    emit_chunk_names: true,
+                    ..Default::default()
+                })
+                .env(Environment::Development)
+                .filename("src/App.tsx")
+                .comments(tester.comments.clone())
+                .build()
+        ),
+        emit_chunk_names_unknown_lines_transform,
+        SAME_LINE_LAZY_IMPORTS
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |tester| visit_mut_pass(
+            TransformVisitor::builder()
+                .config(Config {
+                    track_lazy: true,
+                    ..Default::default()
+                })
+                .env(Environment::Development)
+                .filename("my/file.tsx")
+                .comments(tester.comments.clone())
+                .build()
+        ),
+        pure_annotations_transform,
+        LAZY_COMPONENT
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |tester| visit_mut_pass(
+            TransformVisitor::builder()
+                .config(Config::default())
+                .env(Environment::Development)
+                .filename("my/file.tsx")
+                .comments(tester.comments.clone())
+                .build()
+        ),
+        banner_comments_transform,
+        BANNER_COMMENTS
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            track_dynamic: true,
+            ..Default::default()
+        }),
+        track_next_dynamic_transform,
+        NEXT_DYNAMIC
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            track_loadable: true,
+            ..Default::default()
+        }),
+        track_loadable_transform,
+        LOADABLE_COMPONENT
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            track_use: true,
+            ..Default::default()
+        }),
+        track_use_transform,
+        USE_PROMISE
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            track_tanstack_query: true,
+            ..Default::default()
+        }),
+        track_tanstack_query_transform,
+        TANSTACK_QUERY
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            track_swr: true,
+            swr_hooks: Some(vec![
+                "useSWR".to_string(),
+                "useSWRImmutable".to_string(),
+                "useApi".to_string(),
+            ]),
+            ..Default::default()
+        }),
+        track_swr_transform,
+        SWR_HOOKS
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            track_relay: true,
+            ..Default::default()
+        }),
+        track_relay_transform,
+        RELAY_HOOKS
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            track_apollo: true,
+            ..Default::default()
+        }),
+        track_apollo_transform,
+        APOLLO_HOOKS
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            track_apollo: true,
+            ..Default::default()
+        }),
+        track_apollo_option_variables_transform,
+        APOLLO_OPTION_VARIABLES
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(
+            serde_json::from_str(SUSPENDING_HOOKS_CONFIG).expect("valid config")
+        ),
+        suspending_hooks_transform,
+        CUSTOM_SUSPENDING_HOOKS
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            track_suspenders: true,
+            ..Default::default()
+        }),
+        track_suspenders_transform,
+        SUSPENDER_COMPONENTS
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            track_transitions: true,
+            ..Default::default()
+        }),
+        track_transitions_transform,
+        TRANSITIONS
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            track_deferred_value: true,
+            ..Default::default()
+        }),
+        track_deferred_value_transform,
+        DEFERRED_VALUE
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            track_deferred_value: true,
+            deferred_value_hooks: Some(vec!["useDebouncedValue".into()]),
+            deferred_value_tracker: Some("./tracking".into()),
+            ..Default::default()
+        }),
+        track_custom_deferred_value_hooks_transform,
+        DEFERRED_VALUE
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            inject_profiler: true,
+            ..Default::default()
+        }),
+        inject_profiler_transform,
+        BASIC_SUSPENSE
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            inject_profiler: true,
+            ..Default::default()
+        }),
+        inject_profiler_without_children_transform,
+        SELF_CLOSING_SUSPENSE
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            track_fallback: true,
+            ..Default::default()
+        }),
+        track_fallback_transform,
+        FALLBACK_EXPRESSIONS
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            track_content_mount: true,
+            track_fallback: true,
+            inject_profiler: true,
+            ..Default::default()
+        }),
+        track_content_mount_transform,
+        NESTED_SUSPENSE
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            strip: true,
+            ..Default::default()
+        }),
+        strip_manual_trackers,
+        MANUAL_TRACKERS
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            enabled: Some(false),
+            clean_disabled_imports: true,
+            ..Default::default()
+        }),
+        clean_disabled_imports,
+        RUNTIME_HOOKS
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            enabled: Some(false),
+            clean_disabled_imports: true,
+            entry_points: EntryPoints {
+                suspense: Some("@acme/tracker".into()),
+                error: Some("@acme/error-tracker".into()),
+            },
+            ..Default::default()
+        }),
+        clean_disabled_custom_entry_points,
+        CUSTOM_ENTRY_POINT_IMPORTS
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            fast_refresh: true,
+            emit_registry: true,
+            ..Default::default()
+        }),
+        fast_refresh_transform,
+        FAST_REFRESH
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| visit_mut_pass(
+            TransformVisitor::builder()
+                .env(Environment::Development)
+                .filename("my/file.tsx")
+                .error_boundary("ErrorBoundary", "react-error-boundary")
+                .build()
+        ),
+        boundary_prop_map_transform,
+        ERROR_BOUNDARY_PROPS
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| visit_mut_pass(
+            TransformVisitor::builder()
+                .config(Config {
+                    report_errors: true,
+                    ..Default::default()
+                })
+                .env(Environment::Development)
+                .filename("my/file.tsx")
+                .error_boundary("ErrorBoundary", "react-error-boundary")
+                .build()
+        ),
+        report_errors_transform,
+        ERROR_REPORTING
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            callbacks: Callbacks {
+                on_suspend: Some(ModuleExport {
+                    name: "reportSuspend".to_string(),
+                    from: "@acme/telemetry".to_string(),
+                }),
+                on_resolve: Some(ModuleExport {
+                    name: "reportResolve".to_string(),
+                    from: "@acme/telemetry".to_string(),
+                }),
+            },
+            ..Default::default()
+        }),
+        callbacks_transform,
+        BASIC_SUSPENSE
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            callbacks: Callbacks {
+                on_suspend: Some(ModuleExport {
+                    name: "reportSuspend".to_string(),
+                    from: "@acme/telemetry".to_string(),
+                }),
+                on_resolve: None,
+            },
+            ..Default::default()
+        }),
+        callbacks_without_boundaries,
+        NO_SUSPENSE
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            callbacks: Callbacks {
+                on_suspend: Some(ModuleExport {
+                    name: "reportSuspend".to_string(),
+                    from: "@acme/telemetry".to_string(),
+                }),
+                on_resolve: Some(ModuleExport {
+                    name: "reportResolve".to_string(),
+                    from: "@acme/telemetry".to_string(),
+                }),
+            },
+            ..Default::default()
+        }),
+        callbacks_keep_existing_props_and_imports,
+        EXISTING_CALLBACKS
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            callbacks: Callbacks {
+                on_suspend: Some(ModuleExport {
+                    name: "reportSuspend".to_string(),
+                    from: "@acme/telemetry".to_string(),
+                }),
+                on_resolve: Some(ModuleExport {
+                    name: "reportResolve".to_string(),
+                    from: "@acme/telemetry".to_string(),
+                }),
+            },
+            ..Default::default()
+        }),
+        callbacks_rename_colliding_imports,
+        COLLIDING_CALLBACKS
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor(Environment::Development),
+        shadowed_suspense_without_resolver,
+        SHADOWED_SUSPENSE
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            emit_boundary_source: true,
+            ..Default::default()
+        }),
+        emit_boundary_source_transform,
+        JSX_SOURCE
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            emit_debug_name: true,
+            ..Default::default()
+        }),
+        emit_debug_name_transform,
+        DEBUG_NAMES
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            emit_owner_stack: true,
+            ..Default::default()
+        }),
+        emit_owner_stack_transform,
+        OWNER_STACK
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            emit_test_attributes: true,
+            ..Default::default()
+        }),
+        emit_test_attributes_transform,
+        TEST_ATTRIBUTES
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            otel: true,
+            ..Default::default()
+        }),
+        otel_transform,
+        TEST_ATTRIBUTES
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            emit_element_timing: true,
+            ..Default::default()
+        }),
+        emit_element_timing_transform,
+        TEST_ATTRIBUTES
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            platform: Platform::ReactNative,
+            emit_test_attributes: true,
+            emit_element_timing: true,
+            otel: true,
+            track_fallback: true,
+            ..Default::default()
+        }),
+        react_native_transform,
+        TEST_ATTRIBUTES
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| visit_mut_pass(
+            TransformVisitor::builder()
+                .config(Config {
+                    otel: true,
+                    otel_prop_name: Some("traceAttributes".to_string()),
+                    otel_attributes: Some(BTreeMap::from([
+                        ("boundary.id".to_string(), CallArgument::Id),
+                        (
+                            "code.function".to_string(),
+                            CallArgument::EnclosingComponent
+                        ),
+                    ])),
+                    ..Default::default()
+                })
+                .error_boundary("ErrorBoundary", "react-error-boundary")
+                .env(Environment::Development)
+                .filename("my/file.tsx")
+                .build()
+        ),
+        otel_custom_attributes_transform,
+        ALIASED_BOUNDARIES
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| visit_mut_pass(
+            TransformVisitor::builder()
+                .config(Config {
+                    sentry: Some(SentryTagging::default()),
+                    ..Default::default()
+                })
+                .error_boundary("ErrorBoundary", "@sentry/react")
+                .env(Environment::Development)
+                .filename("my/file.tsx")
+                .build()
+        ),
+        sentry_transform,
+        SENTRY_BOUNDARIES
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| visit_mut_pass(
+            TransformVisitor::builder()
+                .config(Config {
+                    sentry: Some(SentryTagging {
+                        wrapper: Some(ModuleExport {
+                            name: "tagCapture".to_string(),
+                            from: "@acme/sentry".to_string(),
+                        }),
+                        tag: Some("boundary".to_string()),
+                    }),
+                    ..Default::default()
+                })
+                .error_boundary("ErrorBoundary", "@sentry/react")
+                .env(Environment::Development)
+                .filename("my/file.tsx")
+                .build()
+        ),
+        sentry_custom_wrapper_transform,
+        SENTRY_BOUNDARIES
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| visit_mut_pass(
+            TransformVisitor::builder()
+                .config(Config {
+                    test_mode: true,
+                    test_entry: true,
+                    ..Default::default()
+                })
+                .env(Environment::Test)
+                .filename("my/file.tsx")
+                .build()
+        ),
+        test_mode_transform,
+        MULTIPLE_SUSPENSE
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            hoist_ids: true,
+            id_strategy: Some(IdStrategy::Component),
+            emit_registry: true,
+            ..Default::default()
+        }),
+        hoist_ids_transform,
+        MULTIPLE_SUSPENSE
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            hoist_ids: true,
+            ..Default::default()
+        }),
+        hoist_ids_shared_transform,
+        MULTIPLE_SUSPENSE
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            max_depth: Some(2),
+            ..Default::default()
+        }),
+        max_depth_transform,
+        DEEPLY_NESTED_SUSPENSE
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            only_outermost: true,
+            ..Default::default()
+        }),
+        only_outermost_transform,
+        DEEPLY_NESTED_SUSPENSE
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            emit_depth: true,
+            ..Default::default()
+        }),
+        emit_depth_transform,
+        DEEPLY_NESTED_SUSPENSE
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            keyed_loop_ids: true,
+            ..Default::default()
+        }),
+        keyed_loop_ids_transform,
+        LOOPED_SUSPENSE
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            id_format: Some("{file}:{line}:{prop:name}".into()),
+            ..Default::default()
+        }),
+        id_format_props_transform,
+        NAMED_SUSPENSE
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            id_format: Some("{prop:name}@{file}:{line}".into()),
+            ..Default::default()
+        }),
+        id_format_leading_prop_transform,
+        NAMED_SUSPENSE
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor(Environment::Development),
+        track_id_prop_transform,
+        TRACK_ID_SUSPENSE
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor(Environment::Development),
+        existing_tracker_import_transform,
+        EXISTING_TRACKER_IMPORT
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| transform_visitor_with_config(Config {
+            emit_style: EmitStyle::Object,
+            ..Default::default()
+        }),
+        emit_style_object_transform,
+        METADATA_OBJECT
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| visit_mut_pass(
+            TransformVisitor::builder()
+                .config(Config {
+                    emit_original_name: true,
+                    ..Default::default()
+                })
+                .error_boundary("ErrorBoundary", "react-error-boundary")
+                .env(Environment::Development)
+                .filename("my/file.tsx")
+                .build()
+        ),
+        emit_original_name_transform,
+        ALIASED_BOUNDARIES
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| visit_mut_pass(
+            TransformVisitor::builder()
+                .config(Config {
+                    emit_style: EmitStyle::Id,
+                    emit_debug_name: true,
+                    emit_original_name: true,
+                    ..Default::default()
+                })
+                .error_boundary("ErrorBoundary", "react-error-boundary")
+                .env(Environment::Development)
+                .filename("my/file.tsx")
+                .build()
+        ),
+        emit_style_id_transform,
+        ALIASED_BOUNDARIES
+    );
+
+    test!(
+        module,
+        tsx_syntax(),
+        |_| visit_mut_pass(
+            TransformVisitor::builder()
+                .config(Config {
+                    boundary_ref: BoundaryRef::String,
+                    ..Default::default()
+                })
+                .error_boundary("ErrorBoundary", "react-error-boundary")
+                .env(Environment::Development)
+                .filename("my/file.tsx")
+                .build()
+        ),
+        boundary_ref_string_transform,
+        ALIASED_BOUNDARIES
+    );
+
+    #[test]
+    fn diagnostics_report_missing_fallbacks() {
+        let code = r#"import { Suspense } from "react";
+function App(props) {
+  return (
+    <Suspense>
+      <Suspense fallback={<Loading />}>
+        <Content />
+      </Suspense>
+      <Suspense {...props} />
+    </Suspense>
+  );
+}"#;
+        let mut module = parse_tsx(code);
+        let mut visitor = TransformVisitor::builder()
+            .config(Config {
+                diagnostics: true,
+                ..Default::default()
+            })
+            .env(Environment::Development)
+            .filename("my/file.tsx")
+            .source_text(code)
+            .build();
+        module.visit_mut_with(&mut visitor);
+
+        let rules: Vec<_> = visitor
+            .diagnostics()
+            .iter()
+            .map(|diagnostic| (diagnostic.rule, diagnostic.severity))
+            .collect();
+        assert_eq!(
+            rules,
+            vec![(diagnostics::MISSING_FALLBACK, Severity::Warning)]
+        );
+    }
+
+    #[test]
+    fn diagnostics_report_unkeyed_loop_boundaries() {
+        let code = r#"import { Suspense } from "react";
+function List({ items }) {
+  return (
+    <ul>
+      {items.map((item) => (
+        <Suspense fallback={<Loading />}><Row item={item} /></Suspense>
+      ))}
+      {items.map((item) => (
+        <Suspense key={item.id} fallback={<Loading />}><Row item={item} /></Suspense>
+      ))}
+      {items.map((item) => (
+        <li key={item.id}>
+          <Suspense fallback={<Loading />}><Row item={item} /></Suspense>
+        </li>
+      ))}
+      <Suspense fallback={<Loading />}><Total /></Suspense>
+    </ul>
+  );
+}"#;
+        let mut module = parse_tsx(code);
+        let mut visitor = TransformVisitor::builder()
+            .config(Config {
+                diagnostics: true,
+                ..Default::default()
+            })
+            .env(Environment::Development)
+            .filename("my/file.tsx")
+            .source_text(code)
+            .build();
+        module.visit_mut_with(&mut visitor);
+
+        let rules: Vec<_> = visitor
+            .diagnostics()
+            .iter()
+            .map(|diagnostic| (diagnostic.rule, diagnostic.severity))
+            .collect();
+        assert_eq!(
+            rules,
+            vec![(diagnostics::UNKEYED_LOOP_BOUNDARY, Severity::Warning)]
+        );
+    }
+
+    #[test]
+    fn boundary_note_lists_the_boundaries_of_the_module() {
+        let boundary_note = |config: Config| {
+            let mut module = parse_tsx(BASIC_SUSPENSE);
+            let mut visitor = TransformVisitor::builder()
+                .config(config)
+                .env(Environment::Development)
+                .filename("my/file.tsx")
+                .build();
+            module.visit_mut_with(&mut visitor);
+            visitor.boundary_note()
+        };
+
+        assert_eq!(boundary_note(Config::default()), None);
+        let note = boundary_note(Config {
+            emit_boundary_note: true,
+            mode: Mode::Analyze,
+            ..Default::default()
+        })
+        .expect("missing boundary note");
+        let json = note
+            .strip_prefix(diagnostics::BOUNDARY_NOTE_PREFIX)
+            .expect("missing note prefix");
+        let payload: serde_json::Value = serde_json::from_str(json).expect("invalid JSON");
+        assert_eq!(payload["file"], "my/file.tsx");
+        assert_eq!(payload["boundaries"][0]["kind"], "suspense");
+        assert_eq!(payload["boundaries"].as_array().map(Vec::len), Some(1));
+    }
+
+    #[test]
+    fn diagnostics_report_nested_duplicate_fallbacks() {
+        let code = r#"import { Suspense } from "react";
+function App() {
+  return (
+    <Suspense fallback={<Skeleton size="large" />}>
+      <Suspense fallback={<Skeleton size="small" />}>
+        <Suspense fallback={<Skeleton size="large" />}>
+          <Content />
+        </Suspense>
+      </Suspense>
+    </Suspense>
+  );
+}"#;
+        let reported_rules = |rules: BTreeMap<String, RuleSeverity>| {
+            let mut visitor = TransformVisitor::builder()
+                .config(Config {
+                    diagnostics: true,
+                    rules,
+                    ..Default::default()
+                })
+                .env(Environment::Development)
+                .filename("my/file.tsx")
+                .source_text(code)
+                .build();
+            parse_tsx(code).visit_mut_with(&mut visitor);
+            visitor
+                .diagnostics()
+                .iter()
+                .map(|diagnostic| (diagnostic.rule, diagnostic.severity))
+                .collect::<Vec<_>>()
+        };
+        let rule = |severity| {
+            BTreeMap::from([(diagnostics::NESTED_DUPLICATE_FALLBACK.to_string(), severity)])
+        };
+
+        assert_eq!(
+            reported_rules(BTreeMap::new()),
+            vec![(diagnostics::NESTED_DUPLICATE_FALLBACK, Severity::Warning)]
+        );
+        assert_eq!(
+            reported_rules(rule(RuleSeverity::Error)),
+            vec![(diagnostics::NESTED_DUPLICATE_FALLBACK, Severity::Error)]
+        );
+        assert!(reported_rules(rule(RuleSeverity::Off)).is_empty());
+    }
+
+    #[test]
+    fn diagnostics_report_unwrapped_suspenders() {
+        let mut module = parse_tsx(
+            r#"import { Suspense, use } from "react";
+function Profile({ promise }) {
+  const user = use(promise);
+  return <div>{user.name}</div>;
+}
+const Orders = ({ promise }) => <List items={use(promise)} />;
+function Page({ profile }) {
+  return <Profile promise={profile} />;
+}
+export function App({ profile, orders }) {
+  return (
+    <main>
+      <Suspense fallback={<Loading />}>
+        <Page profile={profile} />
+      </Suspense>
+      <Orders promise={orders} />
+    </main>
+  );
+}"#,
+        );
+        let mut visitor = TransformVisitor::builder()
+            .config(Config {
+                diagnostics: true,
+                ..Default::default()
+            })
+            .env(Environment::Development)
+            .filename("my/file.tsx")
+            .build();
+        module.visit_mut_with(&mut visitor);
+
+        let messages: Vec<_> = visitor
+            .diagnostics()
+            .iter()
+            .map(|diagnostic| (diagnostic.rule, diagnostic.message.as_str()))
+            .collect();
+        assert_eq!(
+            messages,
+            vec![(
+                diagnostics::UNWRAPPED_SUSPENDER,
+                "<Orders> calls a suspending API but is not rendered inside a boundary in this file"
+            )]
+        );
+    }
+
+    #[test]
+    fn diagnostics_report_unguarded_lazy_components() {
+        let mut module = parse_tsx(
+            r#"import { Suspense, lazy } from "react";
+import dynamic from "next/dynamic";
+function Dashboard() {
+  return <Chart />;
+}
+export function App() {
+  return (
+    <main>
+      <Suspense fallback={<Loading />}>
+        <Dashboard />
+        <Map />
+      </Suspense>
+      <Map />
+      <Settings />
+    </main>
+  );
+}
+const Chart = lazy(() => import("./Chart"));
+const Map = dynamic(() => import("./Map"));"#,
+        );
+        let mut visitor = TransformVisitor::builder()
+            .config(Config {
+                diagnostics: true,
+                ..Default::default()
+            })
+            .env(Environment::Development)
+            .filename("my/file.tsx")
+            .build();
+        module.visit_mut_with(&mut visitor);
+
+        let messages: Vec<_> = visitor
+            .diagnostics()
+            .iter()
+            .map(|diagnostic| (diagnostic.rule, diagnostic.message.as_str()))
+            .collect();
+        assert_eq!(
+            messages,
+            vec![(
+                diagnostics::UNGUARDED_LAZY,
+                "<Map> is a lazy component but is not rendered inside a Suspense boundary in this file"
+            )]
+        );
+    }
+
+    #[test]
+    fn diagnostics_report_thrown_promises() {
+        let mut module = parse_tsx(THROWN_PROMISES);
+        let mut visitor = TransformVisitor::builder()
+            .config(Config {
+                diagnostics: true,
+                ..Default::default()
+            })
+            .env(Environment::Development)
+            .filename("my/file.tsx")
+            .build();
+        module.visit_mut_with(&mut visitor);
+
+        let messages: Vec<_> = visitor
+            .diagnostics()
+            .iter()
+            .map(|diagnostic| (diagnostic.rule, diagnostic.message.as_str()))
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                (
+                    diagnostics::THROWN_PROMISE,
+                    "useUser throws a promise to suspend, use `use(promise)` instead"
+                ),
+                (
+                    diagnostics::THROWN_PROMISE,
+                    "<Profile> throws a promise to suspend, use `use(promise)` instead"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn nested_boundaries_reference_their_parent() {
+        let mut module = parse_tsx(DEEPLY_NESTED_SUSPENSE);
+        let mut visitor = TransformVisitor::builder()
+            .env(Environment::Development)
+            .filename("my/file.tsx")
+            .build();
+        module.visit_mut_with(&mut visitor);
+
+        let boundaries = visitor.boundaries();
+        let parents: Vec<_> = boundaries
+            .iter()
+            .map(|boundary| boundary.parent.as_deref())
+            .collect();
+        assert_eq!(
+            parents,
+            vec![
+                None,
+                Some(boundaries[0].id.as_str()),
+                Some(boundaries[1].id.as_str()),
+                Some(boundaries[0].id.as_str()),
+            ]
+        );
+    }
+
+    #[test]
+    fn forbidden_boundaries_are_reported_as_errors() {
+        let forbidden_in = |filename: &str| {
+            let mut visitor = TransformVisitor::builder()
+                .config(Config {
+                    forbid: vec![ForbiddenBoundary {
+                        component: "Suspense".into(),
+                        from: Some("react".into()),
+                        paths: Some(vec!["src/routes/**".into()]),
+                        message: Some("use RouteBoundary instead".into()),
+                    }],
+                    ..Default::default()
+                })
+                .env(Environment::Development)
+                .filename(filename)
+                .build();
+            parse_tsx(BASIC_SUSPENSE).visit_mut_with(&mut visitor);
+            visitor
+                .diagnostics()
+                .iter()
+                .map(|diagnostic| (diagnostic.rule, diagnostic.severity))
+                .collect::<Vec<_>>()
+        };
+
+        let error = vec![(diagnostics::FORBIDDEN_BOUNDARY, Severity::Error)];
+        assert_eq!(forbidden_in("src/routes/home/page.tsx"), error);
+        assert_eq!(forbidden_in("/app/src/routes/page.tsx"), error);
+        assert!(forbidden_in("src/components/page.tsx").is_empty());
+    }
+
+    #[test]
+    fn boundary_budgets_report_the_first_exceeding_boundary() {
+        let code = r#"import { Suspense } from "react";
+export const App = () => (
+  <Suspense fallback={null}>
+    <Suspense fallback={null}>
+      <Suspense fallback={null}>
+        <Content />
+      </Suspense>
+    </Suspense>
+  </Suspense>
+);"#;
+        let reported_rules = |filename: &str| {
+            let mut visitor = TransformVisitor::builder()
+                .config(Config {
+                    max_boundaries_per_file: Some(2),
+                    boundary_budgets: vec![BoundaryBudget {
+                        paths: vec!["src/pages/**".into()],
+                        max: 3,
+                    }],
+                    ..Default::default()
+                })
+                .env(Environment::Development)
+                .filename(filename)
+                .build();
+            parse_tsx(code).visit_mut_with(&mut visitor);
+            visitor
+                .diagnostics()
+                .iter()
+                .map(|diagnostic| (diagnostic.rule, diagnostic.severity))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            reported_rules("src/components/App.tsx"),
+            vec![(diagnostics::BOUNDARY_BUDGET, Severity::Warning)]
+        );
+        assert!(reported_rules("src/pages/App.tsx").is_empty());
+    }
+
+    #[test]
+    fn cached_config_is_reparsed_for_other_json() {
+        let lazy = Config::from_json_cached(r#"{"trackLazy": true}"#).expect("valid config");
+        assert!(lazy.track_lazy);
+        let cached = Config::from_json_cached(r#"{"trackLazy": true}"#).expect("valid config");
+        assert!(Rc::ptr_eq(&lazy, &cached));
+
+        let default = Config::from_json_cached("{}").expect("valid config");
+        assert!(!default.track_lazy);
+        assert!(!Rc::ptr_eq(&lazy, &default));
+        assert!(Config::from_json_cached(r#"{"unknownOption": true}"#).is_err());
+    }
+
+    #[test]
+    fn missing_metadata_falls_back_to_the_config() {
+        let (context, warning) = Context::from_metadata(
+            Some("development"),
+            Some("my/file.tsx".into()),
+            &Config::default(),
+        );
+        assert_eq!(context.env_name, Environment::Development);
+        assert_eq!(warning, None);
+
+        let config: Config = serde_json::from_str(
+            r#"{"defaultEnv": "development", "fallbackFilename": "my/file.tsx"}"#,
+        )
+        .expect("valid config");
+        let (context, warning) = Context::from_metadata(None, None, &config);
+        assert_eq!(context.env_name, Environment::Development);
+        assert_eq!(context.filename, "my/file.tsx");
+        assert_eq!(
+            warning.as_deref(),
+            Some("the host did not provide the environment - using `defaultEnv`; the host did not provide the filename - using `fallbackFilename`")
+        );
+
+        let (context, warning) =
+            Context::from_metadata(None, Some("my/file.tsx".into()), &Config::default());
+        assert_eq!(context.env_name, Environment::Production);
+        assert!(warning.is_some_and(|warning| warning.contains("production build")));
+
+        let (context, warning) = Context::from_metadata(Some("staging"), None, &config);
+        assert_eq!(context.env_name, Environment::Development);
+        assert!(warning.is_some_and(|warning| warning.starts_with("staging is not a valid")));
+
+        // A missing filename keeps the environment of the host
+        let (context, warning) =
+            Context::from_metadata(Some("development"), None, &Config::default());
+        assert_eq!(context.env_name, Environment::Development);
+        assert_eq!(context.filename, "unknown");
+        assert!(warning.is_some_and(|warning| warning.contains("filename")));
+    }
+
+    #[test]
+    fn prescan_skips_modules_without_boundaries() {
+        let visitor = TransformVisitor::builder()
+            .env(Environment::Development)
+            .filename("my/file.tsx")
+            .build();
+
+        assert!(visitor.may_transform(BASIC_SUSPENSE));
+        assert!(!visitor.may_transform(r#"export const App = () => <div>Hello World</div>;"#));
+        // `react` is imported but there is no `Suspense`
+        assert!(!visitor.may_transform(NO_SUSPENSE));
+
+        let lazy_visitor = TransformVisitor::builder()
+            .config(Config {
+                track_lazy: true,
+                ..Default::default()
+            })
+            .env(Environment::Development)
+            .build();
+        assert!(lazy_visitor.may_transform(NO_SUSPENSE));
+
+        // Manual trackers of a custom entry point are stripped
+        let strip_visitor = TransformVisitor::builder()
+            .config(Config {
+                strip: true,
+                entry_points: EntryPoints {
+                    suspense: Some("@acme/tracker".into()),
+                    error: None,
+                },
+                ..Default::default()
+            })
+            .env(Environment::Production)
+            .build();
+        assert!(strip_visitor.may_transform(
+            r#"import { BoundaryTrackerSWC } from "@acme/tracker";
+export const App = () => <BoundaryTrackerSWC boundaryId="manual"><Content /></BoundaryTrackerSWC>;"#
+        ));
+        assert!(!strip_visitor.may_transform(NO_SUSPENSE));
+    }
+
+    #[test]
+    fn transform_handles_large_modules() {
+        let elements: String = (0..1000)
+            .map(|index| format!("<Suspense fallback={{null}}><Item{index} /></Suspense>"))
+            .collect();
+        let mut module = parse_tsx(&format!(
+            "import {{ Suspense }} from \"react\";\nexport const App = () => <>{elements}</>;"
+        ));
+
+        let mut visitor = TransformVisitor::builder()
+            .env(Environment::Development)
+            .filename("my/file.tsx")
+            .build();
+        module.visit_mut_with(&mut visitor);
+
+        assert_eq!(visitor.boundaries().len(), 1000);
+    }
+
+    #[test]
+    fn boundaries_can_override_the_id_strategy() {
+        let code = r#"import { Suspense } from "react";
+import { ErrorBoundary } from "react-error-boundary";
+export const App = () => <ErrorBoundary fallback={null}>
+  <Suspense fallback={null}><Content /></Suspense>
+</ErrorBoundary>;"#;
+        let mut module = parse_tsx(code);
+        let config: Config = serde_json::from_str(
+            r#"{
+              "idPrefix": "shop:",
+              "boundaries": [
+                { "component": "ErrorBoundary", "from": "react-error-boundary", "idStrategy": "hash", "idPrefix": "" }
+              ]
+            }"#,
+        )
+        .expect("valid config");
+
+        let mut visitor = TransformVisitor::builder()
+            .config(config)
+            .env(Environment::Development)
+            .filename("my/file.tsx")
+            .source_text(code)
+            .build();
+        module.visit_mut_with(&mut visitor);
+
+        let ids: Vec<&str> = visitor
+            .boundaries()
+            .iter()
+            .map(|boundary| boundary.id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["82b9de08", "shop:my/file.tsx:4"]);
+    }
+
+    #[test]
+    fn source_text_resolves_lines_without_a_source_map() {
+        let code = format!("#!/usr/bin/env node\n// leading comment\n{MULTIPLE_SUSPENSE}");
+        let mut module = parse_tsx(&code);
+
+        let mut visitor = TransformVisitor::builder()
+            .env(Environment::Development)
+            .filename("my/file.tsx")
+            .source_text(code)
+            .build();
+        module.visit_mut_with(&mut visitor);
+
+        let lines: Vec<usize> = visitor
+            .boundaries()
+            .iter()
+            .map(|boundary| boundary.line)
+            .collect();
+        assert_eq!(lines, vec![7, 10]);
+    }
+
+    #[test]
+    fn annotate_mode_adds_a_comment_per_boundary() {
+        let mut module = parse_tsx(MULTIPLE_SUSPENSE);
+        let comments = SingleThreadedComments::default();
+        module.visit_mut_with(
+            &mut TransformVisitor::builder()
+                .mode(Mode::Annotate)
+                .env(Environment::Production)
+                .filename("my/file.tsx")
+                .comments(comments.clone())
+                .build(),
+        );
+        let mut elements = JsxElementCollector::default();
+        module.visit_with(&mut elements);
+
+        let annotations: Vec<String> = elements
+            .0
+            .iter()
+            .filter_map(|element| comments.get_leading(element.span.lo))
+            .flatten()
+            .map(|comment| comment.text.to_string())
+            .collect();
+        assert_eq!(
+            annotations,
+            vec![
+                " @boundary id=my/file.tsx:0 kind=suspense ",
+                " @boundary id=my/file.tsx:0#2 kind=suspense "
+            ]
+        );
+        assert!(elements.0.iter().all(|element| !matches!(
+            &element.opening.name,
+            JSXElementName::Ident(name) if &*name.sym == BOUNDARY_TRACKER_IMPORT_NAME
+        )));
+    }
+
+    #[test]
+    fn route_hints_follow_file_conventions() {
+        let hint =
+            |filename: &str| routes::route_hint(filename, &routes::default_route_conventions());
+
+        // Next.js pages directory
+        assert_eq!(hint("pages/index.tsx").as_deref(), Some("/"));
+        assert_eq!(
+            hint("src/pages/products/[id].tsx").as_deref(),
+            Some("/products/[id]")
+        );
+        assert_eq!(hint("pages/_app.tsx"), None);
+        // Next.js app directory
+        assert_eq!(
+            hint("app/(shop)/products/[id]/page.tsx").as_deref(),
+            Some("/products/[id]")
+        );
+        assert_eq!(
+            hint("/repo/apps/web/src/app/@modal/settings/loading.tsx").as_deref(),
+            Some("/settings")
+        );
+        assert_eq!(hint("app/(marketing)/page.tsx").as_deref(), Some("/"));
+        // React Router / Remix flat routes
+        assert_eq!(
+            hint("app/routes/products.$id.tsx").as_deref(),
+            Some("/products/:id")
+        );
+        assert_eq!(
+            hint("app/routes/_auth.login/route.tsx").as_deref(),
+            Some("/login")
+        );
+        assert_eq!(hint("src/routes/_index.tsx").as_deref(), Some("/"));
+        assert_eq!(hint("src/routes/files.$.tsx").as_deref(), Some("/files/*"));
+        assert_eq!(
+            hint("app/routes/products_.$id.edit.tsx").as_deref(),
+            Some("/products/:id/edit")
+        );
+        assert_eq!(hint("src/components/App.tsx"), None);
+
+        let custom = [RouteConvention {
+            dir: "src/screens".into(),
+            style: RouteStyle::Pages,
+        }];
+        assert_eq!(
+            routes::route_hint("src/screens/checkout.tsx", &custom).as_deref(),
+            Some("/checkout")
+        );
+    }
+
+    #[test]
+    fn route_hint_is_added_to_trackers() {
+        let mut module = parse_tsx(BASIC_SUSPENSE);
+        module.visit_mut_with(
+            &mut TransformVisitor::builder()
+                .config(Config {
+                    emit_route_hint: true,
+                    ..Default::default()
+                })
+                .env(Environment::Development)
+                .filename("src/pages/products/[id].tsx")
+                .build(),
+        );
+        let mut trackers = JsxElementCollector::default();
+        module.visit_with(&mut trackers);
+
+        let route_hint = trackers.0[0]
+            .opening
+            .attrs
+            .iter()
+            .find_map(|attr| match attr {
+                JSXAttrOrSpread::JSXAttr(JSXAttr {
+                    name: JSXAttrName::Ident(name),
+                    value: Some(JSXAttrValue::Str(value)),
+                    ..
+                }) if &*name.sym == "routeHint" => value.value.as_str().map(str::to_string),
+                _ => None,
+            });
+        assert_eq!(route_hint.as_deref(), Some("/products/[id]"));
+    }
+
+    #[test]
+    fn transform_preserves_element_spans() {
+        let mut module = parse_tsx(BASIC_SUSPENSE);
+        let mut original = JsxElementCollector::default();
+        module.visit_with(&mut original);
+        let suspense = &original.0[0];
+
+        module.visit_mut_with(
+            &mut TransformVisitor::builder()
+                .env(Environment::Development)
+                .filename("my/file.tsx")
+                .build(),
+        );
+        let mut transformed = JsxElementCollector::default();
+        module.visit_with(&mut transformed);
+        let tracker = &transformed.0[0];
+
+        let (JSXElementName::Ident(original_name), JSXElementName::Ident(tracker_name)) =
+            (&suspense.opening.name, &tracker.opening.name)
+        else {
+            panic!("expected identifier element names");
+        };
+        assert_eq!(&*tracker_name.sym, BOUNDARY_TRACKER_IMPORT_NAME);
+        assert_eq!(tracker_name.span, original_name.span);
+        assert_eq!(tracker.span, suspense.span);
+
+        let (Some(original_closing), Some(tracker_closing)) = (&suspense.closing, &tracker.closing)
+        else {
+            panic!("expected closing elements");
+        };
+        assert_eq!(
+            tracker_closing.name.span(),
+            original_closing.name.span(),
+            "closing name keeps its span"
+        );
+
+        for attr in &tracker.opening.attrs[suspense.opening.attrs.len()..] {
+            assert_eq!(
+                attr.span(),
+                original_name.span,
+                "injected attributes use the name span"
+            );
+        }
+    }
+
+    #[cfg(feature = "library")]
+    #[test]
+    fn transform_source_collects_boundaries() {
+        let output = transform_source(
+            BASIC_SUSPENSE,
+            Config::default(),
+            Context {
+                env_name: Environment::Development,
+                filename: "my/file.tsx".into(),
+            },
+        )
+        .expect("failed to transform source");
+
+        assert!(output.code.contains("<BoundaryTrackerSWC"));
+        assert!(output.code.contains(r#"boundaryId="my/file.tsx:4""#));
+        assert_eq!(
+            output.boundaries,
+            vec![BoundaryMetadata {
+                id: "my/file.tsx:4".into(),
+                kind: BoundaryKind::Suspense,
+                component: "Suspense".into(),
+                from: "react".into(),
+                line: 4,
+                enclosing_component: Some("App".into()),
+                fallback: Some("Loading".into()),
+                parent: None,
+                location: None,
+            }]
+        );
+    }
+
+    #[cfg(feature = "library")]
+    #[test]
+    fn duplicate_boundary_ids_get_an_ordinal_suffix() {
+        let output = transform_source(
+            r#"import { Suspense } from "react";
+export const App = () => <><Suspense fallback={null}><A /></Suspense><Suspense fallback={null}><B /></Suspense></>;"#,
+            Config {
+                diagnostics: true,
+                ..Default::default()
+            },
+            Context {
+                env_name: Environment::Development,
+                filename: "my/file.tsx".into(),
+            },
+        )
+        .expect("failed to transform source");
+
+        let ids: Vec<&str> = output
+            .boundaries
+            .iter()
+            .map(|boundary| boundary.id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["my/file.tsx:2", "my/file.tsx:2#2"]);
+        assert!(output.code.contains(r#"boundaryId="my/file.tsx:2#2""#));
+        let rules: Vec<&str> = output
+            .diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.rule)
+            .collect();
+        assert_eq!(rules, vec![diagnostics::DUPLICATE_BOUNDARY_ID]);
+    }
+
+    #[test]
+    fn boundary_ids_without_lines_get_an_ordinal_suffix() {
+        // Without a source map or source text every boundary of the file is on line 0
+        let mut module = parse_tsx(
+            r#"import { Suspense } from "react";
+export const App = () => <><Suspense fallback={null}><A /></Suspense><Suspense fallback={null}><B /></Suspense></>;"#,
+        );
+        let mut visitor = TransformVisitor::builder()
+            .config(Config {
+                diagnostics: true,
+                ..Default::default()
+            })
+            .env(Environment::Development)
+            .filename("my/file.tsx")
+            .build();
+        module.visit_mut_with(&mut visitor);
+
+        let ids: Vec<&str> = visitor
+            .boundaries()
+            .iter()
+            .map(|boundary| boundary.id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["my/file.tsx:0", "my/file.tsx:0#2"]);
+        let rules: Vec<&str> = visitor
+            .diagnostics()
+            .iter()
+            .map(|diagnostic| diagnostic.rule)
+            .collect();
+        assert_eq!(rules, vec![diagnostics::DUPLICATE_BOUNDARY_ID]);
+    }
+
+    #[cfg(feature = "library")]
+    #[test]
+    fn analyze_mode_reports_boundaries_in_comment() {
+        let output = transform_source(
+            BASIC_SUSPENSE,
+            Config {
+                mode: Mode::Analyze,
+                ..Default::default()
+            },
+            Context {
+                env_name: Environment::Production,
+                filename: "my/file.tsx".into(),
+            },
+        )
+        .expect("failed to transform source");
+
+        assert!(!output.code.contains("BoundaryTrackerSWC"));
+        assert!(output.code.contains(
+            r#"/* @suspense-boundaries [{"id":"my/file.tsx:4","kind":"suspense","component":"Suspense","from":"react","line":4,"enclosingComponent":"App","fallback":"Loading"}] */"#
+        ));
+    }
+
+    #[cfg(feature = "library")]
+    #[test]
+    fn numeric_ids_are_mapped_in_the_manifest_comment() {
+        let output = transform_source(
+            MULTIPLE_SUSPENSE,
+            Config {
+                id_strategy: Some(IdStrategy::Numeric),
+                ..Default::default()
+            },
+            Context {
+                env_name: Environment::Development,
+                filename: "my/file.tsx".into(),
+            },
+        )
+        .expect("failed to transform source");
+
+        assert!(output.code.contains(r#"boundaryId="1""#));
+        assert!(output.code.contains(r#"boundaryId="2""#));
+        assert!(output.code.contains(
+            r#"/* @suspense-boundaries [{"id":"1","kind":"suspense","component":"Suspense","from":"react","line":5,"enclosingComponent":"App","fallback":"Loading","location":"my/file.tsx:5"},{"id":"2","kind":"suspense","component":"Suspense","from":"react","line":8,"enclosingComponent":"App","fallback":"div","location":"my/file.tsx:8"}] */"#
+        ));
+    }
+
+    #[cfg(feature = "library")]
+    #[test]
+    fn stats_comment_summarizes_the_transformation() {
+        let transform = |code: &str| {
+            transform_source(
+                code,
+                Config {
+                    emit_stats: true,
+                    ..Default::default()
+                },
+                Context {
+                    env_name: Environment::Development,
+                    filename: "my/file.tsx".into(),
+                },
+            )
+            .expect("failed to transform source")
+            .code
+        };
+
+        assert!(transform(BASIC_SUSPENSE)
+            .contains("/* suspense-tracker: 1 boundary, 1 import injected */"));
+        assert!(!transform(NO_SUSPENSE).contains("suspense-tracker:"));
+    }
+
+    #[cfg(feature = "library")]
+    #[test]
+    fn manifest_comment_uses_configured_marker() {
+        let output = transform_source(
+            BASIC_SUSPENSE,
+            Config {
+                emit_manifest_comment: true,
+                manifest_marker: Some("@boundaries".into()),
+                ..Default::default()
+            },
+            Context {
+                env_name: Environment::Development,
+                filename: "my/file.tsx".into(),
+            },
+        )
+        .expect("failed to transform source");
+
+        assert!(output.code.contains("<BoundaryTrackerSWC"));
+        assert!(output
+            .code
+            .contains(r#"/* @boundaries [{"id":"my/file.tsx:4","kind":"suspense""#));
+    }
 }
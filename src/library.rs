@@ -0,0 +1,138 @@
+use swc_core::{
+    common::{
+        comments::SingleThreadedComments, sync::Lrc, FileName, Globals, Mark, SourceMap, GLOBALS,
+    },
+    ecma::{
+        ast::{EsVersion, Program},
+        codegen::to_code_default,
+        parser::{parse_file_as_module, EsSyntax, Syntax, TsSyntax},
+        transforms::base::resolver,
+        visit::visit_mut_pass,
+    },
+};
+
+use crate::{BoundaryMetadata, Config, Context, Diagnostic, Mode, TransformVisitor};
+
+/// Result of [`transform_source`]
+#[derive(Debug)]
+pub struct TransformOutput {
+    /// The emitted code
+    pub code: String,
+    /// All boundaries found in the source
+    pub boundaries: Vec<BoundaryMetadata>,
+    /// Problems reported by the diagnostics rules
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// Transforms a source string without going through the wasm plugin host.
+///
+/// Parses `code` (TypeScript for `.ts`/`.tsx` files, JavaScript with JSX otherwise),
+/// runs the same visitor as the plugin and returns the emitted code together
+/// with the boundaries that were found.
+pub fn transform_source(
+    code: &str,
+    config: Config,
+    context: Context,
+) -> Result<TransformOutput, String> {
+    run(code, config, context, true)
+}
+
+/// Detects the boundaries of a source string without emitting any code.
+///
+/// Boundaries are always detected, independent of the environment.
+pub fn analyze_source(
+    code: &str,
+    config: Config,
+    context: Context,
+) -> Result<Vec<BoundaryMetadata>, String> {
+    let config = Config {
+        enabled: Some(true),
+        mode: Mode::Analyze,
+        ..config
+    };
+    Ok(run(code, config, context, false)?.boundaries)
+}
+
+/// Runs the diagnostics rules on a source string without emitting any code.
+///
+/// Like [`analyze_source`] the rules run independent of the environment.
+pub fn check_source(
+    code: &str,
+    config: Config,
+    context: Context,
+) -> Result<Vec<Diagnostic>, String> {
+    let config = Config {
+        enabled: Some(true),
+        mode: Mode::Analyze,
+        ..config
+    };
+    Ok(run(code, config, context, false)?.diagnostics)
+}
+
+/// Parses and transforms `code`, only running the code generator if `emit_code` is set
+fn run(
+    code: &str,
+    config: Config,
+    context: Context,
+    emit_code: bool,
+) -> Result<TransformOutput, String> {
+    let source_map: Lrc<SourceMap> = Default::default();
+    let source_file = source_map.new_source_file(
+        Lrc::new(FileName::Custom(context.filename.clone())),
+        code.to_string(),
+    );
+    let comments = SingleThreadedComments::default();
+    let syntax = syntax_for_filename(&context.filename);
+
+    GLOBALS.set(&Globals::new(), || {
+        let module = parse_file_as_module(
+            &source_file,
+            syntax,
+            EsVersion::latest(),
+            Some(&comments),
+            &mut vec![],
+        )
+        .map_err(|error| format!("failed to parse {}: {error:?}", context.filename))?;
+
+        let unresolved_mark = Mark::new();
+        let top_level_mark = Mark::new();
+        let program = Program::Module(module).apply(resolver(
+            unresolved_mark,
+            top_level_mark,
+            syntax.typescript(),
+        ));
+
+        let mut visitor = TransformVisitor::builder()
+            .config(config)
+            .context(context)
+            .source_map(source_map.clone())
+            .comments(comments.clone())
+            .build();
+        let program = program.apply(visit_mut_pass(&mut visitor));
+
+        Ok(TransformOutput {
+            code: if emit_code {
+                to_code_default(source_map, Some(&comments), &program)
+            } else {
+                String::new()
+            },
+            boundaries: visitor.boundaries().to_vec(),
+            diagnostics: visitor.diagnostics().to_vec(),
+        })
+    })
+}
+
+/// Picks the parser syntax based on the file extension
+fn syntax_for_filename(filename: &str) -> Syntax {
+    if filename.ends_with(".ts") || filename.ends_with(".tsx") {
+        Syntax::Typescript(TsSyntax {
+            tsx: filename.ends_with(".tsx"),
+            ..Default::default()
+        })
+    } else {
+        Syntax::Es(EsSyntax {
+            jsx: true,
+            ..Default::default()
+        })
+    }
+}
@@ -0,0 +1,135 @@
+use std::cell::OnceCell;
+
+use swc_core::common::{BytePos, SourceMapper};
+
+/// Line start positions of the transformed file
+///
+/// Every `lookup_char_pos` call of the plugin source map proxy crosses the wasm host
+/// boundary, so the line starts are computed once from the first lookup and all
+/// further lines are resolved locally.
+#[derive(Default)]
+pub struct LineCache {
+    table: OnceCell<LineTable>,
+}
+
+struct LineTable {
+    start_pos: BytePos,
+    end_pos: BytePos,
+    /// Positions of the first character of every line
+    line_starts: Vec<BytePos>,
+}
+
+impl LineCache {
+    /// Returns the 1-based line of `pos`
+    pub fn lookup_line(&self, source_map: &dyn SourceMapper, pos: BytePos) -> usize {
+        if let Some(line) = self.table.get().and_then(|table| table.line(pos)) {
+            return line;
+        }
+
+        let loc = source_map.lookup_char_pos(pos);
+        if self.table.get().is_none() {
+            let file = &loc.file;
+            let _ = self
+                .table
+                .set(LineTable::new(&file.src, file.start_pos, file.end_pos));
+        }
+        loc.line
+    }
+
+    /// Computes the line starts from the raw source text if no source map is available
+    ///
+    /// `first_token_pos` is the position of the first token of the module, which is used
+    /// to find the position the file starts at.
+    pub fn init_from_source(&self, source: &str, first_token_pos: BytePos) {
+        // The parser's source map drops the BOM, so positions start after it
+        let source = source.strip_prefix('\u{feff}').unwrap_or(source);
+        let offset = BytePos(first_token_offset(source) as u32);
+        if first_token_pos < offset {
+            return;
+        }
+        let start_pos = first_token_pos - offset;
+        let end_pos = start_pos + BytePos(source.len() as u32);
+        let _ = self.table.set(LineTable::new(source, start_pos, end_pos));
+    }
+
+    /// Returns the 1-based line of `pos` from the line starts computed by
+    /// [`Self::init_from_source`] (0 if unknown)
+    pub fn source_line(&self, pos: BytePos) -> usize {
+        self.table
+            .get()
+            .and_then(|table| table.line(pos))
+            .unwrap_or(0)
+    }
+
+    /// Returns the 1-based column of `pos` from the line starts computed by
+    /// [`Self::init_from_source`] (0 if unknown)
+    pub fn source_column(&self, pos: BytePos) -> usize {
+        self.table
+            .get()
+            .and_then(|table| table.column(pos))
+            .unwrap_or(0)
+    }
+
+    /// Returns the 1-based column (in bytes) of `pos`
+    pub fn lookup_column(&self, source_map: &dyn SourceMapper, pos: BytePos) -> usize {
+        self.lookup_line(source_map, pos);
+        self.table
+            .get()
+            .and_then(|table| table.column(pos))
+            .unwrap_or_else(|| source_map.lookup_char_pos(pos).col.0 + 1)
+    }
+}
+
+impl LineTable {
+    fn new(src: &str, start_pos: BytePos, end_pos: BytePos) -> Self {
+        let line_starts = std::iter::once(start_pos)
+            .chain(
+                src.match_indices('\n')
+                    .map(|(index, _)| start_pos + BytePos(index as u32 + 1)),
+            )
+            .collect();
+        LineTable {
+            start_pos,
+            end_pos,
+            line_starts,
+        }
+    }
+
+    /// Returns the line of `pos` if it is part of this file
+    fn line(&self, pos: BytePos) -> Option<usize> {
+        if pos < self.start_pos || pos > self.end_pos {
+            return None;
+        }
+        Some(
+            self.line_starts
+                .partition_point(|line_start| *line_start <= pos),
+        )
+    }
+
+    /// Returns the column of `pos` if it is part of this file
+    fn column(&self, pos: BytePos) -> Option<usize> {
+        let line_start = self.line_starts[self.line(pos)? - 1];
+        Some((pos - line_start).0 as usize + 1)
+    }
+}
+
+/// Returns the byte offset of the first token, skipping a shebang,
+/// whitespace and comments
+fn first_token_offset(source: &str) -> usize {
+    let mut offset = 0;
+    if source[offset..].starts_with("#!") {
+        offset += source[offset..].find('\n').unwrap_or(source.len() - offset);
+    }
+    loop {
+        let rest = &source[offset..];
+        let trimmed = rest.trim_start();
+        offset += rest.len() - trimmed.len();
+        if let Some(comment) = trimmed.strip_prefix("//") {
+            offset += 2 + comment.find('\n').unwrap_or(comment.len());
+        } else if let Some(comment) = trimmed.strip_prefix("/*") {
+            offset += 2 + comment.find("*/").map_or(comment.len(), |end| end + 2);
+        } else {
+            return offset;
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use swc_core::ecma::ast::*;
+
+use crate::{
+    ast_utils::{jsx_attr_value, tpl_expr},
+    TransformVisitor,
+};
+
+/// Array methods whose callbacks render one element per item
+const ARRAY_CALLBACK_METHODS: &[&str] = &["map", "flatMap", "forEach"];
+
+/// Checks if the call is `items.map(...)`, `items.flatMap(...)` or `items.forEach(...)`
+pub(crate) fn is_array_callback_call(call: &CallExpr) -> bool {
+    let Callee::Expr(callee) = &call.callee else {
+        return false;
+    };
+    let Expr::Member(MemberExpr {
+        prop: MemberProp::Ident(method),
+        ..
+    }) = &**callee
+    else {
+        return false;
+    };
+    ARRAY_CALLBACK_METHODS.contains(&&*method.sym)
+        && call
+            .args
+            .iter()
+            .any(|arg| matches!(*arg.expr, Expr::Arrow(_) | Expr::Fn(_)))
+}
+
+/// Returns the expression of a `key={expr}` attribute
+fn key_expr(jsx_element: &JSXElement) -> Option<&Expr> {
+    match jsx_attr_value(jsx_element, "key")? {
+        JSXAttrValue::JSXExprContainer(JSXExprContainer {
+            expr: JSXExpr::Expr(expr),
+            ..
+        }) => Some(expr),
+        _ => None,
+    }
+}
+
+impl TransformVisitor {
+    /// Remembers the `key` of the outermost keyed element of the enclosing array callback
+    /// and returns whether it has to be forgotten once the element is left
+    pub(crate) fn enter_keyed_element(&mut self, jsx_element: &JSXElement) -> bool {
+        let Some(loop_key @ None) = self.loop_keys.last_mut() else {
+            return false;
+        };
+        let Some(key) = key_expr(jsx_element) else {
+            return false;
+        };
+        *loop_key = Some(key.clone());
+        true
+    }
+
+    pub(crate) fn exit_keyed_element(&mut self, keyed: bool) {
+        if keyed {
+            if let Some(loop_key) = self.loop_keys.last_mut() {
+                *loop_key = None;
+            }
+        }
+    }
+
+    /// Combines the boundary ID with the `key` of the enclosing array callback
+    /// (`` `src/List.tsx:20:${item.id}` ``), only with `keyed_loop_ids`
+    pub(crate) fn keyed_loop_id(&self, id_value: &str, id: Expr) -> Expr {
+        if !self.config.keyed_loop_ids {
+            return id;
+        }
+        let Some(Some(key)) = self.loop_keys.last() else {
+            return id;
+        };
+        match id {
+            Expr::Lit(Lit::Str(_)) => tpl_expr(&[&format!("{id_value}:"), ""], vec![key.clone()]),
+            id => tpl_expr(&["", ":", ""], vec![id, key.clone()]),
+        }
+    }
+}
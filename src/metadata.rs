@@ -0,0 +1,30 @@
+use serde::Serialize;
+
+use crate::BoundaryKind;
+
+/// Information about a boundary element found while transforming a file
+#[derive(Debug, Serialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BoundaryMetadata {
+    /// The generated boundary ID (e.g. `src/App.tsx:12`)
+    pub id: String,
+    /// Whether the boundary catches suspensions or errors
+    pub kind: BoundaryKind,
+    /// The imported name of the boundary component
+    pub component: String,
+    /// The package the boundary component was imported from
+    pub from: String,
+    /// The line of the boundary element (0 if unknown)
+    pub line: usize,
+    /// The name of the component rendering the boundary
+    pub enclosing_component: Option<String>,
+    /// The name of the element passed as `fallback` (e.g. `PageSkeleton`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fallback: Option<String>,
+    /// The ID of the enclosing boundary of the same file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<String>,
+    /// `file:line` of boundaries with numeric IDs which don't contain their location
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+}
@@ -0,0 +1,60 @@
+use napi::{Error, Result};
+use napi_derive::napi;
+use serde_json::Value;
+
+use crate::{transform_source, Config, Context, Environment};
+
+/// Options of `transformSync`
+#[napi(object)]
+pub struct TransformOptions {
+    /// The name of the file used in the boundary IDs (e.g. `src/App.tsx`)
+    pub filename: String,
+    /// `development`, `test` or `production` (defaults to `development`)
+    pub env_name: Option<String>,
+    /// The plugin options
+    pub config: Option<Value>,
+}
+
+/// Result of `transformSync`
+#[napi(object)]
+pub struct TransformResult {
+    /// The emitted code
+    pub code: String,
+    /// All boundaries found in the source
+    pub boundaries: Value,
+    /// Problems reported by the diagnostics rules
+    pub diagnostics: Value,
+}
+
+/// Transforms a source string like the SWC plugin, without the wasm plugin host
+#[napi(js_name = "transformSync")]
+pub fn transform_sync(code: String, options: TransformOptions) -> Result<TransformResult> {
+    let env_name = match options.env_name.as_deref() {
+        Some(env_name) => Environment::try_from(env_name).map_err(Error::from_reason)?,
+        None => Environment::Development,
+    };
+    let config: Config = match options.config {
+        Some(config) => serde_json::from_value(config)
+            .map_err(|error| Error::from_reason(format!("invalid config: {error}")))?,
+        None => Config::default(),
+    };
+
+    let output = transform_source(
+        &code,
+        config,
+        Context {
+            env_name,
+            filename: options.filename,
+        },
+    )
+    .map_err(Error::from_reason)?;
+    Ok(TransformResult {
+        code: output.code,
+        boundaries: to_json(&output.boundaries)?,
+        diagnostics: to_json(&output.diagnostics)?,
+    })
+}
+
+fn to_json(value: &impl serde::Serialize) -> Result<Value> {
+    serde_json::to_value(value).map_err(|error| Error::from_reason(error.to_string()))
+}
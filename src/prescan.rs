@@ -0,0 +1,75 @@
+use crate::{
+    apollo::APOLLO_PACKAGE_NAME,
+    hooks::{SWR_PACKAGE_NAME, TANSTACK_QUERY_PACKAGE_NAME},
+    relay::RELAY_PACKAGE_NAME,
+    TransformVisitor,
+};
+
+/// Package name shared by the runtime entry points
+const RUNTIME_PACKAGE_PREFIX: &str = "react-swc-suspense-tracker";
+
+/// Modules whose imports are instrumented by the call tracking options
+const TRACKED_CALL_PACKAGES: [&str; 7] = [
+    "react",
+    "next/dynamic",
+    "@loadable/component",
+    TANSTACK_QUERY_PACKAGE_NAME,
+    SWR_PACKAGE_NAME,
+    RELAY_PACKAGE_NAME,
+    APOLLO_PACKAGE_NAME,
+];
+
+impl TransformVisitor {
+    /// Checks the raw source text of a module for anything the transform might change
+    ///
+    /// This is a cheap pre-check before walking the AST: it returns `false` only if the
+    /// module neither mentions a configured boundary (component and package) nor any
+    /// import instrumented by the enabled options. It may return `true` for modules
+    /// which end up untouched.
+    pub fn may_transform(&self, source: &str) -> bool {
+        let mentions_boundary = self.boundary_contexts.iter().any(|boundary| {
+            source.contains(boundary.from.as_str()) && source.contains(boundary.component.as_str())
+        });
+        if mentions_boundary {
+            return true;
+        }
+
+        // Custom entry points (`entryPoints`) don't share the package name
+        let mentions_runtime = source.contains(RUNTIME_PACKAGE_PREFIX)
+            || self
+                .tracker_sources()
+                .iter()
+                .any(|src| source.contains(src.as_str()));
+        if (self.config.strip || self.config.clean_disabled_imports) && mentions_runtime {
+            return true;
+        }
+
+        if self.config.track_async_components && self.tracks_calls() && source.contains("async") {
+            return true;
+        }
+
+        let mentions_suspending_hook = self
+            .config
+            .suspending_hooks
+            .iter()
+            .any(|hook| source.contains(hook.from.as_str()));
+        if mentions_suspending_hook && self.tracks_calls() {
+            return true;
+        }
+
+        if self.tracks_thrown_promises() && source.contains("throw") {
+            return true;
+        }
+
+        (self.tracks_calls() || self.tracks_coverage())
+            && (TRACKED_CALL_PACKAGES
+                .iter()
+                .any(|package| source.contains(package))
+                || self
+                    .config
+                    .deferred_value_hooks()
+                    .iter()
+                    .chain(&self.config.swr_hooks())
+                    .any(|hook| source.contains(hook)))
+    }
+}
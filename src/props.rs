@@ -0,0 +1,511 @@
+use swc_core::{
+    common::{util::take::Take, Span, DUMMY_SP},
+    ecma::ast::*,
+};
+
+use crate::{
+    ast_utils::{
+        array_expr, call_expr, ident, jsx_expr_attr, jsx_str_attr, num_expr, object_expr, str_expr,
+    },
+    Boundary, BoundaryKind, BoundaryRef, EmitStyle, Platform, TransformVisitor,
+};
+
+/// Prop of the tracker which describes how to read the normalized boundary props
+pub(crate) const BOUNDARY_PROP_MAP_PROPERTY_NAME: &str = "boundaryPropMap";
+/// Prop with the name of the variable or component the boundary belongs to
+const DEBUG_NAME_PROPERTY_NAME: &str = "debugName";
+/// Prop with the names of the components enclosing the boundary (outermost first)
+const OWNER_STACK_PROPERTY_NAME: &str = "ownerStack";
+/// Prop with the route of the file the boundary is defined in
+const ROUTE_HINT_PROPERTY_NAME: &str = "routeHint";
+/// Prop with the imported name of the boundary component
+const ORIGINAL_NAME_PROPERTY_NAME: &str = "originalName";
+/// Prop with the number of boundaries enclosing the boundary in its file
+const DEPTH_PROPERTY_NAME: &str = "depth";
+/// Prop with the `{ fileName, lineNumber, columnNumber }` location of the boundary
+const BOUNDARY_SOURCE_PROPERTY_NAME: &str = "__boundarySource";
+/// Prop added by the JSX dev transform which already contains the location
+const JSX_SOURCE_PROPERTY_NAME: &str = "__source";
+/// Prop with all metadata of the boundary if `emitStyle` is `object`
+const BOUNDARY_META_PROPERTY_NAME: &str = "__boundaryMeta";
+/// Attribute added to intrinsic fallback elements for E2E tests
+const TEST_ATTRIBUTE_NAME: &str = "data-boundary-id";
+/// Attribute of intrinsic fallback elements for tracing SDKs
+const OTEL_ATTRIBUTE_NAME: &str = "data-otel-boundary-id";
+/// Attribute of intrinsic fallback elements reported by the Element Timing API
+const ELEMENT_TIMING_ATTRIBUTE_NAME: &str = "elementtiming";
+const ON_ERROR_PROPERTY_NAME: &str = "onError";
+const REPORT_BOUNDARY_ERROR_IMPORT_NAME: &str = "__reportBoundaryError";
+const BEFORE_CAPTURE_PROPERTY_NAME: &str = "beforeCapture";
+const TAG_BOUNDARY_CAPTURE_IMPORT_NAME: &str = "__tagBoundaryCapture";
+
+impl TransformVisitor {
+    /// Remembers the variable name of `const name = <Element />` for [`Self::add_debug_name`]
+    pub(crate) fn collect_jsx_binding(&mut self, declarator: &VarDeclarator) {
+        let (Pat::Ident(binding), Some(init)) = (&declarator.name, declarator.init.as_deref())
+        else {
+            return;
+        };
+        if let Expr::JSXElement(jsx_element) = init.unwrap_parens() {
+            self.jsx_binding = Some((jsx_element.span, binding.id.sym.to_string()));
+        }
+    }
+
+    /// Adds `debugName="ProductSection"` with the variable the element is assigned to
+    /// or the name of the enclosing component
+    pub(crate) fn add_debug_name(&self, jsx_element: &mut JSXElement) {
+        if !self.config.emit_debug_name || self.emits_id_only() {
+            return;
+        }
+        let debug_name = match &self.jsx_binding {
+            Some((span, name)) if *span == jsx_element.span => Some(name.clone()),
+            _ => self.enclosing_component(),
+        };
+        if let Some(debug_name) = debug_name {
+            jsx_element.opening.attrs.push(jsx_str_attr(
+                DEBUG_NAME_PROPERTY_NAME,
+                &debug_name,
+                DUMMY_SP,
+            ));
+        }
+    }
+
+    /// Adds `ownerStack={["App", "CheckoutPage"]}` with the enclosing components of the file
+    pub(crate) fn add_owner_stack(&self, jsx_element: &mut JSXElement) {
+        if !self.config.emit_owner_stack || self.emits_id_only() {
+            return;
+        }
+        let owners: Vec<Expr> = self
+            .component_stack
+            .iter()
+            .filter(|name| name.starts_with(|c: char| c.is_ascii_uppercase()))
+            .map(|name| str_expr(name))
+            .collect();
+        if owners.is_empty() {
+            return;
+        }
+        jsx_element.opening.attrs.push(jsx_expr_attr(
+            OWNER_STACK_PROPERTY_NAME,
+            array_expr(owners),
+            DUMMY_SP,
+        ));
+    }
+
+    /// Adds `originalName="Suspense"` with the imported name of the boundary
+    /// (also for aliased imports like `import { Suspense as Loader } from "react"`)
+    pub(crate) fn add_original_name(&self, jsx_element: &mut JSXElement, boundary: &Boundary) {
+        if self.config.emit_original_name && !self.emits_id_only() {
+            jsx_element.opening.attrs.push(jsx_str_attr(
+                ORIGINAL_NAME_PROPERTY_NAME,
+                &boundary.component,
+                DUMMY_SP,
+            ));
+        }
+    }
+
+    /// Adds `depth={1}` with the number of boundaries enclosing the boundary in its file
+    pub(crate) fn add_depth(&self, jsx_element: &mut JSXElement, depth: usize) {
+        if self.config.emit_depth {
+            jsx_element.opening.attrs.push(jsx_expr_attr(
+                DEPTH_PROPERTY_NAME,
+                num_expr(depth),
+                DUMMY_SP,
+            ));
+        }
+    }
+
+    /// Adds `otelAttributes={{ "code.filepath": "src/App.tsx", "code.lineno": 12 }}`
+    /// with the configured attributes of the current boundary
+    pub(crate) fn add_otel_attributes(&self, jsx_element: &mut JSXElement) {
+        if !self.config.otel {
+            return;
+        }
+        let Some(boundary) = self.boundaries.last() else {
+            return;
+        };
+        let attributes = self
+            .config
+            .otel_attributes()
+            .into_iter()
+            .map(|(name, field)| (name, self.boundary_field(boundary, field)))
+            .collect();
+        jsx_element.opening.attrs.push(jsx_expr_attr(
+            self.config.otel_prop_name(),
+            object_expr(attributes),
+            DUMMY_SP,
+        ));
+    }
+
+    /// Adds `routeHint="/products/[id]"` if the file matches a route convention
+    pub(crate) fn add_route_hint(&self, jsx_element: &mut JSXElement) {
+        if let Some(route_hint) = &self.route_hint {
+            jsx_element.opening.attrs.push(jsx_str_attr(
+                ROUTE_HINT_PROPERTY_NAME,
+                route_hint,
+                DUMMY_SP,
+            ));
+        }
+    }
+
+    /// Returns the name passed as `boundary="react:Suspense"` instead of the identifier
+    /// if `boundaryRef` is `string` and the runtime can render the boundary by name
+    pub(crate) fn boundary_name_ref(&self, boundary: &Boundary) -> Option<String> {
+        (self.config.boundary_ref == BoundaryRef::String && is_react_suspense(boundary))
+            .then(|| format!("{}:{}", boundary.from, boundary.component))
+    }
+
+    /// Whether `emitStyle` is `id` which skips the name metadata props
+    fn emits_id_only(&self) -> bool {
+        self.config.emit_style == EmitStyle::Id
+    }
+
+    /// Whether the `boundary` prop is left out because `emitStyle` is `id`
+    /// and the tracker renders the boundary (`Suspense` from `react`) by default
+    pub(crate) fn omits_boundary_prop(&self, boundary: &Boundary) -> bool {
+        self.emits_id_only() && is_react_suspense(boundary)
+    }
+
+    /// Creates `__boundaryMeta={{ id, kind, owner, fallbackName, boundary }}` which replaces
+    /// the `boundaryId` and `boundary` props if `emitStyle` is `object`
+    pub(crate) fn boundary_meta_attr(
+        &self,
+        jsx_element: &JSXElement,
+        id: Expr,
+        boundary: &Boundary,
+        boundary_ident: Ident,
+        span: Span,
+    ) -> JSXAttrOrSpread {
+        let str_or_null = |value: Option<String>| {
+            value.map_or(Expr::Lit(Lit::Null(Null { span: DUMMY_SP })), |value| {
+                str_expr(&value)
+            })
+        };
+        jsx_expr_attr(
+            BOUNDARY_META_PROPERTY_NAME,
+            object_expr(vec![
+                ("id", id),
+                ("kind", str_expr(boundary.kind().as_str())),
+                ("owner", str_or_null(self.enclosing_component())),
+                (
+                    "fallbackName",
+                    str_or_null(
+                        fallback_element(jsx_element)
+                            .map(|fallback| element_name(&fallback.opening.name)),
+                    ),
+                ),
+                (
+                    "boundary",
+                    self.boundary_name_ref(boundary)
+                        .map_or(Expr::Ident(boundary_ident), |name| str_expr(&name)),
+                ),
+            ]),
+            span,
+        )
+    }
+
+    /// Adds `data-boundary-id="src/App.tsx:12"` to an intrinsic fallback element
+    /// (`fallback={<div className="skeleton" />}`) so E2E tests can wait for it,
+    /// `data-otel-boundary-id` for tracing SDKs with `otel` and `elementtiming` so the
+    /// Element Timing API reports when the fallback is painted
+    ///
+    /// Fallbacks rendered by components are skipped as they might not forward the attribute.
+    pub(crate) fn add_fallback_attributes(&self, jsx_element: &mut JSXElement, id: &str) {
+        // React Native has no DOM attributes
+        if self.config.platform == Platform::ReactNative {
+            return;
+        }
+        let names: Vec<&str> = [
+            (self.config.emit_test_attributes, TEST_ATTRIBUTE_NAME),
+            (self.config.otel, OTEL_ATTRIBUTE_NAME),
+            (
+                self.config.emit_element_timing,
+                ELEMENT_TIMING_ATTRIBUTE_NAME,
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(enabled, name)| enabled.then_some(name))
+        .collect();
+        if names.is_empty() {
+            return;
+        }
+        let fallback = jsx_element
+            .opening
+            .attrs
+            .iter_mut()
+            .find_map(|attr| match attr {
+                JSXAttrOrSpread::JSXAttr(JSXAttr {
+                    name: JSXAttrName::Ident(name),
+                    value: Some(value),
+                    ..
+                }) if &*name.sym == "fallback" => match value {
+                    JSXAttrValue::JSXElement(element) => Some(&mut **element),
+                    JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                        expr: JSXExpr::Expr(expr),
+                        ..
+                    }) => match expr.unwrap_parens_mut() {
+                        Expr::JSXElement(element) => Some(&mut **element),
+                        _ => None,
+                    },
+                    _ => None,
+                },
+                _ => None,
+            });
+        let Some(fallback) = fallback else {
+            return;
+        };
+        let is_intrinsic = matches!(
+            &fallback.opening.name,
+            JSXElementName::Ident(name) if name.sym.starts_with(|c: char| c.is_ascii_lowercase())
+        );
+        if !is_intrinsic {
+            return;
+        }
+        for name in names {
+            if !has_attr(fallback, name) {
+                fallback
+                    .opening
+                    .attrs
+                    .push(jsx_str_attr(name, id, DUMMY_SP));
+            }
+        }
+    }
+
+    /// Adds `__boundarySource={{ fileName, lineNumber, columnNumber }}` in the shape of the
+    /// `__source` prop of React's dev runtime unless the element already has `__source`
+    pub(crate) fn add_boundary_source(&self, jsx_element: &mut JSXElement, line: usize) {
+        if !self.config.emit_boundary_source || has_attr(jsx_element, JSX_SOURCE_PROPERTY_NAME) {
+            return;
+        }
+        let column = self.lookup_column(jsx_element.span.lo);
+        jsx_element.opening.attrs.push(jsx_expr_attr(
+            BOUNDARY_SOURCE_PROPERTY_NAME,
+            object_expr(vec![
+                ("fileName", str_expr(&self.normalized_filename)),
+                ("lineNumber", num_expr(line)),
+                ("columnNumber", num_expr(column)),
+            ]),
+            DUMMY_SP,
+        ));
+    }
+
+    /// Adds `boundaryPropMap={{ FallbackComponent: "fallback", ... }}` for all mapped props
+    /// of the element so the tracker can interpret library specific props
+    pub(crate) fn add_boundary_prop_map(&self, jsx_element: &mut JSXElement, boundary: &Boundary) {
+        let prop_map = boundary.prop_map();
+        let mapped_props: Vec<(&str, Expr)> = jsx_element
+            .opening
+            .attrs
+            .iter()
+            .filter_map(|attr| match attr {
+                JSXAttrOrSpread::JSXAttr(JSXAttr {
+                    name: JSXAttrName::Ident(name),
+                    ..
+                }) => prop_map
+                    .iter()
+                    .find(|(prop, _)| *prop == &*name.sym)
+                    .map(|(prop, normalized)| (*prop, str_expr(normalized))),
+                _ => None,
+            })
+            .collect();
+        if mapped_props.is_empty() {
+            return;
+        }
+
+        jsx_element.opening.attrs.push(jsx_expr_attr(
+            BOUNDARY_PROP_MAP_PROPERTY_NAME,
+            object_expr(mapped_props),
+            DUMMY_SP,
+        ));
+    }
+
+    /// Adds `onError={__reportBoundaryError("file:line")}` to an error boundary
+    ///
+    /// A user provided `onError={handler}` is wrapped into
+    /// `onError={__reportBoundaryError("file:line", handler)}` so it is still called.
+    pub(crate) fn inject_error_reporter(
+        &mut self,
+        jsx_element: &mut JSXElement,
+        boundary_id: &str,
+    ) {
+        if !self.config.report_errors {
+            return;
+        }
+        if wrap_handler_prop(
+            jsx_element,
+            ON_ERROR_PROPERTY_NAME,
+            REPORT_BOUNDARY_ERROR_IMPORT_NAME,
+            vec![str_expr(boundary_id)],
+        ) {
+            let (error_entry_point, _) = self.tracker_import(BoundaryKind::Error);
+            self.add_runtime_import_from(&error_entry_point, REPORT_BOUNDARY_ERROR_IMPORT_NAME);
+        }
+    }
+
+    /// Adds `beforeCapture={__tagBoundaryCapture("boundaryId", "file:line")}` to tracked
+    /// error boundaries (e.g. the `ErrorBoundary` of `@sentry/react`) so captured errors
+    /// are tagged with the boundary ID
+    ///
+    /// A user provided `beforeCapture={handler}` is passed as last argument so it is still called.
+    pub(crate) fn inject_sentry_tag(&mut self, jsx_element: &mut JSXElement, boundary_id: &str) {
+        let Some(sentry) = self.config.sentry.clone() else {
+            return;
+        };
+        let (from, name) = match &sentry.wrapper {
+            Some(wrapper) => (wrapper.from.clone(), wrapper.name.as_str()),
+            None => (
+                self.tracker_import(BoundaryKind::Error).0,
+                TAG_BOUNDARY_CAPTURE_IMPORT_NAME,
+            ),
+        };
+        if wrap_handler_prop(
+            jsx_element,
+            BEFORE_CAPTURE_PROPERTY_NAME,
+            name,
+            vec![str_expr(sentry.tag()), str_expr(boundary_id)],
+        ) {
+            self.add_runtime_import_from(&from, name);
+        }
+    }
+
+    /// Adds `onSuspend={reportSuspend}` (and the import of `reportSuspend`) for every
+    /// configured callback
+    ///
+    /// A user provided `onSuspend={handler}` is kept and a spread might contain the handler,
+    /// so the prop is only added if the element has neither. An existing import of the
+    /// callback is reused and the import is renamed if the name is already bound.
+    pub(crate) fn inject_callbacks(&mut self, jsx_element: &mut JSXElement) {
+        let has_spread = jsx_element
+            .opening
+            .attrs
+            .iter()
+            .any(|attr| matches!(attr, JSXAttrOrSpread::SpreadElement(_)));
+        if has_spread {
+            return;
+        }
+        let callbacks = self.config.callbacks.clone();
+        for (prop, callback) in callbacks.props() {
+            if has_attr(jsx_element, prop) {
+                continue;
+            }
+            let binding = self.runtime_binding(&callback.from, &callback.name);
+            jsx_element
+                .opening
+                .attrs
+                .push(jsx_expr_attr(prop, Expr::Ident(binding), DUMMY_SP));
+        }
+    }
+}
+
+/// Returns the name of the element passed as `fallback={<Loading />}`
+pub(crate) fn fallback_name(jsx_element: &JSXElement) -> Option<String> {
+    fallback_element(jsx_element).map(|fallback| element_name(&fallback.opening.name))
+}
+
+/// Returns the element passed as `fallback={<Loading />}`
+fn fallback_element(jsx_element: &JSXElement) -> Option<&JSXElement> {
+    jsx_element
+        .opening
+        .attrs
+        .iter()
+        .find_map(|attr| match attr {
+            JSXAttrOrSpread::JSXAttr(JSXAttr {
+                name: JSXAttrName::Ident(name),
+                value: Some(value),
+                ..
+            }) if &*name.sym == "fallback" => match value {
+                JSXAttrValue::JSXElement(element) => Some(&**element),
+                JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                    expr: JSXExpr::Expr(expr),
+                    ..
+                }) => match expr.unwrap_parens() {
+                    Expr::JSXElement(element) => Some(&**element),
+                    _ => None,
+                },
+                _ => None,
+            },
+            _ => None,
+        })
+}
+
+/// Returns the name of an element as written (e.g. `div` or `Row.Skeleton`)
+fn element_name(name: &JSXElementName) -> String {
+    match name {
+        JSXElementName::Ident(ident) => ident.sym.to_string(),
+        JSXElementName::JSXMemberExpr(member) => {
+            format!("{}.{}", object_name(&member.obj), member.prop.sym)
+        }
+        JSXElementName::JSXNamespacedName(name) => format!("{}:{}", name.ns.sym, name.name.sym),
+        #[cfg(swc_ast_unknown)]
+        _ => String::new(),
+    }
+}
+
+fn object_name(obj: &JSXObject) -> String {
+    match obj {
+        JSXObject::Ident(ident) => ident.sym.to_string(),
+        JSXObject::JSXMemberExpr(member) => {
+            format!("{}.{}", object_name(&member.obj), member.prop.sym)
+        }
+        #[cfg(swc_ast_unknown)]
+        _ => String::new(),
+    }
+}
+
+/// Checks if the element has a (non-spread) attribute `name`
+fn has_attr(jsx_element: &JSXElement, name: &str) -> bool {
+    jsx_element.opening.attrs.iter().any(|attr| {
+        matches!(attr, JSXAttrOrSpread::JSXAttr(JSXAttr {
+            name: JSXAttrName::Ident(attr_name),
+            ..
+        }) if &*attr_name.sym == name)
+    })
+}
+
+/// Whether a boundary is `Suspense` from `react` which the runtime can render without
+/// a reference to the component
+fn is_react_suspense(boundary: &Boundary) -> bool {
+    boundary.from == "react" && boundary.component == "Suspense"
+}
+
+/// Wraps the handler of `prop` into `callee(...args, handler)` or adds `prop={callee(...args)}`
+///
+/// Returns `false` (and leaves the element untouched) if the handler can't be wrapped
+fn wrap_handler_prop(
+    jsx_element: &mut JSXElement,
+    prop: &str,
+    callee: &str,
+    mut args: Vec<Expr>,
+) -> bool {
+    let attrs = &mut jsx_element.opening.attrs;
+    let has_spread = attrs
+        .iter()
+        .any(|attr| matches!(attr, JSXAttrOrSpread::SpreadElement(_)));
+    let handler = attrs.iter_mut().find_map(|attr| match attr {
+        JSXAttrOrSpread::JSXAttr(JSXAttr {
+            name: JSXAttrName::Ident(name),
+            value,
+            ..
+        }) if &*name.sym == prop => Some(value),
+        _ => None,
+    });
+
+    match handler {
+        Some(Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+            expr: JSXExpr::Expr(handler),
+            ..
+        }))) => {
+            args.push(handler.as_mut().take());
+            **handler = call_expr(ident(callee), args);
+        }
+        // `prop="..."` or `prop={}` can't be wrapped
+        Some(_) => return false,
+        // A spread might contain a handler which must not be overridden
+        None if has_spread => return false,
+        None => attrs.push(jsx_expr_attr(
+            prop,
+            call_expr(ident(callee), args),
+            DUMMY_SP,
+        )),
+    }
+    true
+}
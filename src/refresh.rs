@@ -0,0 +1,23 @@
+//! Helpers for modules which are (or will be) transformed by React Fast Refresh
+
+use swc_core::ecma::ast::*;
+
+/// Function called by the React Refresh transform to register components
+const REFRESH_REGISTER_NAME: &str = "$RefreshReg$";
+
+/// Checks if a module item is a `$RefreshReg$(_c, "App");` statement
+pub fn is_refresh_registration(module_item: &ModuleItem) -> bool {
+    let ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. })) = module_item else {
+        return false;
+    };
+    let Expr::Call(CallExpr {
+        callee: Callee::Expr(callee),
+        ..
+    }) = &**expr
+    else {
+        return false;
+    };
+    callee
+        .as_ident()
+        .is_some_and(|callee| &*callee.sym == REFRESH_REGISTER_NAME)
+}
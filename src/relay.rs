@@ -0,0 +1,80 @@
+use swc_core::ecma::ast::*;
+
+use crate::{HookInjection, RelayHook, TransformVisitor};
+
+pub(crate) const RELAY_PACKAGE_NAME: &str = "react-relay";
+/// Where the call-site ID is stored in the options if `idPath` is not configured,
+/// `networkCacheConfig.metadata` is passed on to the network layer
+const DEFAULT_RELAY_ID_PATH: &str = "networkCacheConfig.metadata.suspenseTrackerId";
+
+/// Hooks used if `relayHooks` is not configured
+///
+/// `usePreloadedQuery` suspends on a query loaded elsewhere and `useFragment` only
+/// suspends on deferred fragments, their calls are wrapped without changing the options.
+pub(crate) fn default_relay_hooks() -> Vec<RelayHook> {
+    [
+        ("useLazyLoadQuery", Some(2), false),
+        ("usePreloadedQuery", None, false),
+        ("useFragment", None, true),
+    ]
+    .into_iter()
+    .map(|(name, options_arg, defer_only)| RelayHook {
+        name: name.to_string(),
+        options_arg,
+        id_path: None,
+        defer_only,
+    })
+    .collect()
+}
+
+impl TransformVisitor {
+    /// Rewrites `useLazyLoadQuery(query, variables)` into
+    /// `__trackSuspendingHook(() => useLazyLoadQuery(query, variables, { networkCacheConfig: { metadata: { suspenseTrackerId: "file:line" } } }), "file:line")`
+    pub(crate) fn track_relay_call(&mut self, call: &mut CallExpr) {
+        if !self.config.track_relay {
+            return;
+        }
+        let hooks = self
+            .config
+            .relay_hooks
+            .clone()
+            .unwrap_or_else(default_relay_hooks);
+        let Some(hook) = hooks
+            .into_iter()
+            .find(|hook| self.is_imported_call(&call.callee, RELAY_PACKAGE_NAME, &hook.name))
+        else {
+            return;
+        };
+        if hook.defer_only && !call.args.first().is_some_and(|arg| uses_defer(&arg.expr)) {
+            return;
+        }
+
+        let mut injections = Vec::new();
+        if let Some(arg) = hook.options_arg {
+            injections.push(HookInjection::OptionsField {
+                arg,
+                path: hook
+                    .id_path
+                    .unwrap_or_else(|| DEFAULT_RELAY_ID_PATH.to_string()),
+            });
+        }
+        injections.push(HookInjection::WrapCall);
+        self.inject_hook_call_id(call, &injections);
+    }
+}
+
+/// Checks if the argument is an inline ``graphql`...` `` document using `@defer`
+///
+/// Documents which were already compiled (e.g. by the Relay compiler plugin) or
+/// imported from another module are not inspected.
+fn uses_defer(document: &Expr) -> bool {
+    let Expr::TaggedTpl(tagged) = document else {
+        return false;
+    };
+    matches!(&*tagged.tag, Expr::Ident(tag) if &*tag.sym == "graphql")
+        && tagged
+            .tpl
+            .quasis
+            .iter()
+            .any(|quasi| quasi.raw.contains("@defer"))
+}
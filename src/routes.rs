@@ -0,0 +1,105 @@
+//! Route hints derived from the file routing conventions of common frameworks
+
+use std::borrow::Cow;
+
+use crate::{RouteConvention, RouteStyle};
+
+/// Conventions used if `routeConventions` is not configured
+///
+/// More specific directories come first as the first matching convention wins.
+pub(crate) fn default_route_conventions() -> Vec<RouteConvention> {
+    [
+        ("app/routes", RouteStyle::Flat),
+        ("src/routes", RouteStyle::Flat),
+        ("src/pages", RouteStyle::Pages),
+        ("pages", RouteStyle::Pages),
+        ("src/app", RouteStyle::App),
+        ("app", RouteStyle::App),
+    ]
+    .into_iter()
+    .map(|(dir, style)| RouteConvention {
+        dir: dir.to_string(),
+        style,
+    })
+    .collect()
+}
+
+/// Returns the route of a (normalized) filename for the first matching convention
+pub(crate) fn route_hint(filename: &str, conventions: &[RouteConvention]) -> Option<String> {
+    conventions.iter().find_map(|convention| {
+        let path = path_below(filename, convention.dir.trim_matches('/'))?;
+        let path = path
+            .rsplit_once('.')
+            .map_or(path, |(path, _extension)| path);
+        let segments = match convention.style {
+            RouteStyle::Pages => pages_segments(path)?,
+            RouteStyle::App => app_segments(path),
+            RouteStyle::Flat => flat_segments(path),
+        };
+        Some(format!("/{}", segments.join("/")))
+    })
+}
+
+/// Returns the part of `filename` below the directory `dir` (at any directory level)
+fn path_below<'a>(filename: &'a str, dir: &str) -> Option<&'a str> {
+    let prefix = format!("{dir}/");
+    if let Some(rest) = filename.strip_prefix(&prefix) {
+        return Some(rest);
+    }
+    filename
+        .match_indices(&format!("/{prefix}"))
+        .next()
+        .map(|(index, _)| &filename[index + prefix.len() + 1..])
+}
+
+/// `products/[id]` and `products/index` - files starting with `_` (like `_app`) are no routes
+fn pages_segments(path: &str) -> Option<Vec<Cow<'_, str>>> {
+    let mut segments: Vec<&str> = path.split('/').collect();
+    if segments.iter().any(|segment| segment.starts_with('_')) {
+        return None;
+    }
+    if segments.last() == Some(&"index") {
+        segments.pop();
+    }
+    Some(segments.into_iter().map(Cow::Borrowed).collect())
+}
+
+/// `products/[id]/page` - the file name, route groups, parallel routes and private folders
+/// don't add a segment
+fn app_segments(path: &str) -> Vec<Cow<'_, str>> {
+    let directories = path
+        .rsplit_once('/')
+        .map_or("", |(directories, _file)| directories);
+    directories
+        .split('/')
+        .filter(|segment| {
+            !segment.is_empty()
+                && !is_route_group(segment)
+                && !segment.starts_with('@')
+                && !segment.starts_with('_')
+        })
+        .map(Cow::Borrowed)
+        .collect()
+}
+
+/// `(shop)` - route groups only organize files
+fn is_route_group(segment: &str) -> bool {
+    segment.starts_with('(') && segment.ends_with(')')
+}
+
+/// `products.$id`, `products.$id/route` or `_auth.login` - `$param` becomes `:param`,
+/// a lone `$` (splat) becomes `*`, pathless `_layout` segments and `_index` are dropped
+fn flat_segments(path: &str) -> Vec<Cow<'_, str>> {
+    let path = path.strip_suffix("/route").unwrap_or(path);
+    path.split(['/', '.'])
+        .filter(|segment| !segment.is_empty() && !segment.starts_with('_') && *segment != "index")
+        .map(|segment| {
+            let segment = segment.strip_suffix('_').unwrap_or(segment);
+            match segment.strip_prefix('$') {
+                Some("") => Cow::Borrowed("*"),
+                Some(param) => Cow::Owned(format!(":{param}")),
+                None => Cow::Borrowed(segment),
+            }
+        })
+        .collect()
+}
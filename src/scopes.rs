@@ -0,0 +1,205 @@
+//! Shadowing detection for hosts which don't run the resolver before the plugin
+//!
+//! With the resolver every binding has its own syntax context, so a local `Suspense`
+//! can't be confused with the imported one. Without it all contexts are empty and
+//! local bindings which shadow a boundary import are collected per scope instead.
+
+use std::collections::HashSet;
+
+use swc_core::{
+    common::SyntaxContext,
+    ecma::{
+        ast::*,
+        visit::{Visit, VisitWith},
+    },
+};
+
+use crate::TransformVisitor;
+
+impl TransformVisitor {
+    /// Whether a boundary import has no syntax context (the resolver did not run)
+    fn tracks_scopes(&self) -> bool {
+        self.valid_boundary_idents
+            .keys()
+            .any(|(_, ctxt)| *ctxt == SyntaxContext::empty())
+    }
+
+    /// Checks if a local binding of the current scope shadows `ident`
+    pub(crate) fn is_shadowed(&self, ident: &Ident) -> bool {
+        self.shadowed_boundaries.contains(&ident.to_id())
+    }
+
+    /// Marks the bindings of a scope as shadowed if they are named like a boundary import
+    ///
+    /// The bindings are only collected if the resolver did not run.
+    /// Returns the previous scope depth for [`Self::exit_scope`].
+    pub(crate) fn enter_scope(&mut self, bindings: impl FnOnce() -> Vec<Id>) -> usize {
+        let depth = self.shadowed_boundaries.len();
+        if self.tracks_scopes() {
+            self.shadowed_boundaries.extend(
+                bindings()
+                    .into_iter()
+                    .filter(|id| self.valid_boundary_idents.contains_key(id)),
+            );
+        }
+        depth
+    }
+
+    /// Restores the shadowed bindings of the enclosing scope
+    pub(crate) fn exit_scope(&mut self, depth: usize) {
+        self.shadowed_boundaries.truncate(depth);
+    }
+}
+
+/// Returns the parameters and `var` declarations of a function
+pub(crate) fn function_bindings(function: &Function) -> Vec<Id> {
+    let mut bindings = Vec::new();
+    for param in &function.params {
+        pat_bindings(&param.pat, &mut bindings);
+    }
+    if let Some(body) = &function.body {
+        body.visit_with(&mut VarCollector {
+            bindings: &mut bindings,
+        });
+    }
+    bindings
+}
+
+/// Returns the parameters and (for block bodies) `var` declarations of an arrow function
+pub(crate) fn arrow_bindings(arrow: &ArrowExpr) -> Vec<Id> {
+    let mut bindings = Vec::new();
+    for param in &arrow.params {
+        pat_bindings(param, &mut bindings);
+    }
+    if let BlockStmtOrExpr::BlockStmt(body) = &*arrow.body {
+        body.visit_with(&mut VarCollector {
+            bindings: &mut bindings,
+        });
+    }
+    bindings
+}
+
+/// Returns the `let`, `const`, function and class declarations of a block
+pub(crate) fn block_bindings(block: &BlockStmt) -> Vec<Id> {
+    let mut bindings = Vec::new();
+    for stmt in &block.stmts {
+        match stmt {
+            Stmt::Decl(Decl::Var(var_decl)) if var_decl.kind != VarDeclKind::Var => {
+                for declarator in &var_decl.decls {
+                    pat_bindings(&declarator.name, &mut bindings);
+                }
+            }
+            Stmt::Decl(Decl::Fn(fn_decl)) => bindings.push(fn_decl.ident.to_id()),
+            Stmt::Decl(Decl::Class(class_decl)) => bindings.push(class_decl.ident.to_id()),
+            _ => {}
+        }
+    }
+    bindings
+}
+
+/// Returns the parameter of a `catch` clause
+pub(crate) fn catch_bindings(catch_clause: &CatchClause) -> Vec<Id> {
+    let mut bindings = Vec::new();
+    if let Some(param) = &catch_clause.param {
+        pat_bindings(param, &mut bindings);
+    }
+    bindings
+}
+
+/// Returns the names of all bindings of a module (in any scope)
+pub(crate) fn binding_names(module: &Module) -> HashSet<String> {
+    let mut collector = BindingNameCollector::default();
+    module.visit_with(&mut collector);
+    collector.0
+}
+
+/// Collects the identifiers bound by a pattern
+fn pat_bindings(pat: &Pat, bindings: &mut Vec<Id>) {
+    match pat {
+        Pat::Ident(binding) => bindings.push(binding.id.to_id()),
+        Pat::Array(array) => {
+            for elem in array.elems.iter().flatten() {
+                pat_bindings(elem, bindings);
+            }
+        }
+        Pat::Object(object) => {
+            for prop in &object.props {
+                match prop {
+                    ObjectPatProp::KeyValue(key_value) => pat_bindings(&key_value.value, bindings),
+                    ObjectPatProp::Assign(assign) => bindings.push(assign.key.id.to_id()),
+                    ObjectPatProp::Rest(rest) => pat_bindings(&rest.arg, bindings),
+                    #[cfg(swc_ast_unknown)]
+                    _ => {}
+                }
+            }
+        }
+        Pat::Rest(rest) => pat_bindings(&rest.arg, bindings),
+        Pat::Assign(assign) => pat_bindings(&assign.left, bindings),
+        _ => {}
+    }
+}
+
+/// Collects the names of bindings, imports and function and class names
+#[derive(Default)]
+struct BindingNameCollector(HashSet<String>);
+
+impl Visit for BindingNameCollector {
+    fn visit_binding_ident(&mut self, binding: &BindingIdent) {
+        self.0.insert(binding.id.sym.to_string());
+    }
+
+    fn visit_fn_decl(&mut self, fn_decl: &FnDecl) {
+        self.0.insert(fn_decl.ident.sym.to_string());
+        fn_decl.visit_children_with(self);
+    }
+
+    fn visit_fn_expr(&mut self, fn_expr: &FnExpr) {
+        if let Some(ident) = &fn_expr.ident {
+            self.0.insert(ident.sym.to_string());
+        }
+        fn_expr.visit_children_with(self);
+    }
+
+    fn visit_class_decl(&mut self, class_decl: &ClassDecl) {
+        self.0.insert(class_decl.ident.sym.to_string());
+        class_decl.visit_children_with(self);
+    }
+
+    fn visit_class_expr(&mut self, class_expr: &ClassExpr) {
+        if let Some(ident) = &class_expr.ident {
+            self.0.insert(ident.sym.to_string());
+        }
+        class_expr.visit_children_with(self);
+    }
+
+    fn visit_import_specifier(&mut self, specifier: &ImportSpecifier) {
+        let local = match specifier {
+            ImportSpecifier::Named(named) => &named.local,
+            ImportSpecifier::Default(default) => &default.local,
+            ImportSpecifier::Namespace(namespace) => &namespace.local,
+            #[cfg(swc_ast_unknown)]
+            _ => return,
+        };
+        self.0.insert(local.sym.to_string());
+    }
+}
+
+/// Collects `var` declarations without entering nested functions
+struct VarCollector<'a> {
+    bindings: &'a mut Vec<Id>,
+}
+
+impl Visit for VarCollector<'_> {
+    fn visit_var_decl(&mut self, var_decl: &VarDecl) {
+        if var_decl.kind == VarDeclKind::Var {
+            for declarator in &var_decl.decls {
+                pat_bindings(&declarator.name, self.bindings);
+            }
+        }
+        var_decl.visit_children_with(self);
+    }
+
+    fn visit_function(&mut self, _: &Function) {}
+
+    fn visit_arrow_expr(&mut self, _: &ArrowExpr) {}
+}
@@ -0,0 +1,56 @@
+/**
+ * Server safe runtime of the SWC plugin - must not import client-only React APIs
+ * as it is imported by Server Components.
+ */
+
+/** Timing of an `await` inside an async component, recorded by the SWC plugin */
+export type AwaitTiming = {
+  /** `file:line` of the `await` */
+  callSiteId: string;
+  /** How often the `await` ran */
+  count: number;
+  /** How many awaited values are still pending */
+  pending: number;
+  /** Duration of the last settled await in milliseconds */
+  lastDuration: number | null;
+  /** Sum of all settled await durations in milliseconds */
+  totalDuration: number;
+};
+
+const awaitRegistry = new Map<string, AwaitTiming>();
+
+/**
+ * Internal function wrapping the awaited values of async components if the SWC plugin
+ * runs with `trackAsyncComponents: true`. Returns the value untouched.
+ */
+export const __trackAwait = <T>(value: T, callSiteId: string): T => {
+  const timing = awaitRegistry.get(callSiteId) ?? {
+    callSiteId,
+    count: 0,
+    pending: 0,
+    lastDuration: null,
+    totalDuration: 0,
+  };
+  timing.count++;
+  awaitRegistry.set(callSiteId, timing);
+  if (value && typeof value === "object" && "then" in value) {
+    const startedAt = performance.now();
+    timing.pending++;
+    const settle = () => {
+      const duration = performance.now() - startedAt;
+      timing.pending--;
+      timing.lastDuration = duration;
+      timing.totalDuration += duration;
+    };
+    Promise.resolve(value).then(settle, settle);
+  }
+  return value;
+};
+
+/**
+ * Returns the timings of all awaits of async components (e.g. Server Components)
+ *
+ * Requires the `trackAsyncComponents` plugin option - returns an empty array otherwise.
+ */
+export const getAwaitTimings = (): AwaitTiming[] =>
+  Array.from(awaitRegistry.values(), (timing) => ({ ...timing }));
@@ -1,5 +1,11 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, HashSet},
+    rc::Rc,
+};
+
+use crate::glob::path_matches;
 
 /// Configuration for a boundary component
 #[derive(Debug, Serialize, Deserialize, Clone, Hash, PartialEq, Eq)]
@@ -9,10 +15,405 @@ pub struct Boundary {
     pub component: String,
     /// The package to import the component from
     pub from: String,
+    /// The kind of boundary (Suspense if not set)
+    #[serde(default)]
+    pub kind: Option<BoundaryKind>,
+    /// Maps library specific props (e.g. `FallbackComponent`) to the normalized props
+    /// of the tracker (`fallback`, `onError`, `onReset`, `resetKeys`)
+    #[serde(default)]
+    pub prop_map: Option<BTreeMap<String, String>>,
+    /// How IDs of this boundary are generated (overrides `idStrategy`)
+    #[serde(default)]
+    pub id_strategy: Option<IdStrategy>,
+    /// Prepended to IDs of this boundary (overrides `idPrefix`)
+    #[serde(default)]
+    pub id_prefix: Option<String>,
+}
+
+impl Boundary {
+    /// Returns the configured kind, boundaries are Suspense boundaries by default
+    pub fn kind(&self) -> BoundaryKind {
+        self.kind.unwrap_or(BoundaryKind::Suspense)
+    }
+
+    /// Returns the configured prop mapping or the default one of known boundary libraries
+    pub fn prop_map(&self) -> Vec<(&str, &str)> {
+        match &self.prop_map {
+            Some(prop_map) => prop_map
+                .iter()
+                .map(|(prop, normalized)| (prop.as_str(), normalized.as_str()))
+                .collect(),
+            None if self.from == "react-error-boundary" => REACT_ERROR_BOUNDARY_PROP_MAP.to_vec(),
+            None => Vec::new(),
+        }
+    }
+}
+
+/// Normalized props of the `react-error-boundary` `ErrorBoundary`
+const REACT_ERROR_BOUNDARY_PROP_MAP: [(&str, &str); 6] = [
+    ("fallback", "fallback"),
+    ("FallbackComponent", "fallback"),
+    ("fallbackRender", "fallback"),
+    ("onError", "onError"),
+    ("onReset", "onReset"),
+    ("resetKeys", "resetKeys"),
+];
+
+/// The kind of a boundary
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum BoundaryKind {
+    /// Boundary which catches suspensions (e.g. `Suspense`)
+    Suspense,
+    /// Boundary which catches errors (e.g. `ErrorBoundary`)
+    Error,
+}
+
+impl BoundaryKind {
+    /// The name used for the kind in emitted code
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Suspense => "suspense",
+            Self::Error => "error",
+        }
+    }
+}
+
+/// A named export of a module (e.g. `{ name: "reportSuspend", from: "@acme/telemetry" }`)
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ModuleExport {
+    /// The exported name
+    pub name: String,
+    /// The module to import the export from
+    pub from: String,
+}
+
+/// A field of a boundary passed to the `injectCall` function or as OpenTelemetry attribute
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum CallArgument {
+    /// The boundary ID (e.g. `"src/App.tsx:12"`)
+    Id,
+    /// `"suspense"` or `"error"`
+    Kind,
+    /// The imported name of the boundary component
+    Component,
+    /// The package the boundary component was imported from
+    From,
+    /// The line of the boundary element
+    Line,
+    /// The normalized filename
+    File,
+    /// The name of the component rendering the boundary (`null` if unknown)
+    EnclosingComponent,
+}
+
+/// A function which is called once per boundary when the module is evaluated
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+pub struct InjectCall {
+    /// The exported name of the function
+    pub name: String,
+    /// The module to import the function from
+    pub from: String,
+    /// The boundary fields passed to the function (defaults to `["id", "kind"]`)
+    #[serde(default = "default_call_arguments")]
+    pub args: Vec<CallArgument>,
+}
+
+fn default_call_arguments() -> Vec<CallArgument> {
+    vec![CallArgument::Id, CallArgument::Kind]
+}
+
+/// Callbacks which are called by the tracker of Suspense boundaries
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+pub struct Callbacks {
+    /// Called with the boundary id once the boundary shows its fallback
+    #[serde(default)]
+    pub on_suspend: Option<ModuleExport>,
+    /// Called with the boundary id once the fallback of the boundary is replaced
+    #[serde(default)]
+    pub on_resolve: Option<ModuleExport>,
+}
+
+impl Callbacks {
+    /// Returns the configured callbacks with the name of the tracker prop they are passed as
+    pub fn props(&self) -> Vec<(&'static str, &ModuleExport)> {
+        [
+            ("onSuspend", &self.on_suspend),
+            ("onResolve", &self.on_resolve),
+        ]
+        .into_iter()
+        .filter_map(|(prop, callback)| Some((prop, callback.as_ref()?)))
+        .collect()
+    }
+}
+
+/// Tags errors captured by error boundaries (e.g. `@sentry/react`) with the boundary ID
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+pub struct SentryTagging {
+    /// Function called as `wrapper(tag, boundaryId, beforeCapture)` which returns the
+    /// `beforeCapture` handler (defaults to `__tagBoundaryCapture` of the error runtime)
+    #[serde(default)]
+    pub wrapper: Option<ModuleExport>,
+    /// Name of the tag (defaults to `boundaryId`)
+    #[serde(default)]
+    pub tag: Option<String>,
+}
+
+impl SentryTagging {
+    /// Returns the configured tag name or `boundaryId`
+    pub fn tag(&self) -> &str {
+        self.tag.as_deref().unwrap_or(DEFAULT_SENTRY_TAG)
+    }
+}
+
+/// Default tag of errors captured by error boundaries
+const DEFAULT_SENTRY_TAG: &str = "boundaryId";
+
+/// How boundary IDs are generated
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Hash, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum IdStrategy {
+    /// File and line of the boundary (`src/App.tsx:12`)
+    Line,
+    /// File, enclosing component and boundary component (`src/App.tsx:App/Suspense`)
+    Component,
+    /// Hash of file and line (`3f2a9c1b`) which does not reveal the file path
+    Hash,
+    /// Position of the boundary in its file (`1`, `2`, ...), mapped to `file:line`
+    /// in the manifest comment
+    Numeric,
+}
+
+/// How the boundary ID and component are passed to the tracker
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum EmitStyle {
+    /// Separate `boundaryId` and `boundary` props
+    #[default]
+    Props,
+    /// A single `__boundaryMeta={{ id, kind, owner, fallbackName, boundary }}` prop
+    Object,
+    /// Only the `boundaryId` prop without name metadata (`boundary` is kept for
+    /// boundaries other than `Suspense` from `react` as the tracker has to render them)
+    Id,
+}
+
+/// How the original boundary component is passed to the tracker
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum BoundaryRef {
+    /// The identifier of the boundary component (`boundary={Suspense}`)
+    #[default]
+    Ident,
+    /// The package and name of boundaries the runtime can render by name
+    /// (`boundary="react:Suspense"`), other boundaries keep the identifier
+    String,
+}
+
+/// Where the tracker import is inserted into modules with imports
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum ImportPlacement {
+    /// Before the first import
+    #[default]
+    First,
+    /// After the last import of `react` or `react-dom` (before the first import without one)
+    AfterReact,
+    /// After the last import
+    Last,
+}
+
+/// The platform the module is bundled for
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum Platform {
+    /// Browsers (and server rendering for browsers)
+    #[default]
+    Web,
+    /// React Native which has no DOM: DOM attributes are skipped and the tracker is
+    /// imported from `react-swc-suspense-tracker/context-native`
+    ReactNative,
+}
+
+/// Runtime modules the tracker components are imported from
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+pub struct EntryPoints {
+    /// Module exporting `BoundaryTrackerSWC` for Suspense boundaries
+    /// (defaults to `react-swc-suspense-tracker/context`)
+    #[serde(default)]
+    pub suspense: Option<String>,
+    /// Module exporting `ErrorBoundaryTrackerSWC` and `__reportBoundaryError` for error
+    /// boundaries (defaults to `react-swc-suspense-tracker/error-context`)
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+/// Configured severity of a diagnostics rule
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleSeverity {
+    /// The rule is not checked
+    Off,
+    /// Problems are reported as warnings
+    Warn,
+    /// Problems fail the build
+    Error,
+}
+
+/// A boundary which must not be used (in some files)
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+pub struct ForbiddenBoundary {
+    /// Glob of the component name (e.g. `Suspense` or `*Boundary`)
+    pub component: String,
+    /// Glob of the package the component is imported from (any package if not set)
+    #[serde(default)]
+    pub from: Option<String>,
+    /// Globs of the files the boundary is forbidden in (e.g. `src/routes/**`, all files if not set)
+    #[serde(default)]
+    pub paths: Option<Vec<String>>,
+    /// Explanation appended to the error (e.g. `use RouteBoundary instead`)
+    #[serde(default)]
+    pub message: Option<String>,
+}
+
+/// How the files below a route directory map to routes
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum RouteStyle {
+    /// Next.js `pages` directory (`pages/products/[id].tsx` is `/products/[id]`)
+    Pages,
+    /// Next.js `app` directory (`app/products/[id]/page.tsx` is `/products/[id]`)
+    App,
+    /// React Router / Remix flat routes (`routes/products.$id.tsx` is `/products/:id`)
+    Flat,
+}
+
+/// A directory whose files are routes
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+pub struct RouteConvention {
+    /// The route directory (e.g. `src/pages`), matched at any directory level
+    pub dir: String,
+    /// How the files below `dir` map to routes
+    pub style: RouteStyle,
+}
+
+/// A `react-relay` hook which suspends, instrumented with `trackRelay`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+pub struct RelayHook {
+    /// The exported name of the hook (e.g. `useLazyLoadQuery`)
+    pub name: String,
+    /// The position of the options argument which gets the call-site ID
+    /// (calls are only wrapped if not set)
+    #[serde(default)]
+    pub options_arg: Option<usize>,
+    /// The dot separated path of the call-site ID in the options (defaults to
+    /// `networkCacheConfig.metadata.suspenseTrackerId`)
+    #[serde(default)]
+    pub id_path: Option<String>,
+    /// Whether only calls whose inline `graphql` document uses `@defer` suspend
+    #[serde(default)]
+    pub defer_only: bool,
+}
+
+/// How the call-site ID is passed to a suspending hook
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum HookInjection {
+    /// Sets the field at the dot separated `path` (e.g. `meta.suspenseTrackerId`) of the
+    /// options at argument position `arg`, omitted options are added
+    OptionsField {
+        /// The position of the options argument
+        arg: usize,
+        /// The dot separated path of the field
+        path: String,
+    },
+    /// Wraps the call with `__trackSuspendingHook` which attributes the thrown promise
+    WrapCall,
+}
+
+/// A hook which suspends (e.g. the hook of a data fetching library)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+pub struct SuspendingHook {
+    /// The exported name of the hook (`default` for a default export)
+    pub name: String,
+    /// The module to import the hook from
+    pub from: String,
+    /// How the call-site ID is passed to the hook
+    pub inject: HookInjection,
+}
+
+/// How an `@apollo/client` hook call is instrumented
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum ApolloInstrumentation {
+    /// Adds `context: { suspenseTrackerId }` to the options (second argument)
+    #[default]
+    Context,
+    /// Wraps the call with `__trackSuspendingHook`
+    Wrap,
+}
+
+/// An `@apollo/client` hook instrumented with `trackApollo`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+pub struct ApolloHook {
+    /// The exported name of the hook (e.g. `useSuspenseQuery`)
+    pub name: String,
+    /// How calls of the hook are instrumented
+    #[serde(default)]
+    pub instrument: ApolloInstrumentation,
+}
+
+/// The maximum number of boundaries of the files matching `paths`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+pub struct BoundaryBudget {
+    /// Globs of the files the budget applies to (e.g. `src/components/**`)
+    pub paths: Vec<String>,
+    /// The maximum number of boundaries per file
+    pub max: usize,
+}
+
+/// Configuration which replaces the plugin configuration for the files matching `files`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+pub struct ConfigOverride {
+    /// Glob of the files the override applies to (e.g. `src/admin/**`)
+    pub files: String,
+    /// Replaces `enabled`
+    #[serde(default)]
+    pub enabled: Option<bool>,
+    /// Replaces `boundaries`
+    #[serde(default)]
+    pub boundaries: Option<HashSet<Boundary>>,
+    /// Replaces `mode`
+    #[serde(default)]
+    pub mode: Option<Mode>,
 }
 
 /// Static plugin configuration.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[serde(deny_unknown_fields)]
 pub struct Config {
@@ -22,6 +423,428 @@ pub struct Config {
     /// Boundary configurations (e.g., [{ component: 'ErrorBoundary', from: 'my-package' }])
     #[serde(default)]
     pub boundaries: HashSet<Boundary>,
+    /// The number of nesting levels of boundaries which are tracked
+    /// (`1` only tracks the outermost boundaries of a file)
+    #[serde(default)]
+    pub max_depth: Option<usize>,
+    /// Whether only the outermost boundaries of a file are tracked (same as `max_depth: 1`)
+    #[serde(default)]
+    pub only_outermost: bool,
+    /// Whether `Suspense` from `react` is tracked without being configured in `boundaries`
+    /// (defaults to `true`)
+    #[serde(default)]
+    pub include_default_suspense: Option<bool>,
+    /// How boundaries are processed
+    #[serde(default)]
+    pub mode: Mode,
+    /// Configuration for specific files (all matching overrides apply in order)
+    #[serde(default)]
+    pub overrides: Vec<ConfigOverride>,
+    /// Whether to report problems like boundaries without a fallback as swc warnings
+    #[serde(default)]
+    pub diagnostics: bool,
+    /// Severity of individual diagnostics rules (e.g. `"missing-fallback": "error"`)
+    #[serde(default)]
+    pub rules: BTreeMap<String, RuleSeverity>,
+    /// Boundaries whose usage fails the build
+    #[serde(default)]
+    pub forbid: Vec<ForbiddenBoundary>,
+    /// The maximum number of boundaries per file
+    #[serde(default)]
+    pub max_boundaries_per_file: Option<usize>,
+    /// Boundary budgets for specific files (the first matching budget wins
+    /// over `max_boundaries_per_file`)
+    #[serde(default)]
+    pub boundary_budgets: Vec<BoundaryBudget>,
+    /// The platform the module is bundled for
+    #[serde(default)]
+    pub platform: Platform,
+    /// Runtime modules of the tracker components per boundary kind
+    #[serde(default)]
+    pub entry_points: EntryPoints,
+    /// How the boundary ID and component are passed to the tracker
+    #[serde(default)]
+    pub emit_style: EmitStyle,
+    /// Whether the `boundary` prop is the identifier or the name of the boundary component
+    #[serde(default)]
+    pub boundary_ref: BoundaryRef,
+    /// Where the tracker import is inserted
+    #[serde(default)]
+    pub import_placement: ImportPlacement,
+    /// How boundary IDs are generated (defaults to `line`, or `component` in test mode)
+    #[serde(default)]
+    pub id_strategy: Option<IdStrategy>,
+    /// Template of `line` strategy IDs with `{file}`, `{line}`, `{column}` and `{prop:name}`
+    /// placeholders (defaults to `{file}:{line}`)
+    #[serde(default)]
+    pub id_format: Option<String>,
+    /// Name of the prop which sets the ID of a boundary explicitly (defaults to `trackId`)
+    #[serde(default)]
+    pub track_id_prop: Option<String>,
+    /// Whether to declare every boundary ID as module level constant
+    /// (`const __bid1 = "src/App.tsx:12";`) which the tracker props reference
+    #[serde(default)]
+    pub hoist_ids: bool,
+    /// Whether boundaries rendered in `.map()` / `.forEach()` callbacks get a template literal
+    /// ID combining the static ID with their `key` (`` `src/List.tsx:20:${item.id}` ``)
+    #[serde(default)]
+    pub keyed_loop_ids: bool,
+    /// Prepended to all boundary IDs (e.g. `checkout:`)
+    #[serde(default)]
+    pub id_prefix: Option<String>,
+    /// Whether to register all boundaries of a module via `registerBoundaries([...])`
+    #[serde(default)]
+    pub emit_registry: bool,
+    /// Function called at module level for every boundary (e.g. `__registerBoundary("id", "kind")`)
+    #[serde(default)]
+    pub inject_call: Option<InjectCall>,
+    /// Whether to append `export const __SUSPENSE_BOUNDARIES__ = [...]` to modules with boundaries
+    #[serde(default)]
+    pub export_boundaries: bool,
+    /// Whether to append a `/* @suspense-boundaries [...] */` comment to modules with boundaries
+    /// (always enabled in analyze mode)
+    #[serde(default)]
+    pub emit_manifest_comment: bool,
+    /// Marker of the manifest comment (defaults to `@suspense-boundaries`)
+    #[serde(default)]
+    pub manifest_marker: Option<String>,
+    /// Whether to append a `/* suspense-tracker: 3 boundaries, 1 import injected */` comment
+    /// to transformed modules
+    #[serde(default)]
+    pub emit_stats: bool,
+    /// Whether to report the boundaries of every module as note-level swc diagnostic
+    /// (`[suspense-boundaries] {"file":...,"boundaries":[...]}`) for build tool plugins
+    #[serde(default)]
+    pub emit_boundary_note: bool,
+    /// Whether to add a `__boundarySource={{ fileName, lineNumber, columnNumber }}` prop
+    /// to boundaries which don't already receive `__source` from the JSX dev transform
+    #[serde(default)]
+    pub emit_boundary_source: bool,
+    /// Whether to add a `debugName` prop with the name of the variable or component
+    /// the boundary belongs to
+    #[serde(default)]
+    pub emit_debug_name: bool,
+    /// Whether to add an `ownerStack` prop with the names of the enclosing components
+    #[serde(default)]
+    pub emit_owner_stack: bool,
+    /// Whether to add a `routeHint="/products/[id]"` prop derived from the file path
+    #[serde(default)]
+    pub emit_route_hint: bool,
+    /// Whether to add an `originalName="Suspense"` prop with the imported name of the boundary
+    #[serde(default)]
+    pub emit_original_name: bool,
+    /// Whether to add a `depth={0}` prop with the number of boundaries enclosing the
+    /// boundary in its file
+    #[serde(default)]
+    pub emit_depth: bool,
+    /// Whether to add `data-boundary-id` to intrinsic fallback elements for E2E tests
+    #[serde(default)]
+    pub emit_test_attributes: bool,
+    /// Whether to add `elementtiming="file:line"` to intrinsic fallback elements so the
+    /// Element Timing API reports when they are painted
+    #[serde(default)]
+    pub emit_element_timing: bool,
+    /// Whether to add an `otelAttributes={{ "code.filepath": ..., "code.lineno": ... }}` prop
+    /// and `data-otel-boundary-id` to intrinsic fallback elements for tracing SDKs
+    #[serde(default)]
+    pub otel: bool,
+    /// Name of the OpenTelemetry attributes prop (defaults to `otelAttributes`)
+    #[serde(default)]
+    pub otel_prop_name: Option<String>,
+    /// OpenTelemetry attribute names and the boundary field they are set to
+    /// (defaults to `code.filepath` and `code.lineno`)
+    #[serde(default)]
+    pub otel_attributes: Option<BTreeMap<String, CallArgument>>,
+    /// Route directories for `emit_route_hint` (defaults to the Next.js `pages` and `app`
+    /// directories and React Router / Remix `routes` directories)
+    #[serde(default)]
+    pub route_conventions: Option<Vec<RouteConvention>>,
+    /// Whether to wrap `lazy(() => import("./x"))` calls with `trackLazy`
+    #[serde(default)]
+    pub track_lazy: bool,
+    /// Whether to wrap `next/dynamic` calls with `trackLazy`
+    #[serde(default)]
+    pub track_dynamic: bool,
+    /// Whether to wrap `@loadable/component` factories with `trackLazy`
+    #[serde(default)]
+    pub track_loadable: bool,
+    /// Whether to wrap the `import()` of lazy component factories with `__trackChunk`
+    #[serde(default)]
+    pub track_chunks: bool,
+    /// Whether to add `/* webpackChunkName: "b__src_App_tsx_30" */` to the `import()` of
+    /// tracked lazy component factories
+    #[serde(default)]
+    pub emit_chunk_names: bool,
+    /// Whether to wrap the argument of `use(promise)` with `__trackPromise`
+    #[serde(default)]
+    pub track_use: bool,
+    /// Whether to add `meta: { suspenseTrackerId }` to TanStack Query suspense hook calls
+    #[serde(default)]
+    pub track_tanstack_query: bool,
+    /// The `@tanstack/react-query` hooks to instrument (defaults to all suspense hooks)
+    #[serde(default)]
+    pub tanstack_query_hooks: Option<Vec<String>>,
+    /// Hooks whose calls get the call-site ID (in addition to the built-in library support)
+    #[serde(default)]
+    pub suspending_hooks: Vec<SuspendingHook>,
+    /// Whether to wrap SWR hook calls with `suspense: true` with `__trackSuspendingHook`
+    #[serde(default)]
+    pub track_swr: bool,
+    /// The SWR hooks (or wrappers of them) to instrument (defaults to `useSWR` and
+    /// `useSWRImmutable`)
+    #[serde(default)]
+    pub swr_hooks: Option<Vec<String>>,
+    /// Whether to wrap `react-relay` suspense hook calls with `__trackSuspendingHook`
+    #[serde(default)]
+    pub track_relay: bool,
+    /// The `react-relay` hooks to instrument (defaults to `useLazyLoadQuery`,
+    /// `usePreloadedQuery` and `useFragment` with `@defer`)
+    #[serde(default)]
+    pub relay_hooks: Option<Vec<RelayHook>>,
+    /// Whether to instrument `@apollo/client` suspense hook calls
+    #[serde(default)]
+    pub track_apollo: bool,
+    /// The `@apollo/client` hooks to instrument (defaults to `useSuspenseQuery` and
+    /// `useBackgroundQuery` with context and a wrapped `useReadQuery`)
+    #[serde(default)]
+    pub apollo_hooks: Option<Vec<ApolloHook>>,
+    /// Whether to inject `useSuspenseTrackerSWC("Name", "file:line")` into components
+    /// which call a suspending API
+    #[serde(default)]
+    pub track_suspenders: bool,
+    /// Whether to wrap the callback of `startTransition(cb)` with `__trackTransition`
+    #[serde(default)]
+    pub track_transitions: bool,
+    /// Whether to wrap `useDeferredValue(value)` calls with `__trackDeferredValue`
+    #[serde(default)]
+    pub track_deferred_value: bool,
+    /// Whether to wrap the awaits of async components (e.g. Server Components)
+    /// with `__trackAwait`
+    #[serde(default)]
+    pub track_async_components: bool,
+    /// Whether to wrap promises thrown by components and hooks with `__trackPromise`
+    #[serde(default)]
+    pub track_thrown_promises: bool,
+    /// The imported names of the hooks to instrument (defaults to `useDeferredValue`)
+    #[serde(default)]
+    pub deferred_value_hooks: Option<Vec<String>>,
+    /// The module `__trackDeferredValue` is imported from (defaults to the runtime package)
+    #[serde(default)]
+    pub deferred_value_tracker: Option<String>,
+    /// Whether to wrap the children of tracked boundaries in a React `<Profiler>`
+    #[serde(default)]
+    pub inject_profiler: bool,
+    /// Whether to wrap the `fallback` of tracked Suspense boundaries in `<FallbackTrackerSWC>`
+    #[serde(default)]
+    pub track_fallback: bool,
+    /// Whether to wrap the children of tracked Suspense boundaries in `<ContentTrackerSWC>`
+    #[serde(default)]
+    pub track_content_mount: bool,
+    /// Whether to add `onError={__reportBoundaryError(boundaryId)}` to tracked error boundaries
+    #[serde(default)]
+    pub report_errors: bool,
+    /// Whether to add `beforeCapture={__tagBoundaryCapture("boundaryId", id)}` to tracked
+    /// error boundaries so error reports (e.g. of Sentry) are tagged with the boundary ID
+    #[serde(default)]
+    pub sentry: Option<SentryTagging>,
+    /// Callbacks of a user module which are passed to tracked Suspense boundaries
+    #[serde(default)]
+    pub callbacks: Callbacks,
+    /// Whether to replace hand-written `<BoundaryTrackerSWC>` elements with their original
+    /// boundary instead of transforming the module (runs in every environment)
+    #[serde(default)]
+    pub strip: bool,
+    /// Whether to replace runtime hooks with inert values and remove unused tracker imports
+    /// when the plugin is disabled
+    #[serde(default)]
+    pub clean_disabled_imports: bool,
+    /// Whether the module is also transformed by React Fast Refresh (suspending hook calls
+    /// are not wrapped with `__trackSuspendingHook`)
+    #[serde(default)]
+    pub fast_refresh: bool,
+    /// Whether to transform modules under `NODE_ENV=test` with IDs based on the enclosing
+    /// component instead of the line (e.g. `src/App.tsx:App/Suspense`) so test snapshots
+    /// don't change with unrelated edits
+    #[serde(default)]
+    pub test_mode: bool,
+    /// Whether test mode imports `BoundaryTrackerSWC` from
+    /// `react-swc-suspense-tracker/context-test` which records suspended boundaries for assertions
+    #[serde(default)]
+    pub test_entry: bool,
+    /// The environment used if the host does not provide one
+    #[serde(default)]
+    pub default_env: Option<Environment>,
+    /// The filename used if the host does not provide one
+    #[serde(default)]
+    pub fallback_filename: Option<String>,
+}
+
+thread_local! {
+    /// The last parsed plugin config and its JSON string
+    ///
+    /// The plugin host reuses the wasm instance across modules and passes
+    /// the same config string for every module.
+    static PARSED_CONFIG: RefCell<Option<(String, Rc<Config>)>> = const { RefCell::new(None) };
+}
+
+impl Config {
+    /// Parses the JSON plugin config and shares the result if the same
+    /// JSON was parsed before on this thread
+    pub fn from_json_cached(json: &str) -> Result<Rc<Config>, serde_json::Error> {
+        PARSED_CONFIG.with_borrow_mut(|parsed| {
+            if let Some((cached_json, config)) = parsed.as_ref() {
+                if cached_json == json {
+                    return Ok(Rc::clone(config));
+                }
+            }
+            let config = Rc::new(serde_json::from_str::<Config>(json)?);
+            *parsed = Some((json.to_string(), Rc::clone(&config)));
+            Ok(config)
+        })
+    }
+
+    /// Returns the number of tracked nesting levels of `max_depth` and `only_outermost`
+    pub fn max_depth(&self) -> Option<usize> {
+        self.max_depth
+            .into_iter()
+            .chain(self.only_outermost.then_some(1))
+            .min()
+    }
+
+    /// Applies all `overrides` matching a (normalized) filename in order
+    pub fn apply_overrides(&mut self, filename: &str) {
+        for config_override in &self.overrides {
+            if !path_matches(&config_override.files, filename) {
+                continue;
+            }
+            if let Some(enabled) = config_override.enabled {
+                self.enabled = Some(enabled);
+            }
+            if let Some(boundaries) = &config_override.boundaries {
+                self.boundaries = boundaries.clone();
+            }
+            if let Some(mode) = config_override.mode {
+                self.mode = mode;
+            }
+        }
+    }
+
+    /// Returns the configured manifest marker or the default one
+    pub fn manifest_marker(&self) -> &str {
+        self.manifest_marker
+            .as_deref()
+            .unwrap_or(DEFAULT_MANIFEST_MARKER)
+    }
+
+    /// Returns the configured explicit boundary ID prop name or `trackId`
+    pub fn track_id_prop(&self) -> &str {
+        self.track_id_prop
+            .as_deref()
+            .unwrap_or(DEFAULT_TRACK_ID_PROP)
+    }
+
+    /// Returns the configured OpenTelemetry attributes prop name or `otelAttributes`
+    pub fn otel_prop_name(&self) -> &str {
+        self.otel_prop_name
+            .as_deref()
+            .unwrap_or(DEFAULT_OTEL_PROP_NAME)
+    }
+
+    /// Returns the configured OpenTelemetry attributes or `code.filepath` and `code.lineno`
+    pub fn otel_attributes(&self) -> Vec<(&str, CallArgument)> {
+        self.otel_attributes.as_ref().map_or_else(
+            || DEFAULT_OTEL_ATTRIBUTES.to_vec(),
+            |attributes| {
+                attributes
+                    .iter()
+                    .map(|(name, field)| (name.as_str(), *field))
+                    .collect()
+            },
+        )
+    }
+
+    /// Returns the configured TanStack Query hooks or the default suspense hooks
+    pub fn tanstack_query_hooks(&self) -> Vec<&str> {
+        self.tanstack_query_hooks.as_ref().map_or_else(
+            || DEFAULT_TANSTACK_QUERY_HOOKS.to_vec(),
+            |hooks| hooks.iter().map(String::as_str).collect(),
+        )
+    }
+
+    /// Returns the configured SWR hooks or `useSWR` and `useSWRImmutable`
+    pub fn swr_hooks(&self) -> Vec<&str> {
+        self.swr_hooks.as_ref().map_or_else(
+            || DEFAULT_SWR_HOOKS.to_vec(),
+            |hooks| hooks.iter().map(String::as_str).collect(),
+        )
+    }
+
+    /// Returns the configured deferred value hooks or `useDeferredValue`
+    pub fn deferred_value_hooks(&self) -> Vec<&str> {
+        self.deferred_value_hooks.as_ref().map_or_else(
+            || DEFAULT_DEFERRED_VALUE_HOOKS.to_vec(),
+            |hooks| hooks.iter().map(String::as_str).collect(),
+        )
+    }
+}
+
+/// Hooks returning a deferred value
+const DEFAULT_DEFERRED_VALUE_HOOKS: [&str; 1] = ["useDeferredValue"];
+
+/// TanStack Query hooks which suspend
+const DEFAULT_TANSTACK_QUERY_HOOKS: [&str; 3] = [
+    "useSuspenseQuery",
+    "useSuspenseQueries",
+    "useSuspenseInfiniteQuery",
+];
+
+/// SWR hooks which suspend with `suspense: true`
+const DEFAULT_SWR_HOOKS: [&str; 2] = ["useSWR", "useSWRImmutable"];
+
+/// Default name of the prop which sets a boundary ID explicitly
+const DEFAULT_TRACK_ID_PROP: &str = "trackId";
+
+/// Default name of the OpenTelemetry attributes prop
+const DEFAULT_OTEL_PROP_NAME: &str = "otelAttributes";
+
+/// OpenTelemetry `code.*` attributes describing the location of a boundary
+const DEFAULT_OTEL_ATTRIBUTES: [(&str, CallArgument); 2] = [
+    ("code.filepath", CallArgument::File),
+    ("code.lineno", CallArgument::Line),
+];
+
+/// Default marker of the boundary manifest comment
+const DEFAULT_MANIFEST_MARKER: &str = "@suspense-boundaries";
+
+/// Filename of boundary IDs if the host provides none and `fallbackFilename` is not set
+const UNKNOWN_FILENAME: &str = "unknown";
+
+/// How the plugin processes boundaries
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum Mode {
+    /// Replace boundaries with the boundary tracker
+    #[default]
+    Transform,
+    /// Leave the code untouched and only report the detected boundaries
+    /// in a trailing `@suspense-boundaries` comment
+    Analyze,
+    /// Keep the boundaries and only rewrite the `fallback` of Suspense boundaries
+    /// into `__trackFallback(id, fallback)` (no wrapper component around the boundary)
+    Inline,
+    /// Leave the code untouched and only add a `/* @boundary id=... kind=... */` comment
+    /// before every boundary element
+    Annotate,
+    /// Keep the boundaries and wrap their children into
+    /// `<BoundaryIdProvider value="file:line">` to provide the ID to descendants
+    Provider,
+}
+
+impl Mode {
+    /// Whether the mode only reports boundaries without changing the behavior of the code
+    pub fn is_read_only(self) -> bool {
+        matches!(self, Mode::Analyze | Mode::Annotate)
+    }
 }
 
 /// Default value for the enabled field (defaults to Some(true) if not specified).
@@ -38,8 +861,64 @@ pub struct Context {
     pub filename: String,
 }
 
+impl Context {
+    /// Creates the context from the metadata of the plugin host
+    ///
+    /// Some hosts (e.g. Turbopack) don't provide the environment or the filename.
+    /// A missing or invalid environment falls back to `defaultEnv`, otherwise the module
+    /// is treated as a production build. A missing filename falls back to
+    /// `fallbackFilename` or `unknown` and keeps the environment of the host.
+    /// Returns a warning message whenever the metadata of the host is incomplete.
+    pub fn from_metadata(
+        env_name: Option<&str>,
+        filename: Option<String>,
+        config: &Config,
+    ) -> (Self, Option<String>) {
+        let mut warnings = Vec::new();
+        let env_name = match env_name.map(Environment::try_from) {
+            Some(Ok(env_name)) => env_name,
+            missing => {
+                let reason = match missing {
+                    Some(Err(error)) => error,
+                    _ => "the host did not provide the environment".to_string(),
+                };
+                match config.default_env {
+                    Some(env_name) => {
+                        warnings.push(format!("{reason} - using `defaultEnv`"));
+                        env_name
+                    }
+                    None => {
+                        warnings.push(format!(
+                            "{reason} - treating the module as production build (configure `defaultEnv` to track it)"
+                        ));
+                        Environment::Production
+                    }
+                }
+            }
+        };
+        let filename = filename.unwrap_or_else(|| match &config.fallback_filename {
+            Some(filename) => {
+                warnings.push(
+                    "the host did not provide the filename - using `fallbackFilename`".to_string(),
+                );
+                filename.clone()
+            }
+            None => {
+                warnings.push(format!(
+                    "the host did not provide the filename - using `{UNKNOWN_FILENAME}` in boundary IDs (configure `fallbackFilename` for unique IDs)"
+                ));
+                UNKNOWN_FILENAME.to_string()
+            }
+        });
+
+        let warning = (!warnings.is_empty()).then(|| warnings.join("; "));
+        (Context { env_name, filename }, warning)
+    }
+}
+
 /// The target environment.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Environment {
     /// Development mode where Suspense tracking is most useful for debugging
     Development,
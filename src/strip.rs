@@ -0,0 +1,137 @@
+use std::collections::HashSet;
+
+use swc_core::{
+    common::DUMMY_SP,
+    ecma::{
+        ast::*,
+        visit::{VisitMut, VisitMutWith},
+    },
+};
+
+use crate::{
+    imports::{collect_import_bindings, remove_unused_imports},
+    props::BOUNDARY_PROP_MAP_PROPERTY_NAME,
+    BoundaryKind, TransformVisitor, BOUNDARY_ID_PROPERTY_NAME, BOUNDARY_NAME_PROPERTY_NAME,
+};
+
+impl TransformVisitor {
+    /// Replaces hand-written `<BoundaryTrackerSWC boundary={Suspense} boundaryId="...">`
+    /// (and `<ErrorBoundaryTrackerSWC>`) elements with the original boundary and drops
+    /// the tracker import once it is unused
+    pub(crate) fn strip_boundary_trackers(&mut self, module_items: &mut Vec<ModuleItem>) {
+        let trackers: HashSet<Id> = module_items
+            .iter()
+            .filter_map(|item| item.as_module_decl()?.as_import())
+            .flat_map(collect_import_bindings)
+            .filter(|(_, binding)| {
+                [BoundaryKind::Suspense, BoundaryKind::Error]
+                    .into_iter()
+                    .any(|kind| {
+                        let (src, name) = self.tracker_import(kind);
+                        binding.is(&src, name)
+                    })
+            })
+            .map(|(id, _)| id)
+            .collect();
+        if trackers.is_empty() {
+            return;
+        }
+
+        module_items.visit_mut_children_with(&mut TrackerStripper {
+            trackers: &trackers,
+        });
+
+        // Keep the import if a tracker could not be unwrapped or is referenced otherwise
+        remove_unused_imports(module_items, &trackers);
+    }
+}
+
+/// Unwraps boundary tracker elements
+struct TrackerStripper<'a> {
+    trackers: &'a HashSet<Id>,
+}
+
+impl VisitMut for TrackerStripper<'_> {
+    fn visit_mut_jsx_element(&mut self, jsx_element: &mut JSXElement) {
+        jsx_element.visit_mut_children_with(self);
+
+        let JSXElementName::Ident(name) = &jsx_element.opening.name else {
+            return;
+        };
+        if !self.trackers.contains(&name.to_id()) {
+            return;
+        }
+        let Some(boundary) = jsx_element
+            .opening
+            .attrs
+            .iter()
+            .find_map(|attr| attr_expr(attr, BOUNDARY_NAME_PROPERTY_NAME))
+            .and_then(jsx_element_name)
+        else {
+            return;
+        };
+
+        jsx_element.opening.attrs.retain(|attr| {
+            !is_attr(attr, BOUNDARY_NAME_PROPERTY_NAME)
+                && !is_attr(attr, BOUNDARY_ID_PROPERTY_NAME)
+                && !is_attr(attr, BOUNDARY_PROP_MAP_PROPERTY_NAME)
+        });
+        if let Some(closing) = &mut jsx_element.closing {
+            closing.name = boundary.clone();
+        }
+        jsx_element.opening.name = boundary;
+    }
+}
+
+/// Checks if an attribute is `name=...`
+pub(crate) fn is_attr(attr: &JSXAttrOrSpread, name: &str) -> bool {
+    matches!(
+        attr,
+        JSXAttrOrSpread::JSXAttr(JSXAttr { name: JSXAttrName::Ident(attr_name), .. })
+            if &*attr_name.sym == name
+    )
+}
+
+/// Returns the expression of an attribute `name={expr}`
+fn attr_expr<'a>(attr: &'a JSXAttrOrSpread, name: &str) -> Option<&'a Expr> {
+    if !is_attr(attr, name) {
+        return None;
+    }
+    match attr {
+        JSXAttrOrSpread::JSXAttr(JSXAttr {
+            value:
+                Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                    expr: JSXExpr::Expr(expr),
+                    ..
+                })),
+            ..
+        }) => Some(expr),
+        _ => None,
+    }
+}
+
+/// Converts `Suspense` or `Boundaries.Error` into a JSX element name
+fn jsx_element_name(expr: &Expr) -> Option<JSXElementName> {
+    match expr {
+        Expr::Ident(ident) => Some(JSXElementName::Ident(ident.clone())),
+        Expr::Member(member) => Some(JSXElementName::JSXMemberExpr(jsx_member_expr(member)?)),
+        _ => None,
+    }
+}
+
+/// Converts `a.b.c` into a JSX member expression
+fn jsx_member_expr(member: &MemberExpr) -> Option<JSXMemberExpr> {
+    let MemberProp::Ident(prop) = &member.prop else {
+        return None;
+    };
+    let obj = match &*member.obj {
+        Expr::Ident(ident) => JSXObject::Ident(ident.clone()),
+        Expr::Member(member) => JSXObject::JSXMemberExpr(Box::new(jsx_member_expr(member)?)),
+        _ => return None,
+    };
+    Some(JSXMemberExpr {
+        span: DUMMY_SP,
+        obj,
+        prop: prop.clone(),
+    })
+}
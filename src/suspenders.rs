@@ -0,0 +1,122 @@
+use swc_core::{
+    common::{util::take::Take, BytePos, DUMMY_SP},
+    ecma::{
+        ast::*,
+        visit::{Visit, VisitWith},
+    },
+};
+
+use crate::{
+    ast_utils::{call_expr, expr_stmt, ident, str_expr},
+    hooks::TANSTACK_QUERY_PACKAGE_NAME,
+    TransformVisitor,
+};
+
+const SUSPENSE_TRACKER_HOOK_NAME: &str = "useSuspenseTrackerSWC";
+
+impl TransformVisitor {
+    /// Injects `useSuspenseTrackerSWC("Name", "file:line")` into function components
+    /// which call a suspending API
+    pub(crate) fn track_suspender_function(&mut self, name: &str, function: &mut Function) {
+        if !is_component_name(name) {
+            return;
+        }
+        let Some(body) = &mut function.body else {
+            return;
+        };
+        if !self.calls_suspending_api(&*body) {
+            return;
+        }
+
+        let hook_call = self.create_suspender_hook_call(name, function.span.lo);
+        body.stmts.insert(0, hook_call);
+    }
+
+    /// Same as [`Self::track_suspender_function`] for arrow function components
+    pub(crate) fn track_suspender_arrow(&mut self, name: &str, arrow: &mut ArrowExpr) {
+        if !is_component_name(name) || !self.calls_suspending_api(&*arrow.body) {
+            return;
+        }
+
+        let hook_call = self.create_suspender_hook_call(name, arrow.span.lo);
+        match &mut *arrow.body {
+            BlockStmtOrExpr::BlockStmt(block) => block.stmts.insert(0, hook_call),
+            BlockStmtOrExpr::Expr(expr) => {
+                // `() => use(x)` becomes `() => { useSuspenseTrackerSWC(...); return use(x); }`
+                let returned = expr.as_mut().take();
+                *arrow.body = BlockStmtOrExpr::BlockStmt(BlockStmt {
+                    span: DUMMY_SP,
+                    ctxt: Default::default(),
+                    stmts: vec![
+                        hook_call,
+                        Stmt::Return(ReturnStmt {
+                            span: DUMMY_SP,
+                            arg: Some(Box::new(returned)),
+                        }),
+                    ],
+                });
+            }
+            #[cfg(swc_ast_unknown)]
+            _ => {}
+        }
+    }
+
+    /// Checks if a callee is an API which might suspend (`use`, TanStack Query suspense hooks)
+    pub(crate) fn is_suspending_call(&self, callee: &Callee) -> bool {
+        self.is_imported_call(callee, "react", "use")
+            || self
+                .config
+                .tanstack_query_hooks()
+                .into_iter()
+                .any(|hook| self.is_imported_call(callee, TANSTACK_QUERY_PACKAGE_NAME, hook))
+    }
+
+    /// Checks if a function body calls a suspending API outside of nested functions
+    pub(crate) fn calls_suspending_api<N>(&self, body: &N) -> bool
+    where
+        N: for<'a> VisitWith<SuspendingCallFinder<'a>>,
+    {
+        let mut finder = SuspendingCallFinder {
+            visitor: self,
+            found: false,
+        };
+        body.visit_with(&mut finder);
+        finder.found
+    }
+
+    /// Creates the `useSuspenseTrackerSWC("Name", "file:line");` statement
+    fn create_suspender_hook_call(&mut self, name: &str, pos: BytePos) -> Stmt {
+        let id = self.generate_boundary_id(self.lookup_line(pos));
+        self.add_runtime_import(SUSPENSE_TRACKER_HOOK_NAME);
+        expr_stmt(call_expr(
+            ident(SUSPENSE_TRACKER_HOOK_NAME),
+            vec![str_expr(name), str_expr(&id)],
+        ))
+    }
+}
+
+/// Components are named in PascalCase
+pub(crate) fn is_component_name(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_uppercase())
+}
+
+/// Finds calls of suspending APIs
+pub(crate) struct SuspendingCallFinder<'a> {
+    visitor: &'a TransformVisitor,
+    found: bool,
+}
+
+impl Visit for SuspendingCallFinder<'_> {
+    fn visit_call_expr(&mut self, call: &CallExpr) {
+        if self.visitor.is_suspending_call(&call.callee) {
+            self.found = true;
+            return;
+        }
+        call.visit_children_with(self);
+    }
+
+    // Nested functions are not called during render
+    fn visit_function(&mut self, _: &Function) {}
+
+    fn visit_arrow_expr(&mut self, _: &ArrowExpr) {}
+}
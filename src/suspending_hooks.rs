@@ -0,0 +1,71 @@
+use swc_core::{common::util::take::Take, ecma::ast::*};
+
+use crate::{
+    ast_utils::{arrow_expr, call_node, ident, object_expr, str_expr},
+    hooks::set_options_field,
+    HookInjection, TransformVisitor,
+};
+
+const TRACK_SUSPENDING_HOOK_IMPORT_NAME: &str = "__trackSuspendingHook";
+
+impl TransformVisitor {
+    /// Passes the call-site ID to calls of the configured `suspendingHooks`
+    pub(crate) fn track_suspending_hook_call(&mut self, call: &mut CallExpr) {
+        let injections: Vec<HookInjection> = self
+            .config
+            .suspending_hooks
+            .iter()
+            .filter(|hook| self.is_imported_call(&call.callee, &hook.from, &hook.name))
+            .map(|hook| hook.inject.clone())
+            .collect();
+        if !injections.is_empty() {
+            self.inject_hook_call_id(call, &injections);
+        }
+    }
+
+    /// Passes the call-site ID to a suspending hook call, the engine behind `suspendingHooks`
+    /// and the library specific options
+    ///
+    /// Options fields are set before the call is wrapped (at most once) as the wrapped
+    /// call is no longer a call of the hook.
+    /// With `fast_refresh` calls are not wrapped: the hook would be called by the arrow
+    /// function instead of the component and drop out of its Fast Refresh signature, so
+    /// adding or removing the hook wouldn't remount the component.
+    pub(crate) fn inject_hook_call_id(
+        &mut self,
+        call: &mut CallExpr,
+        injections: &[HookInjection],
+    ) {
+        let id = self.generate_boundary_id(self.lookup_line(call.span.lo));
+        for injection in injections {
+            if let HookInjection::OptionsField { arg, path } = injection {
+                set_options_arg_field(call, *arg, path, &id);
+            }
+        }
+        if injections.contains(&HookInjection::WrapCall) && !self.config.fast_refresh {
+            let hook_call = Expr::Call(call.take());
+            *call = call_node(
+                ident(TRACK_SUSPENDING_HOOK_IMPORT_NAME),
+                vec![arrow_expr(hook_call), str_expr(&id)],
+            );
+            self.add_runtime_import(TRACK_SUSPENDING_HOOK_IMPORT_NAME);
+        }
+    }
+}
+
+/// Sets the field at the dot separated `path` of the options argument at position `arg`
+///
+/// Omitted options are added, omitted arguments before them are not guessed.
+fn set_options_arg_field(call: &mut CallExpr, arg: usize, path: &str, id: &str) {
+    if call.args.len() == arg {
+        call.args.push(ExprOrSpread {
+            spread: None,
+            expr: Box::new(object_expr(Vec::new())),
+        });
+    }
+    let Some(options) = call.args.get_mut(arg).filter(|arg| arg.spread.is_none()) else {
+        return;
+    };
+    let path: Vec<&str> = path.split('.').collect();
+    set_options_field(&mut options.expr, &path, str_expr(id));
+}
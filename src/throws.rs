@@ -0,0 +1,143 @@
+use swc_core::{
+    common::{util::take::Take, Span},
+    ecma::{
+        ast::*,
+        visit::{VisitMut, VisitMutWith},
+    },
+};
+
+use crate::{
+    ast_utils::{call_expr, ident, str_expr},
+    diagnostics::THROWN_PROMISE,
+    hooks::TRACK_PROMISE_IMPORT_NAME,
+    suspenders::is_component_name,
+    TransformVisitor,
+};
+
+/// Methods of promises which return another promise
+const PROMISE_METHODS: [&str; 3] = ["then", "catch", "finally"];
+
+impl TransformVisitor {
+    /// Whether `throw promise` statements of components are reported or wrapped
+    pub(crate) fn tracks_thrown_promises(&self) -> bool {
+        (self.config.track_thrown_promises && self.tracks_calls())
+            || self.is_rule_enabled(THROWN_PROMISE)
+    }
+
+    /// Reports `throw promise` statements of a function component or hook and, with
+    /// `track_thrown_promises`, rewrites them into `throw __trackPromise(promise, "file:line")`
+    pub(crate) fn track_thrown_promises_function(&mut self, name: &str, function: &mut Function) {
+        if let Some(body) = &mut function.body {
+            self.track_thrown_promises(name, body);
+        }
+    }
+
+    /// Same as [`Self::track_thrown_promises_function`] for arrow functions
+    pub(crate) fn track_thrown_promises_arrow(&mut self, name: &str, arrow: &mut ArrowExpr) {
+        self.track_thrown_promises(name, &mut *arrow.body);
+    }
+
+    fn track_thrown_promises<N>(&mut self, name: &str, body: &mut N)
+    where
+        N: for<'a> VisitMutWith<ThrownPromiseWrapper<'a>>,
+    {
+        let label = if is_component_name(name) {
+            format!("<{name}>")
+        } else if is_hook_name(name) {
+            name.to_string()
+        } else {
+            return;
+        };
+        if !self.tracks_thrown_promises() {
+            return;
+        }
+        let mut wrapper = ThrownPromiseWrapper {
+            wrap: self.config.track_thrown_promises && self.tracks_calls(),
+            visitor: self,
+            spans: Vec::new(),
+        };
+        body.visit_mut_with(&mut wrapper);
+        let ThrownPromiseWrapper { wrap, spans, .. } = wrapper;
+        if wrap && !spans.is_empty() {
+            self.add_runtime_import(TRACK_PROMISE_IMPORT_NAME);
+        }
+        for span in spans {
+            self.report(
+                THROWN_PROMISE,
+                span,
+                format!("{label} throws a promise to suspend, use `use(promise)` instead"),
+            );
+        }
+    }
+}
+
+/// Hooks are named `useSomething`
+fn is_hook_name(name: &str) -> bool {
+    name.strip_prefix("use")
+        .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_uppercase()))
+}
+
+/// Checks if a thrown expression looks like a promise: `new Promise(...)`, `fetch(...)`,
+/// `Promise.all(...)`, `x.then(...)` or a binding whose name contains `promise`
+fn is_promise_like(expr: &Expr) -> bool {
+    match expr.unwrap_parens() {
+        Expr::New(NewExpr { callee, .. }) => is_promise_ident(callee),
+        Expr::Call(CallExpr {
+            callee: Callee::Expr(callee),
+            ..
+        }) => match &**callee {
+            Expr::Ident(callee) => &*callee.sym == "fetch",
+            Expr::Member(MemberExpr {
+                obj,
+                prop: MemberProp::Ident(method),
+                ..
+            }) => is_promise_ident(obj) || PROMISE_METHODS.contains(&&*method.sym),
+            _ => false,
+        },
+        Expr::Ident(binding) => binding.sym.to_ascii_lowercase().contains("promise"),
+        Expr::Member(MemberExpr {
+            prop: MemberProp::Ident(prop),
+            ..
+        }) => prop.sym.to_ascii_lowercase().contains("promise"),
+        _ => false,
+    }
+}
+
+fn is_promise_ident(expr: &Expr) -> bool {
+    matches!(expr, Expr::Ident(ident) if &*ident.sym == "Promise")
+}
+
+/// Finds (and optionally wraps) the promises thrown by a component body
+pub(crate) struct ThrownPromiseWrapper<'a> {
+    visitor: &'a TransformVisitor,
+    wrap: bool,
+    spans: Vec<Span>,
+}
+
+impl VisitMut for ThrownPromiseWrapper<'_> {
+    fn visit_mut_throw_stmt(&mut self, throw_stmt: &mut ThrowStmt) {
+        throw_stmt.visit_mut_children_with(self);
+
+        if !is_promise_like(&throw_stmt.arg) {
+            return;
+        }
+        self.spans.push(throw_stmt.span);
+        if self.wrap {
+            let id = self
+                .visitor
+                .generate_boundary_id(self.visitor.lookup_line(throw_stmt.span.lo));
+            let promise = throw_stmt.arg.as_mut().take();
+            *throw_stmt.arg = call_expr(
+                ident(TRACK_PROMISE_IMPORT_NAME),
+                vec![promise, str_expr(&id)],
+            );
+        }
+    }
+
+    // Throws of nested functions (e.g. event handlers) don't happen during render
+    fn visit_mut_function(&mut self, _: &mut Function) {}
+
+    fn visit_mut_arrow_expr(&mut self, _: &mut ArrowExpr) {}
+
+    fn visit_mut_class(&mut self, _: &mut Class) {}
+}
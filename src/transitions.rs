@@ -0,0 +1,57 @@
+use swc_core::{common::util::take::Take, ecma::ast::*};
+
+use crate::{ast_utils::str_expr, TransformVisitor};
+
+const TRACK_TRANSITION_IMPORT_NAME: &str = "__trackTransition";
+
+impl TransformVisitor {
+    /// Remembers the `startTransition` of `const [isPending, startTransition] = useTransition()`
+    pub(crate) fn collect_transition_binding(&mut self, declarator: &VarDeclarator) {
+        if !self.config.track_transitions {
+            return;
+        }
+        let (Pat::Array(tuple), Some(Expr::Call(call))) =
+            (&declarator.name, declarator.init.as_deref())
+        else {
+            return;
+        };
+        if !self.is_imported_call(&call.callee, "react", "useTransition") {
+            return;
+        }
+        if let Some(Some(Pat::Ident(binding))) = tuple.elems.get(1) {
+            self.transition_bindings.insert(binding.id.to_id());
+        }
+    }
+
+    /// Rewrites `startTransition(cb)` into `startTransition(__trackTransition(cb, "file:line"))`
+    pub(crate) fn track_transition_call(&mut self, call: &mut CallExpr) {
+        if !self.config.track_transitions || !self.is_start_transition(&call.callee) {
+            return;
+        }
+        let [arg] = call.args.as_mut_slice() else {
+            return;
+        };
+        if arg.spread.is_some() {
+            return;
+        }
+
+        let id = self.generate_boundary_id(self.lookup_line(call.span.lo));
+        let callback = arg.expr.as_mut().take();
+        *arg.expr =
+            Expr::Call(self.pure_call(TRACK_TRANSITION_IMPORT_NAME, vec![callback, str_expr(&id)]));
+        self.add_runtime_import(TRACK_TRANSITION_IMPORT_NAME);
+    }
+
+    /// Checks if a callee is the standalone `startTransition` or one returned by `useTransition`
+    fn is_start_transition(&self, callee: &Callee) -> bool {
+        if self.is_imported_call(callee, "react", "startTransition") {
+            return true;
+        }
+        let Callee::Expr(callee) = callee else {
+            return false;
+        };
+        callee
+            .as_ident()
+            .is_some_and(|ident| self.transition_bindings.contains(&ident.to_id()))
+    }
+}
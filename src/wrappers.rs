@@ -0,0 +1,219 @@
+use swc_core::{
+    common::{util::take::Take, DUMMY_SP},
+    ecma::ast::*,
+};
+
+use crate::{
+    ast_utils::{call_expr, ident, jsx_element, jsx_expr_attr, jsx_str_attr, str_expr},
+    TransformVisitor,
+};
+
+const PROFILER_IMPORT_NAME: &str = "BoundaryProfilerSWC";
+const ON_BOUNDARY_RENDER_IMPORT_NAME: &str = "__onBoundaryRender";
+const FALLBACK_TRACKER_IMPORT_NAME: &str = "FallbackTrackerSWC";
+const FALLBACK_PROPERTY_NAME: &str = "fallback";
+const CONTENT_TRACKER_IMPORT_NAME: &str = "ContentTrackerSWC";
+const TRACK_FALLBACK_IMPORT_NAME: &str = "__trackFallback";
+const BOUNDARY_ID_PROVIDER_IMPORT_NAME: &str = "BoundaryIdProvider";
+
+impl TransformVisitor {
+    /// Wraps the children of a tracked boundary into
+    /// `<BoundaryProfilerSWC id="file:line" onRender={__onBoundaryRender}>`
+    pub(crate) fn inject_profiler(&mut self, jsx_element: &mut JSXElement, boundary_id: &str) {
+        if !self.config.inject_profiler {
+            return;
+        }
+        let attrs = vec![
+            jsx_str_attr("id", boundary_id, DUMMY_SP),
+            jsx_expr_attr(
+                "onRender",
+                Expr::Ident(ident(ON_BOUNDARY_RENDER_IMPORT_NAME)),
+                DUMMY_SP,
+            ),
+        ];
+        if wrap_children(jsx_element, PROFILER_IMPORT_NAME, attrs) {
+            self.add_runtime_import(PROFILER_IMPORT_NAME);
+            self.add_runtime_import(ON_BOUNDARY_RENDER_IMPORT_NAME);
+        }
+    }
+
+    /// Wraps the children of a tracked Suspense boundary into `<ContentTrackerSWC id="file:line">`
+    ///
+    /// Runs before [`Self::inject_profiler`] so the profiler stays the outermost wrapper
+    pub(crate) fn track_content_mount(&mut self, jsx_element: &mut JSXElement, boundary_id: &str) {
+        if !self.config.track_content_mount {
+            return;
+        }
+        let attrs = vec![jsx_str_attr("id", boundary_id, DUMMY_SP)];
+        if wrap_children(jsx_element, CONTENT_TRACKER_IMPORT_NAME, attrs) {
+            self.add_runtime_import(CONTENT_TRACKER_IMPORT_NAME);
+        }
+    }
+
+    /// Rewrites `fallback={original}` of a tracked Suspense boundary into
+    /// `fallback={<FallbackTrackerSWC id="file:line">{original}</FallbackTrackerSWC>}`
+    pub(crate) fn track_fallback(&mut self, element: &mut JSXElement, boundary_id: &str) {
+        if !self.config.track_fallback {
+            return;
+        }
+        let Some(value) = element
+            .opening
+            .attrs
+            .iter_mut()
+            .find_map(|attr| match attr {
+                JSXAttrOrSpread::JSXAttr(JSXAttr {
+                    name: JSXAttrName::Ident(name),
+                    value: Some(value),
+                    ..
+                }) if &*name.sym == FALLBACK_PROPERTY_NAME => Some(value),
+                _ => None,
+            })
+        else {
+            return;
+        };
+
+        let fallback = match value {
+            JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                expr: JSXExpr::Expr(expr),
+                ..
+            }) => jsx_child(expr.as_mut().take()),
+            JSXAttrValue::Str(str_lit) => jsx_child(Expr::Lit(Lit::Str(str_lit.clone()))),
+            JSXAttrValue::JSXElement(fallback) => JSXElementChild::JSXElement(fallback.clone()),
+            JSXAttrValue::JSXFragment(fragment) => JSXElementChild::JSXFragment(fragment.clone()),
+            // `fallback={}` and unknown values are left untouched
+            _ => return,
+        };
+
+        let fallback_tracker = jsx_element(
+            FALLBACK_TRACKER_IMPORT_NAME,
+            vec![jsx_str_attr("id", boundary_id, DUMMY_SP)],
+            vec![fallback],
+        );
+        *value = JSXAttrValue::JSXExprContainer(JSXExprContainer {
+            span: DUMMY_SP,
+            expr: JSXExpr::Expr(Box::new(Expr::JSXElement(Box::new(fallback_tracker)))),
+        });
+        self.add_runtime_import(FALLBACK_TRACKER_IMPORT_NAME);
+    }
+
+    /// Wraps the children of a boundary into
+    /// `<BoundaryIdProvider value="file:line" boundary={Suspense}>` for `mode: "provider"`
+    pub(crate) fn provide_boundary_id(
+        &mut self,
+        jsx_element: &mut JSXElement,
+        boundary_id: &str,
+        boundary_ident: Ident,
+    ) {
+        let attrs = vec![
+            jsx_str_attr("value", boundary_id, DUMMY_SP),
+            jsx_expr_attr(
+                "boundary",
+                Expr::Ident(Ident {
+                    span: DUMMY_SP,
+                    ..boundary_ident
+                }),
+                DUMMY_SP,
+            ),
+        ];
+        if wrap_children(jsx_element, BOUNDARY_ID_PROVIDER_IMPORT_NAME, attrs) {
+            self.add_runtime_import(BOUNDARY_ID_PROVIDER_IMPORT_NAME);
+        }
+    }
+
+    /// Rewrites `fallback={original}` of a Suspense boundary into
+    /// `fallback={__trackFallback("file:line", original)}` for `mode: "inline"`
+    ///
+    /// Boundaries without a fallback get `fallback={__trackFallback("file:line", null)}`
+    /// unless a spread might contain the fallback.
+    pub(crate) fn inline_track_fallback(
+        &mut self,
+        jsx_element: &mut JSXElement,
+        boundary_id: &str,
+    ) {
+        let attrs = &mut jsx_element.opening.attrs;
+        let fallback_index = attrs.iter().position(|attr| {
+            matches!(attr, JSXAttrOrSpread::JSXAttr(JSXAttr {
+                name: JSXAttrName::Ident(name),
+                ..
+            }) if &*name.sym == FALLBACK_PROPERTY_NAME)
+        });
+
+        let Some(fallback_index) = fallback_index else {
+            if attrs
+                .iter()
+                .any(|attr| matches!(attr, JSXAttrOrSpread::SpreadElement(_)))
+            {
+                return;
+            }
+            attrs.push(jsx_expr_attr(
+                FALLBACK_PROPERTY_NAME,
+                track_fallback_call(boundary_id, Expr::Lit(Lit::Null(Null { span: DUMMY_SP }))),
+                DUMMY_SP,
+            ));
+            self.add_runtime_import(TRACK_FALLBACK_IMPORT_NAME);
+            return;
+        };
+
+        let JSXAttrOrSpread::JSXAttr(JSXAttr {
+            value: Some(value), ..
+        }) = &mut attrs[fallback_index]
+        else {
+            return;
+        };
+        let original = match value {
+            JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                expr: JSXExpr::Expr(expr),
+                ..
+            }) => expr.as_mut().take(),
+            JSXAttrValue::Str(str_lit) => Expr::Lit(Lit::Str(str_lit.clone())),
+            JSXAttrValue::JSXElement(element) => Expr::JSXElement(element.clone()),
+            JSXAttrValue::JSXFragment(fragment) => Expr::JSXFragment(fragment.clone()),
+            // `fallback={}` and unknown values are left untouched
+            _ => return,
+        };
+        *value = JSXAttrValue::JSXExprContainer(JSXExprContainer {
+            span: DUMMY_SP,
+            expr: JSXExpr::Expr(Box::new(track_fallback_call(boundary_id, original))),
+        });
+        self.add_runtime_import(TRACK_FALLBACK_IMPORT_NAME);
+    }
+}
+
+/// Creates `__trackFallback("file:line", fallback)`
+fn track_fallback_call(boundary_id: &str, fallback: Expr) -> Expr {
+    call_expr(
+        ident(TRACK_FALLBACK_IMPORT_NAME),
+        vec![str_expr(boundary_id), fallback],
+    )
+}
+
+/// Converts an expression into a JSX child (`<a/>` stays `<a/>`, everything else becomes `{expr}`)
+fn jsx_child(expr: Expr) -> JSXElementChild {
+    match expr {
+        Expr::JSXElement(element) => JSXElementChild::JSXElement(element),
+        Expr::JSXFragment(fragment) => JSXElementChild::JSXFragment(fragment),
+        expr => JSXElementChild::JSXExprContainer(JSXExprContainer {
+            span: DUMMY_SP,
+            expr: JSXExpr::Expr(Box::new(expr)),
+        }),
+    }
+}
+
+/// Moves the children of an element into a new `<name {...attrs}>` element
+///
+/// Returns `false` (and leaves the element untouched) if there are no children to wrap
+fn wrap_children(element: &mut JSXElement, name: &str, attrs: Vec<JSXAttrOrSpread>) -> bool {
+    if element.children.iter().all(is_whitespace_text) {
+        return false;
+    }
+    let children = std::mem::take(&mut element.children);
+    element.children = vec![JSXElementChild::JSXElement(Box::new(jsx_element(
+        name, attrs, children,
+    )))];
+    true
+}
+
+/// Checks if a child is text which only consists of whitespace (e.g. indentation)
+fn is_whitespace_text(child: &JSXElementChild) -> bool {
+    matches!(child, JSXElementChild::JSXText(text) if text.value.trim().is_empty())
+}
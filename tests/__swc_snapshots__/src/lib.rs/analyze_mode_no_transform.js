@@ -0,0 +1,6 @@
+import { useEffect, Suspense } from "react";
+function App() {
+    return <Suspense fallback={<Loading/>}>
+      <MyComponent/>
+    </Suspense>;
+}
@@ -0,0 +1,6 @@
+// Copyright (c) Acme Inc.
+// @ts-nocheck
+import { BoundaryTrackerSWC } from "react-swc-suspense-tracker/context";
+// eslint-disable-next-line import/order
+import { Suspense } from "react";
+export const App = ()=><BoundaryTrackerSWC fallback={null} boundaryId="my/file.tsx:0" boundary={Suspense}><Header/></BoundaryTrackerSWC>;
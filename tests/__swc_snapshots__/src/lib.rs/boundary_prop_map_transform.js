@@ -0,0 +1,11 @@
+import { ErrorBoundaryTrackerSWC } from "react-swc-suspense-tracker/error-context";
+import { ErrorBoundary } from "react-error-boundary";
+function App(props) {
+    return <ErrorBoundaryTrackerSWC FallbackComponent={ErrorFallback} onError={props.onError} resetKeys={props.resetKeys} className="app" boundaryId="my/file.tsx:0" boundary={ErrorBoundary} boundaryPropMap={{
+        FallbackComponent: "fallback",
+        onError: "onError",
+        resetKeys: "resetKeys"
+    }}>
+      <Content/>
+    </ErrorBoundaryTrackerSWC>;
+}
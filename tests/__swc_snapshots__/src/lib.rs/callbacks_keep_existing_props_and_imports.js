@@ -0,0 +1,9 @@
+import { BoundaryTrackerSWC } from "react-swc-suspense-tracker/context";
+import { reportResolve } from "@acme/telemetry";
+import { Suspense } from "react";
+import { reportSuspend as onSuspend } from "@acme/telemetry";
+export const App = ({ props })=><>
+    <BoundaryTrackerSWC fallback={null} onResolve={done} boundaryId="my/file.tsx:0" boundary={Suspense} onSuspend={onSuspend}><Content/></BoundaryTrackerSWC>
+    <BoundaryTrackerSWC fallback={null} {...props} boundaryId="my/file.tsx:0#2" boundary={Suspense}><Content/></BoundaryTrackerSWC>
+    <BoundaryTrackerSWC fallback={null} boundaryId="my/file.tsx:0#3" boundary={Suspense} onSuspend={onSuspend} onResolve={reportResolve}><Content/></BoundaryTrackerSWC>
+  </>;
@@ -0,0 +1,9 @@
+import { BoundaryTrackerSWC } from "react-swc-suspense-tracker/context";
+import { reportSuspend as _reportSuspend, reportResolve as _reportResolve } from "@acme/telemetry";
+import { Suspense } from "react";
+import { reportResolve } from "./local-telemetry";
+const reportSuspend = (id)=>console.log(id);
+export const App = ()=><>
+    <BoundaryTrackerSWC fallback={null} boundaryId="my/file.tsx:0" boundary={Suspense} onSuspend={_reportSuspend} onResolve={_reportResolve}><Content/></BoundaryTrackerSWC>
+    <BoundaryTrackerSWC fallback={null} boundaryId="my/file.tsx:0#2" boundary={Suspense} onSuspend={_reportSuspend} onResolve={_reportResolve}><Content/></BoundaryTrackerSWC>
+  </>;
@@ -0,0 +1,8 @@
+import { BoundaryTrackerSWC } from "react-swc-suspense-tracker/context";
+import { reportSuspend, reportResolve } from "@acme/telemetry";
+import { useEffect, Suspense } from "react";
+function App() {
+    return <BoundaryTrackerSWC fallback={<Loading/>} boundaryId="my/file.tsx:0" boundary={Suspense} onSuspend={reportSuspend} onResolve={reportResolve}>
+      <MyComponent/>
+    </BoundaryTrackerSWC>;
+}
@@ -0,0 +1,4 @@
+import { useEffect } from "react";
+function App() {
+    return <div>Hello World</div>;
+}
@@ -0,0 +1,2 @@
+import { format } from "./format";
+export const label = format("ready");
@@ -0,0 +1,9 @@
+import { useQuery } from "./query";
+const useTrackedQuery = useQuery;
+function Product() {
+    const boundaries = [];
+    const owner = null;
+    const lazyInfo = void 0;
+    skipCheck(), void 0;
+    return <div>{owner}</div>;
+}
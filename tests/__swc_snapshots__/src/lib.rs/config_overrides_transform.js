@@ -0,0 +1,4 @@
+import { BoundaryIdProvider } from "react-swc-suspense-tracker/context";
+import { Suspense as Loader } from "react";
+import { ErrorBoundary as Guard } from "react-error-boundary";
+export const App = ()=><Guard fallback={null}><BoundaryIdProvider value="my/file.tsx:0" boundary={Guard}><Loader fallback={null}><BoundaryIdProvider value="my/file.tsx:0#2" boundary={Loader}><Content/></BoundaryIdProvider></Loader></BoundaryIdProvider></Guard>;
@@ -0,0 +1,10 @@
+#!/usr/bin/env node
+"use client";
+"use strict";
+import { __trackPromise } from "react-swc-suspense-tracker/context";
+export function Profile({ userPromise }) {
+    if (!userPromise.value) {
+        throw __trackPromise(userPromise, "my/file.tsx:0");
+    }
+    return null;
+}
@@ -0,0 +1,12 @@
+import { BoundaryTrackerSWC } from "react-swc-suspense-tracker/context";
+import { Suspense } from "react";
+function App() {
+    return <BoundaryTrackerSWC fallback={<Loading/>} boundaryId="my/file.tsx:0" boundary={Suspense} __boundarySource={{
+        fileName: "my/file.tsx",
+        lineNumber: 0,
+        columnNumber: 0
+    }}><MyComponent/></BoundaryTrackerSWC>;
+}
+function Compiled() {
+    return <BoundaryTrackerSWC fallback={<Loading/>} __source={source} boundaryId="my/file.tsx:0#2" boundary={Suspense}><MyComponent/></BoundaryTrackerSWC>;
+}
@@ -0,0 +1,3 @@
+import { trackLazy } from "react-swc-suspense-tracker/context";
+import { lazy } from "react";
+const Product = /*#__PURE__*/ trackLazy(lazy(()=>import(/* webpackChunkName: "b__src_App_tsx_2_17" */ "./Product")), "src/App.tsx:2", "./Product"), Reviews = /*#__PURE__*/ trackLazy(lazy(()=>import(/* webpackChunkName: "b__src_App_tsx_2_60" */ "./Reviews")), "src/App.tsx:2", "./Reviews");
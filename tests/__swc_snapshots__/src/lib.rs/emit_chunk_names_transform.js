@@ -0,0 +1,5 @@
+import { trackLazy } from "react-swc-suspense-tracker/context";
+import { lazy } from "react";
+const Product = /*#__PURE__*/ trackLazy(lazy(()=>import(/* webpackChunkName: "b__src_App_tsx_2_17" */ "./Product")), "src/App.tsx:2", "./Product");
+const Cart = /*#__PURE__*/ trackLazy(lazy(()=>import(/* webpackChunkName: "cart" */ "./Cart")), "src/App.tsx:3", "./Cart");
+const Reviews = /*#__PURE__*/ trackLazy(lazy(()=>import(/* webpackPrefetch: true */ /* webpackChunkName: "b__src_App_tsx_4_17" */ "./Reviews")), "src/App.tsx:4", "./Reviews");
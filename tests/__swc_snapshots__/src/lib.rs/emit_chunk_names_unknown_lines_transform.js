@@ -0,0 +1,3 @@
+import { trackLazy } from "react-swc-suspense-tracker/context";
+import { lazy } from "react";
+const Product = /*#__PURE__*/ trackLazy(lazy(()=>import(/* webpackChunkName: "b__src_App_tsx_0" */ "./Product")), "src/App.tsx:0", "./Product"), Reviews = /*#__PURE__*/ trackLazy(lazy(()=>import(/* webpackChunkName: "b__src_App_tsx_0_2" */ "./Reviews")), "src/App.tsx:0", "./Reviews");
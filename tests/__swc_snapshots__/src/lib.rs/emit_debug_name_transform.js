@@ -0,0 +1,9 @@
+import { BoundaryTrackerSWC } from "react-swc-suspense-tracker/context";
+import { Suspense } from "react";
+const ProductSection = ()=><BoundaryTrackerSWC fallback={<Loading/>} boundaryId="my/file.tsx:0" boundary={Suspense} debugName="ProductSection">
+    <Products/>
+  </BoundaryTrackerSWC>;
+const reviews = <BoundaryTrackerSWC fallback={<Loading/>} boundaryId="my/file.tsx:0#2" boundary={Suspense} debugName="reviews"><Reviews/></BoundaryTrackerSWC>;
+export default function() {
+    return <BoundaryTrackerSWC fallback={<Loading/>} boundaryId="my/file.tsx:0#3" boundary={Suspense}>{reviews}</BoundaryTrackerSWC>;
+}
@@ -0,0 +1,10 @@
+import { BoundaryTrackerSWC } from "react-swc-suspense-tracker/context";
+import { Suspense } from "react";
+function Page() {
+    return <BoundaryTrackerSWC fallback={<PageSkeleton/>} boundaryId="my/file.tsx:0" boundary={Suspense} depth={0}>
+      <BoundaryTrackerSWC fallback={<WidgetSkeleton/>} boundaryId="my/file.tsx:0#2" boundary={Suspense} depth={1}>
+        <BoundaryTrackerSWC fallback={<RowSkeleton/>} boundaryId="my/file.tsx:0#3" boundary={Suspense} depth={2}><Row/></BoundaryTrackerSWC>
+      </BoundaryTrackerSWC>
+      <BoundaryTrackerSWC fallback={<WidgetSkeleton/>} boundaryId="my/file.tsx:0#4" boundary={Suspense} depth={1}><Widget/></BoundaryTrackerSWC>
+    </BoundaryTrackerSWC>;
+}
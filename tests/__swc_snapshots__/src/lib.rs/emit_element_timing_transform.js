@@ -0,0 +1,9 @@
+import { BoundaryTrackerSWC } from "react-swc-suspense-tracker/context";
+import { Suspense } from "react";
+function App() {
+    return <>
+      <BoundaryTrackerSWC fallback={<div className="skeleton" elementtiming="my/file.tsx:0"/>} boundaryId="my/file.tsx:0" boundary={Suspense}><Content/></BoundaryTrackerSWC>
+      <BoundaryTrackerSWC fallback={<span data-boundary-id="custom" elementtiming="my/file.tsx:0#2"/>} boundaryId="my/file.tsx:0#2" boundary={Suspense}><Content/></BoundaryTrackerSWC>
+      <BoundaryTrackerSWC fallback={<Skeleton/>} boundaryId="my/file.tsx:0#3" boundary={Suspense}><Content/></BoundaryTrackerSWC>
+    </>;
+}
@@ -0,0 +1,14 @@
+import { BoundaryTrackerSWC } from "react-swc-suspense-tracker/context";
+import { Suspense } from "react";
+export function CheckoutPage() {
+    const PaymentSection = ()=><BoundaryTrackerSWC fallback={<Loading/>} boundaryId="my/file.tsx:0" boundary={Suspense} ownerStack={[
+            "CheckoutPage",
+            "PaymentSection"
+        ]}>
+      <Payment/>
+    </BoundaryTrackerSWC>;
+    return <BoundaryTrackerSWC fallback={<Loading/>} boundaryId="my/file.tsx:0#2" boundary={Suspense} ownerStack={[
+        "CheckoutPage"
+    ]}><PaymentSection/></BoundaryTrackerSWC>;
+}
+export const Fallback = <BoundaryTrackerSWC fallback={null} boundaryId="my/file.tsx:0#3" boundary={Suspense}><Content/></BoundaryTrackerSWC>;
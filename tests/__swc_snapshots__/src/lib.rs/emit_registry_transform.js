@@ -0,0 +1,24 @@
+import { BoundaryTrackerSWC, registerBoundaries } from "react-swc-suspense-tracker/context";
+import { Suspense } from "react";
+registerBoundaries([
+    {
+        id: "my/file.tsx:0",
+        kind: "suspense",
+        component: "Suspense"
+    },
+    {
+        id: "my/file.tsx:0#2",
+        kind: "suspense",
+        component: "Suspense"
+    }
+]);
+function App() {
+    return <div>
+      <BoundaryTrackerSWC fallback={<Loading/>} boundaryId="my/file.tsx:0" boundary={Suspense}>
+        <Component1/>
+      </BoundaryTrackerSWC>
+      <BoundaryTrackerSWC fallback={<div>Loading...</div>} boundaryId="my/file.tsx:0#2" boundary={Suspense}>
+        <Component2/>
+      </BoundaryTrackerSWC>
+    </div>;
+}
@@ -0,0 +1,7 @@
+import { ErrorBoundaryTrackerSWC } from "react-swc-suspense-tracker/error-context";
+import { BoundaryTrackerSWC } from "react-swc-suspense-tracker/context";
+import { Suspense as Loader } from "react";
+import { ErrorBoundary as Guard } from "react-error-boundary";
+export const App = ()=><ErrorBoundaryTrackerSWC fallback={null} boundaryId="my/file.tsx:0" boundary={Guard} boundaryPropMap={{
+        fallback: "fallback"
+    }}><BoundaryTrackerSWC fallback={null} boundaryId="my/file.tsx:0#2"><Content/></BoundaryTrackerSWC></ErrorBoundaryTrackerSWC>;
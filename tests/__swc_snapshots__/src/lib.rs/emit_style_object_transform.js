@@ -0,0 +1,20 @@
+import { BoundaryTrackerSWC } from "react-swc-suspense-tracker/context";
+import { Suspense } from "react";
+function App() {
+    return <>
+      <BoundaryTrackerSWC fallback={<Row.Skeleton/>} __boundaryMeta={{
+        id: "my/file.tsx:0",
+        kind: "suspense",
+        owner: "App",
+        fallbackName: "Row.Skeleton",
+        boundary: Suspense
+    }}><Content/></BoundaryTrackerSWC>
+      <BoundaryTrackerSWC fallback="Loading..." __boundaryMeta={{
+        id: "my/file.tsx:0#2",
+        kind: "suspense",
+        owner: "App",
+        fallbackName: null,
+        boundary: Suspense
+    }}><Content/></BoundaryTrackerSWC>
+    </>;
+}
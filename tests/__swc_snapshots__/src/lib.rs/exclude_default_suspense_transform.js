@@ -0,0 +1,4 @@
+import { BoundaryTrackerSWC } from "react-swc-suspense-tracker/context";
+import { Suspense } from "react";
+import { RouteBoundary } from "@acme/router";
+export const App = ()=><BoundaryTrackerSWC fallback={null} boundaryId="my/file.tsx:0" boundary={RouteBoundary}><Suspense fallback={null}><Content/></Suspense></BoundaryTrackerSWC>;
@@ -0,0 +1,4 @@
+import { Suspense } from "react";
+import { BoundaryTrackerSWC as Tracker } from "react-swc-suspense-tracker/context";
+export const Manual = ()=><Tracker boundary={Suspense} boundaryId="manual"><Content/></Tracker>;
+export const App = ()=><Tracker fallback={null} boundaryId="my/file.tsx:0" boundary={Suspense}><Content/></Tracker>;
@@ -0,0 +1,14 @@
+import { BoundaryTrackerSWC } from "react-swc-suspense-tracker/context";
+import { useEffect, Suspense } from "react";
+function App() {
+    return <BoundaryTrackerSWC fallback={<Loading/>} boundaryId="my/file.tsx:0" boundary={Suspense}>
+      <MyComponent/>
+    </BoundaryTrackerSWC>;
+}
+export const __SUSPENSE_BOUNDARIES__ = [
+    {
+        id: "my/file.tsx:0",
+        kind: "suspense",
+        component: "Suspense"
+    }
+];
@@ -0,0 +1,17 @@
+import { BoundaryTrackerSWC, registerBoundaries } from "react-swc-suspense-tracker/context";
+import { Suspense } from "react";
+var _c;
+export default function App() {
+    return <BoundaryTrackerSWC fallback={<Loading/>} boundaryId="my/file.tsx:0" boundary={Suspense}>
+      <Content/>
+    </BoundaryTrackerSWC>;
+}
+_c = App;
+$RefreshReg$(_c, "App");
+registerBoundaries([
+    {
+        id: "my/file.tsx:0",
+        kind: "suspense",
+        component: "Suspense"
+    }
+]);
@@ -0,0 +1,14 @@
+import { BoundaryTrackerSWC } from "react-swc-suspense-tracker/context";
+import { Suspense } from "react";
+const __bid1 = "my/file.tsx:0";
+const __bid2 = "my/file.tsx:0#2";
+function App() {
+    return <div>
+      <BoundaryTrackerSWC fallback={<Loading/>} boundaryId={__bid1} boundary={Suspense}>
+        <Component1/>
+      </BoundaryTrackerSWC>
+      <BoundaryTrackerSWC fallback={<div>Loading...</div>} boundaryId={__bid2} boundary={Suspense}>
+        <Component2/>
+      </BoundaryTrackerSWC>
+    </div>;
+}
@@ -0,0 +1,26 @@
+import { BoundaryTrackerSWC, registerBoundaries } from "react-swc-suspense-tracker/context";
+import { Suspense } from "react";
+const __bid1 = "my/file.tsx:App/Suspense";
+const __bid2 = "my/file.tsx:App/Suspense#2";
+registerBoundaries([
+    {
+        id: "my/file.tsx:App/Suspense",
+        kind: "suspense",
+        component: "Suspense"
+    },
+    {
+        id: "my/file.tsx:App/Suspense#2",
+        kind: "suspense",
+        component: "Suspense"
+    }
+]);
+function App() {
+    return <div>
+      <BoundaryTrackerSWC fallback={<Loading/>} boundaryId={__bid1} boundary={Suspense}>
+        <Component1/>
+      </BoundaryTrackerSWC>
+      <BoundaryTrackerSWC fallback={<div>Loading...</div>} boundaryId={__bid2} boundary={Suspense}>
+        <Component2/>
+      </BoundaryTrackerSWC>
+    </div>;
+}
@@ -0,0 +1,9 @@
+import { BoundaryTrackerSWC } from "react-swc-suspense-tracker/context";
+import { Suspense } from "react";
+function Dashboard({ section }) {
+    return <>
+      <BoundaryTrackerSWC name="chart" fallback={<Spinner/>} boundaryId="chart@my/file.tsx:0" boundary={Suspense}><Chart/></BoundaryTrackerSWC>
+      <BoundaryTrackerSWC name={section} fallback={<Spinner/>} boundaryId={`${section}@my/file.tsx:0`} boundary={Suspense}><Section/></BoundaryTrackerSWC>
+      <BoundaryTrackerSWC fallback={<Spinner/>} boundaryId="my/file.tsx:0" boundary={Suspense}><Footer/></BoundaryTrackerSWC>
+    </>;
+}
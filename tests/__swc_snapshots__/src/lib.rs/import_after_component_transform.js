@@ -0,0 +1,7 @@
+export function App() {
+    return <BoundaryTrackerSWC fallback={<Loading/>} boundaryId="my/file.tsx:0" boundary={Suspense}>
+      <Content/>
+    </BoundaryTrackerSWC>;
+}
+import { BoundaryTrackerSWC } from "react-swc-suspense-tracker/context";
+import { Suspense } from "react";
@@ -0,0 +1,6 @@
+import "./polyfills";
+import { Suspense } from "react";
+import { createRoot } from "react-dom/client";
+import { BoundaryTrackerSWC } from "react-swc-suspense-tracker/context";
+import { Header } from "./Header";
+export const App = ()=><BoundaryTrackerSWC fallback={null} boundaryId="my/file.tsx:0" boundary={Suspense}><Header/></BoundaryTrackerSWC>;
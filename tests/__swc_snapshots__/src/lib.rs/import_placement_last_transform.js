@@ -0,0 +1,6 @@
+import "./polyfills";
+import { Suspense } from "react";
+import { createRoot } from "react-dom/client";
+import { Header } from "./Header";
+import { BoundaryTrackerSWC } from "react-swc-suspense-tracker/context";
+export const App = ()=><BoundaryTrackerSWC fallback={null} boundaryId="my/file.tsx:0" boundary={Suspense}><Header/></BoundaryTrackerSWC>;
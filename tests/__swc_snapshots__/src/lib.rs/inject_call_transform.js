@@ -0,0 +1,15 @@
+import { BoundaryTrackerSWC } from "react-swc-suspense-tracker/context";
+import { __registerBoundary } from "@acme/telemetry";
+import { Suspense } from "react";
+__registerBoundary("my/file.tsx:0", "suspense", "App");
+__registerBoundary("my/file.tsx:0#2", "suspense", "App");
+function App() {
+    return <div>
+      <BoundaryTrackerSWC fallback={<Loading/>} boundaryId="my/file.tsx:0" boundary={Suspense}>
+        <Component1/>
+      </BoundaryTrackerSWC>
+      <BoundaryTrackerSWC fallback={<div>Loading...</div>} boundaryId="my/file.tsx:0#2" boundary={Suspense}>
+        <Component2/>
+      </BoundaryTrackerSWC>
+    </div>;
+}
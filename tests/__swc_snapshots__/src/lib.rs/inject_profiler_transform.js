@@ -0,0 +1,7 @@
+import { BoundaryTrackerSWC, BoundaryProfilerSWC, __onBoundaryRender } from "react-swc-suspense-tracker/context";
+import { useEffect, Suspense } from "react";
+function App() {
+    return <BoundaryTrackerSWC fallback={<Loading/>} boundaryId="my/file.tsx:0" boundary={Suspense}><BoundaryProfilerSWC id="my/file.tsx:0" onRender={__onBoundaryRender}>
+      <MyComponent/>
+    </BoundaryProfilerSWC></BoundaryTrackerSWC>;
+}
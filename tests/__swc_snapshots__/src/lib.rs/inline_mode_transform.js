@@ -0,0 +1,10 @@
+import { __trackFallback } from "react-swc-suspense-tracker/context";
+import { Suspense } from "react";
+function App(props) {
+    return <div>
+      <Suspense fallback={__trackFallback("my/file.tsx:0", <Loading/>)}><Component1/></Suspense>
+      <Suspense fallback={__trackFallback("my/file.tsx:0#2", "Loading...")}><Component2/></Suspense>
+      <Suspense fallback={__trackFallback("my/file.tsx:0#3", null)}><Component3/></Suspense>
+      <Suspense {...props}><Component4/></Suspense>
+    </div>;
+}
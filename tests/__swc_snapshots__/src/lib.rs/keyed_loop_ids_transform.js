@@ -0,0 +1,11 @@
+import { BoundaryTrackerSWC } from "react-swc-suspense-tracker/context";
+import { Suspense } from "react";
+function List({ items }) {
+    return <ul>
+      {items.map((item)=><BoundaryTrackerSWC key={item.id} fallback={<RowSkeleton/>} boundaryId={`my/file.tsx:0:${item.id}`} boundary={Suspense}><Row item={item}/></BoundaryTrackerSWC>)}
+      {items.map((item)=><li key={item.id}>
+          <BoundaryTrackerSWC fallback={<RowSkeleton/>} boundaryId={`my/file.tsx:0#2:${item.id}`} boundary={Suspense}><Row item={item}/></BoundaryTrackerSWC>
+        </li>)}
+      <BoundaryTrackerSWC fallback={<TotalSkeleton/>} boundaryId="my/file.tsx:0#3" boundary={Suspense}><Total/></BoundaryTrackerSWC>
+    </ul>;
+}
@@ -0,0 +1,10 @@
+import { BoundaryTrackerSWC } from "react-swc-suspense-tracker/context";
+import { Suspense } from "react";
+function Page() {
+    return <BoundaryTrackerSWC fallback={<PageSkeleton/>} boundaryId="my/file.tsx:0" boundary={Suspense}>
+      <BoundaryTrackerSWC fallback={<WidgetSkeleton/>} boundaryId="my/file.tsx:0#2" boundary={Suspense}>
+        <Suspense fallback={<RowSkeleton/>}><Row/></Suspense>
+      </BoundaryTrackerSWC>
+      <BoundaryTrackerSWC fallback={<WidgetSkeleton/>} boundaryId="my/file.tsx:0#3" boundary={Suspense}><Widget/></BoundaryTrackerSWC>
+    </BoundaryTrackerSWC>;
+}
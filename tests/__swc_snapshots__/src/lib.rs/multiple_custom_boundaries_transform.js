@@ -6,7 +6,7 @@ function App() {
       <BoundaryTrackerSWC fallback={<ErrorFallback/>} boundaryId="my/file.tsx:0" boundary={ErrorBoundary}>
         <Component1/>
       </BoundaryTrackerSWC>
-      <BoundaryTrackerSWC fallback={<div>Loading...</div>} boundaryId="my/file.tsx:0" boundary={LoadingBoundary}>
+      <BoundaryTrackerSWC fallback={<div>Loading...</div>} boundaryId="my/file.tsx:0#2" boundary={LoadingBoundary}>
         <Component2/>
       </BoundaryTrackerSWC>
     </div>;
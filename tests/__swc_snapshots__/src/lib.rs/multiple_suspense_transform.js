@@ -5,7 +5,7 @@ function App() {
       <BoundaryTrackerSWC fallback={<Loading/>} boundaryId="my/file.tsx:0" boundary={Suspense}>
         <Component1/>
       </BoundaryTrackerSWC>
-      <BoundaryTrackerSWC fallback={<div>Loading...</div>} boundaryId="my/file.tsx:0" boundary={Suspense}>
+      <BoundaryTrackerSWC fallback={<div>Loading...</div>} boundaryId="my/file.tsx:0#2" boundary={Suspense}>
         <Component2/>
       </BoundaryTrackerSWC>
     </div>;
@@ -0,0 +1,10 @@
+import { BoundaryTrackerSWC } from "react-swc-suspense-tracker/context";
+import { Suspense } from "react";
+function Page() {
+    return <BoundaryTrackerSWC fallback={<PageSkeleton/>} boundaryId="my/file.tsx:0" boundary={Suspense}>
+      <Suspense fallback={<WidgetSkeleton/>}>
+        <Suspense fallback={<RowSkeleton/>}><Row/></Suspense>
+      </Suspense>
+      <Suspense fallback={<WidgetSkeleton/>}><Widget/></Suspense>
+    </BoundaryTrackerSWC>;
+}
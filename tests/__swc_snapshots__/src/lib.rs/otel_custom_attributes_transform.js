@@ -0,0 +1,13 @@
+import { ErrorBoundaryTrackerSWC } from "react-swc-suspense-tracker/error-context";
+import { BoundaryTrackerSWC } from "react-swc-suspense-tracker/context";
+import { Suspense as Loader } from "react";
+import { ErrorBoundary as Guard } from "react-error-boundary";
+export const App = ()=><ErrorBoundaryTrackerSWC fallback={null} boundaryId="my/file.tsx:0" boundary={Guard} traceAttributes={{
+        "boundary.id": "my/file.tsx:0",
+        "code.function": "App"
+    }} boundaryPropMap={{
+        fallback: "fallback"
+    }}><BoundaryTrackerSWC fallback={null} boundaryId="my/file.tsx:0#2" boundary={Loader} traceAttributes={{
+        "boundary.id": "my/file.tsx:0#2",
+        "code.function": "App"
+    }}><Content/></BoundaryTrackerSWC></ErrorBoundaryTrackerSWC>;
@@ -0,0 +1,18 @@
+import { BoundaryTrackerSWC } from "react-swc-suspense-tracker/context";
+import { Suspense } from "react";
+function App() {
+    return <>
+      <BoundaryTrackerSWC fallback={<div className="skeleton" data-otel-boundary-id="my/file.tsx:0"/>} boundaryId="my/file.tsx:0" boundary={Suspense} otelAttributes={{
+        "code.filepath": "my/file.tsx",
+        "code.lineno": 0
+    }}><Content/></BoundaryTrackerSWC>
+      <BoundaryTrackerSWC fallback={<span data-boundary-id="custom" data-otel-boundary-id="my/file.tsx:0#2"/>} boundaryId="my/file.tsx:0#2" boundary={Suspense} otelAttributes={{
+        "code.filepath": "my/file.tsx",
+        "code.lineno": 0
+    }}><Content/></BoundaryTrackerSWC>
+      <BoundaryTrackerSWC fallback={<Skeleton/>} boundaryId="my/file.tsx:0#3" boundary={Suspense} otelAttributes={{
+        "code.filepath": "my/file.tsx",
+        "code.lineno": 0
+    }}><Content/></BoundaryTrackerSWC>
+    </>;
+}
@@ -0,0 +1,18 @@
+import { BoundaryTrackerSWC, FallbackTrackerSWC } from "react-swc-suspense-tracker/context-native";
+import { Suspense } from "react";
+function App() {
+    return <>
+      <BoundaryTrackerSWC fallback={<FallbackTrackerSWC id="my/file.tsx:0"><div className="skeleton"/></FallbackTrackerSWC>} boundaryId="my/file.tsx:0" boundary={Suspense} otelAttributes={{
+        "code.filepath": "my/file.tsx",
+        "code.lineno": 0
+    }}><Content/></BoundaryTrackerSWC>
+      <BoundaryTrackerSWC fallback={<FallbackTrackerSWC id="my/file.tsx:0#2"><span data-boundary-id="custom"/></FallbackTrackerSWC>} boundaryId="my/file.tsx:0#2" boundary={Suspense} otelAttributes={{
+        "code.filepath": "my/file.tsx",
+        "code.lineno": 0
+    }}><Content/></BoundaryTrackerSWC>
+      <BoundaryTrackerSWC fallback={<FallbackTrackerSWC id="my/file.tsx:0#3"><Skeleton/></FallbackTrackerSWC>} boundaryId="my/file.tsx:0#3" boundary={Suspense} otelAttributes={{
+        "code.filepath": "my/file.tsx",
+        "code.lineno": 0
+    }}><Content/></BoundaryTrackerSWC>
+    </>;
+}
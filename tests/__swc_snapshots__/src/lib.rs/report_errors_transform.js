@@ -0,0 +1,15 @@
+import { ErrorBoundaryTrackerSWC, __reportBoundaryError } from "react-swc-suspense-tracker/error-context";
+import { ErrorBoundary } from "react-error-boundary";
+function App(props) {
+    return <ErrorBoundaryTrackerSWC fallback={<Error/>} boundaryId="my/file.tsx:0" boundary={ErrorBoundary} boundaryPropMap={{
+        fallback: "fallback"
+    }} onError={__reportBoundaryError("my/file.tsx:0")}>
+      <ErrorBoundaryTrackerSWC fallback={<Error/>} onError={__reportBoundaryError("my/file.tsx:0#2", (error)=>console.error(error))} boundaryId="my/file.tsx:0#2" boundary={ErrorBoundary} boundaryPropMap={{
+        fallback: "fallback",
+        onError: "onError"
+    }}>
+        <Content/>
+      </ErrorBoundaryTrackerSWC>
+      <ErrorBoundaryTrackerSWC {...props} boundaryId="my/file.tsx:0#3" boundary={ErrorBoundary}/>
+    </ErrorBoundaryTrackerSWC>;
+}
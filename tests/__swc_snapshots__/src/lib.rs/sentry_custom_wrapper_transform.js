@@ -0,0 +1,7 @@
+import { ErrorBoundaryTrackerSWC } from "react-swc-suspense-tracker/error-context";
+import { tagCapture } from "@acme/sentry";
+import { ErrorBoundary } from "@sentry/react";
+export const App = ()=><>
+    <ErrorBoundaryTrackerSWC fallback={<p>Error</p>} boundaryId="my/file.tsx:0" boundary={ErrorBoundary} beforeCapture={tagCapture("boundary", "my/file.tsx:0")}><Content/></ErrorBoundaryTrackerSWC>
+    <ErrorBoundaryTrackerSWC beforeCapture={tagCapture("boundary", "my/file.tsx:0#2", (scope)=>scope.setTag("page", "app"))} boundaryId="my/file.tsx:0#2" boundary={ErrorBoundary}><Content/></ErrorBoundaryTrackerSWC>
+  </>;
@@ -0,0 +1,6 @@
+import { ErrorBoundaryTrackerSWC, __tagBoundaryCapture } from "react-swc-suspense-tracker/error-context";
+import { ErrorBoundary } from "@sentry/react";
+export const App = ()=><>
+    <ErrorBoundaryTrackerSWC fallback={<p>Error</p>} boundaryId="my/file.tsx:0" boundary={ErrorBoundary} beforeCapture={__tagBoundaryCapture("boundaryId", "my/file.tsx:0")}><Content/></ErrorBoundaryTrackerSWC>
+    <ErrorBoundaryTrackerSWC beforeCapture={__tagBoundaryCapture("boundaryId", "my/file.tsx:0#2", (scope)=>scope.setTag("page", "app"))} boundaryId="my/file.tsx:0#2" boundary={ErrorBoundary}><Content/></ErrorBoundaryTrackerSWC>
+  </>;
@@ -0,0 +1,16 @@
+import { BoundaryTrackerSWC } from "react-swc-suspense-tracker/context";
+import { Suspense } from "react";
+function App() {
+    return <BoundaryTrackerSWC fallback={<Loading/>} boundaryId="my/file.tsx:0" boundary={Suspense}><Inner/></BoundaryTrackerSWC>;
+}
+function Inner() {
+    const Suspense = ({ children })=><div>{children}</div>;
+    return <Suspense><Content/></Suspense>;
+}
+const Param = ({ Suspense })=><Suspense><Content/></Suspense>;
+function Hoisted() {
+    if (enabled) {
+        var Suspense = Custom;
+    }
+    return <Suspense><Content/></Suspense>;
+}
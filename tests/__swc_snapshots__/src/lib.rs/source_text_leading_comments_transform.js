@@ -0,0 +1,15 @@
+import { BoundaryTrackerSWC } from "react-swc-suspense-tracker/context";
+/**
+ * The product page
+ */ // @ts-check
+import { Suspense } from "react";
+function App() {
+    return <div>
+      <BoundaryTrackerSWC fallback={<Loading/>} boundaryId="my/file.tsx:10" boundary={Suspense}>
+        <Component1/>
+      </BoundaryTrackerSWC>
+      <BoundaryTrackerSWC fallback={<div>Loading...</div>} boundaryId="my/file.tsx:13" boundary={Suspense}>
+        <Component2/>
+      </BoundaryTrackerSWC>
+    </div>;
+}
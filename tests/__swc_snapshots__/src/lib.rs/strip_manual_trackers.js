@@ -0,0 +1,11 @@
+import { Suspense } from "react";
+import * as Boundaries from "./boundaries";
+import { registerBoundaries } from "react-swc-suspense-tracker/context";
+registerBoundaries([]);
+function App() {
+    return <Suspense fallback={<Loading/>}>
+      <Boundaries.Error>
+        <Content/>
+      </Boundaries.Error>
+    </Suspense>;
+}
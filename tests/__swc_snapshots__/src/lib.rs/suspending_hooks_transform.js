@@ -0,0 +1,16 @@
+import { __trackSuspendingHook } from "react-swc-suspense-tracker/context";
+import { useQuery } from "urql";
+import useResource from "@acme/resources";
+function Product(props) {
+    const [result] = useQuery({
+        query: ProductQuery,
+        variables: {
+            id: props.id
+        },
+        context: {
+            suspenseTrackerId: "my/file.tsx:0"
+        }
+    });
+    const stock = __trackSuspendingHook(()=>useResource(`/stock/${props.id}`), "my/file.tsx:0");
+    return null;
+}
@@ -0,0 +1,12 @@
+import { BoundaryTrackerSWC } from "react-swc-suspense-tracker/context-test";
+import { Suspense } from "react";
+function App() {
+    return <div>
+      <BoundaryTrackerSWC fallback={<Loading/>} boundaryId="my/file.tsx:App/Suspense" boundary={Suspense}>
+        <Component1/>
+      </BoundaryTrackerSWC>
+      <BoundaryTrackerSWC fallback={<div>Loading...</div>} boundaryId="my/file.tsx:App/Suspense#2" boundary={Suspense}>
+        <Component2/>
+      </BoundaryTrackerSWC>
+    </div>;
+}
@@ -0,0 +1,20 @@
+import { skipToken as _skipToken } from "@apollo/client/react";
+import { useBackgroundQuery, useSuspenseQuery } from "@apollo/client/react";
+function Product({ productOptions, reviewsOptions }) {
+    const skipToken = props.skip;
+    const { data } = useSuspenseQuery(PRODUCT_QUERY, productOptions === _skipToken ? productOptions : {
+        ...productOptions,
+        context: {
+            ...productOptions.context,
+            suspenseTrackerId: "my/file.tsx:0"
+        }
+    });
+    const [queryRef] = useBackgroundQuery(REVIEWS_QUERY, reviewsOptions === _skipToken ? reviewsOptions : {
+        ...reviewsOptions,
+        context: {
+            ...reviewsOptions.context,
+            suspenseTrackerId: "my/file.tsx:0"
+        }
+    });
+    return null;
+}
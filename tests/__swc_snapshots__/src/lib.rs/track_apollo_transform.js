@@ -0,0 +1,32 @@
+import { __trackSuspendingHook } from "react-swc-suspense-tracker/context";
+import { skipToken, useBackgroundQuery, useReadQuery, useSuspenseQuery } from "@apollo/client";
+function Product(props) {
+    const { data } = useSuspenseQuery(PRODUCT_QUERY, {
+        variables: {
+            id: props.id
+        },
+        context: {
+            suspenseTrackerId: "my/file.tsx:0"
+        }
+    });
+    const { data: user } = useSuspenseQuery(USER_QUERY, {
+        context: {
+            suspenseTrackerId: "my/file.tsx:0"
+        }
+    });
+    const { data: cart } = useSuspenseQuery(CART_QUERY, props.cartId ? {
+        variables: {
+            id: props.cartId
+        }
+    } : skipToken);
+    const { data: skipped } = useSuspenseQuery(CART_QUERY, skipToken);
+    const [queryRef] = useBackgroundQuery(REVIEWS_QUERY, reviewsOptions === skipToken ? reviewsOptions : {
+        ...reviewsOptions,
+        context: {
+            ...reviewsOptions.context,
+            suspenseTrackerId: "my/file.tsx:0"
+        }
+    });
+    const { data: reviews } = __trackSuspendingHook(()=>useReadQuery(queryRef), "my/file.tsx:0");
+    return null;
+}
@@ -0,0 +1,17 @@
+import { __trackAwait } from "react-swc-suspense-tracker/server";
+import { db } from "./db";
+export default async function Page({ id }) {
+    const product = await __trackAwait(db.product(id), "my/file.tsx:0");
+    if (product.hasReviews) {
+        const reviews = await __trackAwait(db.reviews(id), "my/file.tsx:0");
+    }
+    const load = async ()=>await db.related(id);
+    return <Product product={product} load={load}/>;
+}
+export const Reviews = async ({ id })=>{
+    const reviews = await __trackAwait(db.reviews(id), "my/file.tsx:0");
+    return <List items={reviews}/>;
+};
+async function loadUser(id) {
+    return await db.user(id);
+}
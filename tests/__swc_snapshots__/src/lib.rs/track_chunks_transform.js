@@ -0,0 +1,14 @@
+import { __trackChunk, trackLazy } from "react-swc-suspense-tracker/context";
+import dynamic from "next/dynamic";
+const Chart = trackLazy(dynamic(()=>__trackChunk(import("./Chart"), "my/file.tsx:0", "./Chart"), {
+    ssr: false
+}), "my/file.tsx:0", "./Chart");
+const Map = trackLazy(dynamic({
+    loader: ()=>__trackChunk(import("./Map"), "my/file.tsx:0", "./Map")
+}), "my/file.tsx:0", "./Map");
+function App() {
+    return <div>
+      <Chart/>
+      <Map/>
+    </div>;
+}
@@ -0,0 +1,10 @@
+import { BoundaryTrackerSWC, ContentTrackerSWC, FallbackTrackerSWC, BoundaryProfilerSWC, __onBoundaryRender } from "react-swc-suspense-tracker/context";
+import { Suspense } from "react";
+function App() {
+    return <BoundaryTrackerSWC fallback={<FallbackTrackerSWC id="my/file.tsx:0"><Loading/></FallbackTrackerSWC>} boundaryId="my/file.tsx:0" boundary={Suspense}><BoundaryProfilerSWC id="my/file.tsx:0" onRender={__onBoundaryRender}><ContentTrackerSWC id="my/file.tsx:0">
+      <Header/>
+      <BoundaryTrackerSWC fallback={<FallbackTrackerSWC id="my/file.tsx:0#2"><Spinner/></FallbackTrackerSWC>} boundaryId="my/file.tsx:0#2" boundary={Suspense}><BoundaryProfilerSWC id="my/file.tsx:0#2" onRender={__onBoundaryRender}><ContentTrackerSWC id="my/file.tsx:0#2">
+        <Content/>
+      </ContentTrackerSWC></BoundaryProfilerSWC></BoundaryTrackerSWC>
+    </ContentTrackerSWC></BoundaryProfilerSWC></BoundaryTrackerSWC>;
+}
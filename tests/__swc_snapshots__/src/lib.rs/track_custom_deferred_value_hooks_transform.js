@@ -0,0 +1,10 @@
+import { __trackDeferredValue } from "./tracking";
+import * as React from "react";
+import { useDeferredValue } from "react";
+import { useDebouncedValue } from "./hooks";
+function SearchResults(props) {
+    const query = useDeferredValue(props.query);
+    const page = React.useDeferredValue(props.page, 0);
+    const filter = __trackDeferredValue(useDebouncedValue(props.filter), "my/file.tsx:0");
+    return <Results query={query} page={page} filter={filter}/>;
+}
@@ -0,0 +1,10 @@
+import { __trackDeferredValue } from "react-swc-suspense-tracker/context";
+import * as React from "react";
+import { useDeferredValue } from "react";
+import { useDebouncedValue } from "./hooks";
+function SearchResults(props) {
+    const query = __trackDeferredValue(useDeferredValue(props.query), "my/file.tsx:0");
+    const page = __trackDeferredValue(React.useDeferredValue(props.page, 0), "my/file.tsx:0");
+    const filter = useDebouncedValue(props.filter);
+    return <Results query={query} page={page} filter={filter}/>;
+}
@@ -0,0 +1,18 @@
+import { BoundaryTrackerSWC, FallbackTrackerSWC } from "react-swc-suspense-tracker/context";
+import { Suspense } from "react";
+function App(props) {
+    return <div>
+      <BoundaryTrackerSWC fallback={<FallbackTrackerSWC id="my/file.tsx:0"><Loading/></FallbackTrackerSWC>} boundaryId="my/file.tsx:0" boundary={Suspense}>
+        <Component1/>
+      </BoundaryTrackerSWC>
+      <BoundaryTrackerSWC fallback={<FallbackTrackerSWC id="my/file.tsx:0#2">{props.loading}</FallbackTrackerSWC>} boundaryId="my/file.tsx:0#2" boundary={Suspense}>
+        <Component2/>
+      </BoundaryTrackerSWC>
+      <BoundaryTrackerSWC fallback={<FallbackTrackerSWC id="my/file.tsx:0#3">{"Loading..."}</FallbackTrackerSWC>} boundaryId="my/file.tsx:0#3" boundary={Suspense}>
+        <Component3/>
+      </BoundaryTrackerSWC>
+      <BoundaryTrackerSWC fallback={<FallbackTrackerSWC id="my/file.tsx:0#4"><>Loading...</></FallbackTrackerSWC>} boundaryId="my/file.tsx:0#4" boundary={Suspense}>
+        <Component4/>
+      </BoundaryTrackerSWC>
+    </div>;
+}
@@ -0,0 +1,8 @@
+import { BoundaryTrackerSWC } from "react-swc-suspense-tracker/context";
+import { Suspense } from "react";
+function Checkout() {
+    return <BoundaryTrackerSWC fallback={<Spinner/>} boundaryId="checkout-items" boundary={Suspense}>
+      <Items/>
+      <BoundaryTrackerSWC fallback={<Spinner/>} boundaryId="my/file.tsx:0" boundary={Suspense}><Total/></BoundaryTrackerSWC>
+    </BoundaryTrackerSWC>;
+}
@@ -0,0 +1,10 @@
+import { trackLazy, BoundaryTrackerSWC } from "react-swc-suspense-tracker/context";
+import React, { lazy, Suspense } from "react";
+const Product = trackLazy(lazy(()=>import("./Product")), "my/file.tsx:0", "./Product");
+const Cart = trackLazy(React.lazy(()=>import("./Cart")), "my/file.tsx:0", "./Cart");
+function App() {
+    return <BoundaryTrackerSWC fallback={<Loading/>} boundaryId="my/file.tsx:0" boundary={Suspense}>
+      <Product/>
+      <Cart/>
+    </BoundaryTrackerSWC>;
+}
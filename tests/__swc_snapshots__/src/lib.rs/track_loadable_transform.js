@@ -0,0 +1,6 @@
+import { trackLazy } from "react-swc-suspense-tracker/context";
+import loadable, { lazy } from "@loadable/component";
+const Product = trackLazy(loadable(()=>import("./Product"), {
+    fallback: <Loading/>
+}), "my/file.tsx:0", "./Product");
+const Cart = trackLazy(lazy(()=>import("./Cart")), "my/file.tsx:0", "./Cart");
@@ -0,0 +1,14 @@
+import { trackLazy } from "react-swc-suspense-tracker/context";
+import dynamic from "next/dynamic";
+const Chart = trackLazy(dynamic(()=>import("./Chart"), {
+    ssr: false
+}), "my/file.tsx:0", "./Chart");
+const Map = trackLazy(dynamic({
+    loader: ()=>import("./Map")
+}), "my/file.tsx:0", "./Map");
+function App() {
+    return <div>
+      <Chart/>
+      <Map/>
+    </div>;
+}
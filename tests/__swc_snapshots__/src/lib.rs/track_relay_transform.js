@@ -0,0 +1,27 @@
+import { __trackSuspendingHook } from "react-swc-suspense-tracker/context";
+import { graphql, useFragment, useLazyLoadQuery, usePreloadedQuery } from "react-relay";
+function Product(props) {
+    const data = __trackSuspendingHook(()=>useLazyLoadQuery(ProductQuery, {
+            id: props.id
+        }, {
+            networkCacheConfig: {
+                metadata: {
+                    suspenseTrackerId: "my/file.tsx:0"
+                }
+            }
+        }), "my/file.tsx:0");
+    const cached = __trackSuspendingHook(()=>useLazyLoadQuery(ProductQuery, {
+            id: props.id
+        }, {
+            fetchPolicy: "store-only",
+            networkCacheConfig: {
+                metadata: {
+                    suspenseTrackerId: "my/file.tsx:0"
+                }
+            }
+        }), "my/file.tsx:0");
+    const preloaded = __trackSuspendingHook(()=>usePreloadedQuery(ProductQuery, props.queryRef), "my/file.tsx:0");
+    const reviews = __trackSuspendingHook(()=>useFragment(graphql`fragment Product_reviews on Product { reviews @defer { id } }`, data.product), "my/file.tsx:0");
+    const price = useFragment(graphql`fragment Product_price on Product { price }`, data.product);
+    return null;
+}
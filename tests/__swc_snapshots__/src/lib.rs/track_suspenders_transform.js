@@ -0,0 +1,24 @@
+import { useSuspenseTrackerSWC } from "react-swc-suspense-tracker/context";
+import { use } from "react";
+import { useSuspenseQuery } from "@tanstack/react-query";
+function Cart(props) {
+    useSuspenseTrackerSWC("Cart", "my/file.tsx:0");
+    const cart = use(props.cartPromise);
+    return <div>{cart.items}</div>;
+}
+const Price = (props)=>{
+    useSuspenseTrackerSWC("Price", "my/file.tsx:0");
+    return use(props.pricePromise);
+};
+const Product = function(props) {
+    useSuspenseTrackerSWC("Product", "my/file.tsx:0");
+    const { data } = useSuspenseQuery(props.query);
+    const onClick = ()=>use(props.promise);
+    return <button onClick={onClick}>{data.name}</button>;
+};
+function Static() {
+    return <div/>;
+}
+function formatPrice(price) {
+    return use(price);
+}
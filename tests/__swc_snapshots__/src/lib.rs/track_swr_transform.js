@@ -0,0 +1,17 @@
+import { __trackSuspendingHook } from "react-swc-suspense-tracker/context";
+import useSWR from "swr";
+import useImmutable from "swr/immutable";
+import { useApi } from "./api";
+function Product(props) {
+    const { data: product } = __trackSuspendingHook(()=>useSWR(props.key, fetchProduct, {
+            suspense: true
+        }), "my/file.tsx:0");
+    const { data: reviews } = useSWR(props.reviewsKey, fetchReviews);
+    const { data: stock } = __trackSuspendingHook(()=>useImmutable(props.stockKey, {
+            suspense: true
+        }), "my/file.tsx:0");
+    const { data: prices } = __trackSuspendingHook(()=>useApi(props.priceKey, fetchPrices, {
+            suspense: true
+        }), "my/file.tsx:0");
+    return null;
+}
@@ -0,0 +1,36 @@
+import { useSuspenseQuery, useSuspenseQueries } from "@tanstack/react-query";
+function Product(props) {
+    const product = useSuspenseQuery({
+        queryKey: props.key,
+        queryFn: fetchProduct,
+        meta: {
+            suspenseTrackerId: "my/file.tsx:0"
+        }
+    });
+    const reviews = useSuspenseQuery({
+        queryKey: props.key,
+        meta: {
+            owner: "reviews",
+            suspenseTrackerId: "my/file.tsx:0"
+        }
+    });
+    const stock = useSuspenseQuery(props.stockOptions);
+    const prices = useSuspenseQuery({
+        ...priceOptions,
+        meta: {
+            ...priceOptions.meta,
+            suspenseTrackerId: "my/file.tsx:0"
+        }
+    });
+    const related = useSuspenseQueries({
+        queries: [
+            {
+                queryKey: props.key,
+                meta: {
+                    suspenseTrackerId: "my/file.tsx:0"
+                }
+            }
+        ]
+    });
+    return null;
+}
@@ -0,0 +1,25 @@
+import { __trackPromise } from "react-swc-suspense-tracker/context";
+import { fetchUser } from "./api";
+const cache = new Map();
+function useUser(id) {
+    const user = cache.get(id);
+    if (!user) {
+        throw __trackPromise(fetchUser(id).then((user)=>cache.set(id, user)), "my/file.tsx:0");
+    }
+    return user;
+}
+export function Profile({ userPromise }) {
+    if (!userPromise.value) {
+        throw __trackPromise(userPromise, "my/file.tsx:0");
+    }
+    if (userPromise.value.banned) {
+        throw new Error("banned");
+    }
+    const refresh = ()=>{
+        throw fetch("/api/user");
+    };
+    return <Avatar user={userPromise.value} onClick={refresh}/>;
+}
+function loadUser(id) {
+    throw fetchUser(id);
+}
@@ -0,0 +1,8 @@
+import { __trackTransition } from "react-swc-suspense-tracker/context";
+import { startTransition, useTransition } from "react";
+function Search(props) {
+    const [isPending, startSearch] = useTransition();
+    const onChange = (event)=>startSearch(__trackTransition(()=>props.onSearch(event.target.value), "my/file.tsx:0"));
+    const onReset = ()=>startTransition(__trackTransition(()=>props.onSearch(""), "my/file.tsx:0"));
+    return <input onChange={onChange} onReset={onReset}/>;
+}
@@ -0,0 +1,6 @@
+import { __trackPromise } from "react-swc-suspense-tracker/context";
+import { use } from "react";
+function Cart(props) {
+    const cart = use(__trackPromise(props.cartPromise, "my/file.tsx:0"));
+    return <div>{cart.items}</div>;
+}
@@ -0,0 +1,169 @@
+//! Regression tests over the real-world fixtures of `tests/corpus/fixtures`
+//!
+//! The fixtures are anonymized files of Next.js, React Router and TanStack Query apps
+//! and generated files. Every fixture is transformed with the options of
+//! `tests/corpus/config.json` and must not panic, must emit code which parses again and
+//! must find the boundaries and IDs of its snapshot in `tests/corpus/snapshots`.
+//! Run with `UPDATE=1` to write the snapshots of new or changed fixtures.
+#![cfg(feature = "library")]
+
+use std::{
+    env, fs,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+};
+
+use react_swc_suspense_tracker::{transform_source, Config, Context, Environment};
+use serde_json::{json, Value};
+use swc_core::{
+    common::{sync::Lrc, FileName, SourceMap},
+    ecma::{
+        ast::{EsVersion, Module, Str},
+        parser::{parse_file_as_module, EsSyntax, Syntax, TsSyntax},
+        visit::{Visit, VisitWith},
+    },
+};
+
+const CORPUS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/corpus");
+
+/// Returns all files below `dir` sorted by path
+fn fixture_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir).expect("read fixtures") {
+        let path = entry.expect("fixture entry").path();
+        if path.is_dir() {
+            files.extend(fixture_files(&path));
+        } else {
+            files.push(path);
+        }
+    }
+    files.sort();
+    files
+}
+
+fn syntax_for_filename(filename: &str) -> Syntax {
+    if filename.ends_with(".ts") || filename.ends_with(".tsx") {
+        Syntax::Typescript(TsSyntax {
+            tsx: filename.ends_with(".tsx"),
+            ..Default::default()
+        })
+    } else {
+        Syntax::Es(EsSyntax {
+            jsx: true,
+            ..Default::default()
+        })
+    }
+}
+
+/// Parses the emitted code with the syntax of the fixture
+fn parse_output(filename: &str, code: &str) -> Result<Module, String> {
+    let source_map: Lrc<SourceMap> = Default::default();
+    let source_file =
+        source_map.new_source_file(FileName::Custom(filename.into()).into(), code.to_string());
+    parse_file_as_module(
+        &source_file,
+        syntax_for_filename(filename),
+        EsVersion::latest(),
+        None,
+        &mut Vec::new(),
+    )
+    .map_err(|error| format!("{error:?}"))
+}
+
+/// Collects the IDs the transform injected (all strings starting with `file:`)
+struct IdCollector<'a> {
+    prefix: &'a str,
+    ids: Vec<String>,
+}
+
+impl Visit for IdCollector<'_> {
+    fn visit_str(&mut self, str: &Str) {
+        if let Some(value) = str.value.as_str() {
+            if value.starts_with(self.prefix) {
+                self.ids.push(value.to_string());
+            }
+        }
+    }
+}
+
+/// Transforms a fixture and returns its snapshot
+fn transform_fixture(filename: &str, source: &str, config: Config) -> Result<Value, String> {
+    let output = panic::catch_unwind(AssertUnwindSafe(|| {
+        transform_source(
+            source,
+            config,
+            Context {
+                env_name: Environment::Development,
+                filename: filename.to_string(),
+            },
+        )
+    }))
+    .map_err(|_| "the transform panicked".to_string())??;
+
+    let module = parse_output(filename, &output.code)
+        .map_err(|error| format!("the output doesn't parse: {error}\n{}", output.code))?;
+    let prefix = format!("{filename}:");
+    let mut ids = IdCollector {
+        prefix: &prefix,
+        ids: Vec::new(),
+    };
+    module.visit_with(&mut ids);
+
+    Ok(json!({
+        "boundaries": output.boundaries,
+        "ids": ids.ids,
+    }))
+}
+
+#[test]
+fn corpus_transforms_without_regressions() {
+    let config: Config = serde_json::from_str(
+        &fs::read_to_string(format!("{CORPUS_DIR}/config.json")).expect("read config"),
+    )
+    .expect("invalid config");
+    let update = env::var("UPDATE").is_ok_and(|update| update == "1");
+
+    let fixtures_dir = Path::new(CORPUS_DIR).join("fixtures");
+    let fixtures = fixture_files(&fixtures_dir);
+    assert!(!fixtures.is_empty(), "the corpus has no fixtures");
+
+    let mut failures = Vec::new();
+    for fixture in fixtures {
+        let filename = fixture
+            .strip_prefix(&fixtures_dir)
+            .expect("fixture below the fixtures directory")
+            .to_string_lossy()
+            .replace('\\', "/");
+        let source = fs::read_to_string(&fixture).expect("read fixture");
+        let snapshot_path = Path::new(CORPUS_DIR)
+            .join("snapshots")
+            .join(format!("{filename}.json"));
+
+        let actual = match transform_fixture(&filename, &source, config.clone()) {
+            Ok(actual) => actual,
+            Err(error) => {
+                failures.push(format!("{filename}: {error}"));
+                continue;
+            }
+        };
+        if update {
+            fs::create_dir_all(snapshot_path.parent().expect("snapshot directory"))
+                .expect("create snapshot directory");
+            let snapshot = serde_json::to_string_pretty(&actual).expect("serialize snapshot");
+            fs::write(&snapshot_path, format!("{snapshot}\n")).expect("write snapshot");
+            continue;
+        }
+        let Ok(snapshot) = fs::read_to_string(&snapshot_path) else {
+            failures.push(format!("{filename}: missing snapshot, run with UPDATE=1"));
+            continue;
+        };
+        let expected: Value = serde_json::from_str(&snapshot).expect("invalid snapshot");
+        if actual != expected {
+            failures.push(format!(
+                "{filename}: the snapshot changed, run with UPDATE=1 if this is expected\n{}",
+                serde_json::to_string_pretty(&actual).expect("serialize snapshot")
+            ));
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n\n"));
+}
@@ -0,0 +1,509 @@
+// Code generated by icon-builder. DO NOT EDIT.
+import { forwardRef, type SVGProps } from "react";
+
+export type IconProps = SVGProps<SVGSVGElement> & { size?: number | string };
+
+export const ArrowLeftIcon = forwardRef<SVGSVGElement, IconProps>(function ArrowLeftIcon(
+  { size = 24, ...props },
+  ref,
+) {
+  return (
+    <svg
+      ref={ref}
+      xmlns="http://www.w3.org/2000/svg"
+      width={size}
+      height={size}
+      viewBox="0 0 24 24"
+      fill="none"
+      stroke="currentColor"
+      strokeWidth={2}
+      strokeLinecap="round"
+      strokeLinejoin="round"
+      {...props}
+    >
+      <path d="M15 18l-6-6 6-6" />
+    </svg>
+  );
+});
+ArrowLeftIcon.displayName = "ArrowLeftIcon";
+
+export const ArrowRightIcon = forwardRef<SVGSVGElement, IconProps>(function ArrowRightIcon(
+  { size = 24, ...props },
+  ref,
+) {
+  return (
+    <svg
+      ref={ref}
+      xmlns="http://www.w3.org/2000/svg"
+      width={size}
+      height={size}
+      viewBox="0 0 24 24"
+      fill="none"
+      stroke="currentColor"
+      strokeWidth={2}
+      strokeLinecap="round"
+      strokeLinejoin="round"
+      {...props}
+    >
+      <path d="M9 18l6-6-6-6" />
+    </svg>
+  );
+});
+ArrowRightIcon.displayName = "ArrowRightIcon";
+
+export const CartIcon = forwardRef<SVGSVGElement, IconProps>(function CartIcon(
+  { size = 24, ...props },
+  ref,
+) {
+  return (
+    <svg
+      ref={ref}
+      xmlns="http://www.w3.org/2000/svg"
+      width={size}
+      height={size}
+      viewBox="0 0 24 24"
+      fill="none"
+      stroke="currentColor"
+      strokeWidth={2}
+      strokeLinecap="round"
+      strokeLinejoin="round"
+      {...props}
+    >
+      <path d="M6 6h15l-1.5 9h-12z" />
+    </svg>
+  );
+});
+CartIcon.displayName = "CartIcon";
+
+export const CheckIcon = forwardRef<SVGSVGElement, IconProps>(function CheckIcon(
+  { size = 24, ...props },
+  ref,
+) {
+  return (
+    <svg
+      ref={ref}
+      xmlns="http://www.w3.org/2000/svg"
+      width={size}
+      height={size}
+      viewBox="0 0 24 24"
+      fill="none"
+      stroke="currentColor"
+      strokeWidth={2}
+      strokeLinecap="round"
+      strokeLinejoin="round"
+      {...props}
+    >
+      <path d="M20 6L9 17l-5-5" />
+    </svg>
+  );
+});
+CheckIcon.displayName = "CheckIcon";
+
+export const ChevronDownIcon = forwardRef<SVGSVGElement, IconProps>(function ChevronDownIcon(
+  { size = 24, ...props },
+  ref,
+) {
+  return (
+    <svg
+      ref={ref}
+      xmlns="http://www.w3.org/2000/svg"
+      width={size}
+      height={size}
+      viewBox="0 0 24 24"
+      fill="none"
+      stroke="currentColor"
+      strokeWidth={2}
+      strokeLinecap="round"
+      strokeLinejoin="round"
+      {...props}
+    >
+      <path d="M6 9l6 6 6-6" />
+    </svg>
+  );
+});
+ChevronDownIcon.displayName = "ChevronDownIcon";
+
+export const ChevronUpIcon = forwardRef<SVGSVGElement, IconProps>(function ChevronUpIcon(
+  { size = 24, ...props },
+  ref,
+) {
+  return (
+    <svg
+      ref={ref}
+      xmlns="http://www.w3.org/2000/svg"
+      width={size}
+      height={size}
+      viewBox="0 0 24 24"
+      fill="none"
+      stroke="currentColor"
+      strokeWidth={2}
+      strokeLinecap="round"
+      strokeLinejoin="round"
+      {...props}
+    >
+      <path d="M18 15l-6-6-6 6" />
+    </svg>
+  );
+});
+ChevronUpIcon.displayName = "ChevronUpIcon";
+
+export const CloseIcon = forwardRef<SVGSVGElement, IconProps>(function CloseIcon(
+  { size = 24, ...props },
+  ref,
+) {
+  return (
+    <svg
+      ref={ref}
+      xmlns="http://www.w3.org/2000/svg"
+      width={size}
+      height={size}
+      viewBox="0 0 24 24"
+      fill="none"
+      stroke="currentColor"
+      strokeWidth={2}
+      strokeLinecap="round"
+      strokeLinejoin="round"
+      {...props}
+    >
+      <path d="M18 6L6 18M6 6l12 12" />
+    </svg>
+  );
+});
+CloseIcon.displayName = "CloseIcon";
+
+export const FilterIcon = forwardRef<SVGSVGElement, IconProps>(function FilterIcon(
+  { size = 24, ...props },
+  ref,
+) {
+  return (
+    <svg
+      ref={ref}
+      xmlns="http://www.w3.org/2000/svg"
+      width={size}
+      height={size}
+      viewBox="0 0 24 24"
+      fill="none"
+      stroke="currentColor"
+      strokeWidth={2}
+      strokeLinecap="round"
+      strokeLinejoin="round"
+      {...props}
+    >
+      <path d="M22 3H2l8 9.46V19l4 2v-8.54z" />
+    </svg>
+  );
+});
+FilterIcon.displayName = "FilterIcon";
+
+export const HeartIcon = forwardRef<SVGSVGElement, IconProps>(function HeartIcon(
+  { size = 24, ...props },
+  ref,
+) {
+  return (
+    <svg
+      ref={ref}
+      xmlns="http://www.w3.org/2000/svg"
+      width={size}
+      height={size}
+      viewBox="0 0 24 24"
+      fill="none"
+      stroke="currentColor"
+      strokeWidth={2}
+      strokeLinecap="round"
+      strokeLinejoin="round"
+      {...props}
+    >
+      <path d="M20.8 4.6a5.5 5.5 0 0 0-7.8 0L12 5.7l-1-1.1a5.5 5.5 0 0 0-7.8 7.8L12 21l8.8-8.6a5.5 5.5 0 0 0 0-7.8z" />
+    </svg>
+  );
+});
+HeartIcon.displayName = "HeartIcon";
+
+export const HomeIcon = forwardRef<SVGSVGElement, IconProps>(function HomeIcon(
+  { size = 24, ...props },
+  ref,
+) {
+  return (
+    <svg
+      ref={ref}
+      xmlns="http://www.w3.org/2000/svg"
+      width={size}
+      height={size}
+      viewBox="0 0 24 24"
+      fill="none"
+      stroke="currentColor"
+      strokeWidth={2}
+      strokeLinecap="round"
+      strokeLinejoin="round"
+      {...props}
+    >
+      <path d="M3 9l9-7 9 7v11a2 2 0 0 1-2 2H5a2 2 0 0 1-2-2z" />
+    </svg>
+  );
+});
+HomeIcon.displayName = "HomeIcon";
+
+export const InfoIcon = forwardRef<SVGSVGElement, IconProps>(function InfoIcon(
+  { size = 24, ...props },
+  ref,
+) {
+  return (
+    <svg
+      ref={ref}
+      xmlns="http://www.w3.org/2000/svg"
+      width={size}
+      height={size}
+      viewBox="0 0 24 24"
+      fill="none"
+      stroke="currentColor"
+      strokeWidth={2}
+      strokeLinecap="round"
+      strokeLinejoin="round"
+      {...props}
+    >
+      <path d="M12 16v-4M12 8h.01" />
+    </svg>
+  );
+});
+InfoIcon.displayName = "InfoIcon";
+
+export const MenuIcon = forwardRef<SVGSVGElement, IconProps>(function MenuIcon(
+  { size = 24, ...props },
+  ref,
+) {
+  return (
+    <svg
+      ref={ref}
+      xmlns="http://www.w3.org/2000/svg"
+      width={size}
+      height={size}
+      viewBox="0 0 24 24"
+      fill="none"
+      stroke="currentColor"
+      strokeWidth={2}
+      strokeLinecap="round"
+      strokeLinejoin="round"
+      {...props}
+    >
+      <path d="M3 12h18M3 6h18M3 18h18" />
+    </svg>
+  );
+});
+MenuIcon.displayName = "MenuIcon";
+
+export const MinusIcon = forwardRef<SVGSVGElement, IconProps>(function MinusIcon(
+  { size = 24, ...props },
+  ref,
+) {
+  return (
+    <svg
+      ref={ref}
+      xmlns="http://www.w3.org/2000/svg"
+      width={size}
+      height={size}
+      viewBox="0 0 24 24"
+      fill="none"
+      stroke="currentColor"
+      strokeWidth={2}
+      strokeLinecap="round"
+      strokeLinejoin="round"
+      {...props}
+    >
+      <path d="M5 12h14" />
+    </svg>
+  );
+});
+MinusIcon.displayName = "MinusIcon";
+
+export const PlusIcon = forwardRef<SVGSVGElement, IconProps>(function PlusIcon(
+  { size = 24, ...props },
+  ref,
+) {
+  return (
+    <svg
+      ref={ref}
+      xmlns="http://www.w3.org/2000/svg"
+      width={size}
+      height={size}
+      viewBox="0 0 24 24"
+      fill="none"
+      stroke="currentColor"
+      strokeWidth={2}
+      strokeLinecap="round"
+      strokeLinejoin="round"
+      {...props}
+    >
+      <path d="M12 5v14M5 12h14" />
+    </svg>
+  );
+});
+PlusIcon.displayName = "PlusIcon";
+
+export const SearchIcon = forwardRef<SVGSVGElement, IconProps>(function SearchIcon(
+  { size = 24, ...props },
+  ref,
+) {
+  return (
+    <svg
+      ref={ref}
+      xmlns="http://www.w3.org/2000/svg"
+      width={size}
+      height={size}
+      viewBox="0 0 24 24"
+      fill="none"
+      stroke="currentColor"
+      strokeWidth={2}
+      strokeLinecap="round"
+      strokeLinejoin="round"
+      {...props}
+    >
+      <path d="M21 21l-4.35-4.35" />
+    </svg>
+  );
+});
+SearchIcon.displayName = "SearchIcon";
+
+export const SettingsIcon = forwardRef<SVGSVGElement, IconProps>(function SettingsIcon(
+  { size = 24, ...props },
+  ref,
+) {
+  return (
+    <svg
+      ref={ref}
+      xmlns="http://www.w3.org/2000/svg"
+      width={size}
+      height={size}
+      viewBox="0 0 24 24"
+      fill="none"
+      stroke="currentColor"
+      strokeWidth={2}
+      strokeLinecap="round"
+      strokeLinejoin="round"
+      {...props}
+    >
+      <path d="M12 15a3 3 0 1 0 0-6 3 3 0 0 0 0 6z" />
+    </svg>
+  );
+});
+SettingsIcon.displayName = "SettingsIcon";
+
+export const StarIcon = forwardRef<SVGSVGElement, IconProps>(function StarIcon(
+  { size = 24, ...props },
+  ref,
+) {
+  return (
+    <svg
+      ref={ref}
+      xmlns="http://www.w3.org/2000/svg"
+      width={size}
+      height={size}
+      viewBox="0 0 24 24"
+      fill="none"
+      stroke="currentColor"
+      strokeWidth={2}
+      strokeLinecap="round"
+      strokeLinejoin="round"
+      {...props}
+    >
+      <path d="M12 2l3.09 6.26L22 9.27l-5 4.87 1.18 6.88L12 17.77l-6.18 3.25L7 14.14 2 9.27l6.91-1.01z" />
+    </svg>
+  );
+});
+StarIcon.displayName = "StarIcon";
+
+export const TrashIcon = forwardRef<SVGSVGElement, IconProps>(function TrashIcon(
+  { size = 24, ...props },
+  ref,
+) {
+  return (
+    <svg
+      ref={ref}
+      xmlns="http://www.w3.org/2000/svg"
+      width={size}
+      height={size}
+      viewBox="0 0 24 24"
+      fill="none"
+      stroke="currentColor"
+      strokeWidth={2}
+      strokeLinecap="round"
+      strokeLinejoin="round"
+      {...props}
+    >
+      <path d="M3 6h18M8 6V4h8v2M19 6l-1 14H6L5 6" />
+    </svg>
+  );
+});
+TrashIcon.displayName = "TrashIcon";
+
+export const UserIcon = forwardRef<SVGSVGElement, IconProps>(function UserIcon(
+  { size = 24, ...props },
+  ref,
+) {
+  return (
+    <svg
+      ref={ref}
+      xmlns="http://www.w3.org/2000/svg"
+      width={size}
+      height={size}
+      viewBox="0 0 24 24"
+      fill="none"
+      stroke="currentColor"
+      strokeWidth={2}
+      strokeLinecap="round"
+      strokeLinejoin="round"
+      {...props}
+    >
+      <path d="M20 21v-2a4 4 0 0 0-4-4H8a4 4 0 0 0-4 4v2" />
+    </svg>
+  );
+});
+UserIcon.displayName = "UserIcon";
+
+export const WarningIcon = forwardRef<SVGSVGElement, IconProps>(function WarningIcon(
+  { size = 24, ...props },
+  ref,
+) {
+  return (
+    <svg
+      ref={ref}
+      xmlns="http://www.w3.org/2000/svg"
+      width={size}
+      height={size}
+      viewBox="0 0 24 24"
+      fill="none"
+      stroke="currentColor"
+      strokeWidth={2}
+      strokeLinecap="round"
+      strokeLinejoin="round"
+      {...props}
+    >
+      <path d="M10.29 3.86L1.82 18a2 2 0 0 0 1.71 3h16.94a2 2 0 0 0 1.71-3L13.71 3.86a2 2 0 0 0-3.42 0z" />
+    </svg>
+  );
+});
+WarningIcon.displayName = "WarningIcon";
+
+export const icons = {
+  arrowLeft: ArrowLeftIcon,
+  arrowRight: ArrowRightIcon,
+  cart: CartIcon,
+  check: CheckIcon,
+  chevronDown: ChevronDownIcon,
+  chevronUp: ChevronUpIcon,
+  close: CloseIcon,
+  filter: FilterIcon,
+  heart: HeartIcon,
+  home: HomeIcon,
+  info: InfoIcon,
+  menu: MenuIcon,
+  minus: MinusIcon,
+  plus: PlusIcon,
+  search: SearchIcon,
+  settings: SettingsIcon,
+  star: StarIcon,
+  trash: TrashIcon,
+  user: UserIcon,
+  warning: WarningIcon,
+} as const;
+
+export type IconName = keyof typeof icons;
@@ -0,0 +1,52 @@
+import { Suspense } from "react";
+import dynamic from "next/dynamic";
+import { notFound } from "next/navigation";
+import { getProduct, getRecommendations } from "@/lib/api";
+import { ProductDetails } from "./product-details";
+import { RecommendationsSkeleton, ReviewsSkeleton } from "./skeletons";
+
+const Reviews = dynamic(() => import("./reviews"), {
+  loading: () => <ReviewsSkeleton />,
+});
+
+type Props = {
+  params: Promise<{ id: string }>;
+  searchParams: Promise<Record<string, string | string[] | undefined>>;
+};
+
+export async function generateMetadata({ params }: Props) {
+  const { id } = await params;
+  const product = await getProduct(id);
+  return { title: product?.name ?? "Product" };
+}
+
+export default async function ProductPage({ params }: Props) {
+  const { id } = await params;
+  const product = await getProduct(id);
+  if (!product) {
+    notFound();
+  }
+
+  return (
+    <div className="grid gap-8 md:grid-cols-2">
+      <ProductDetails product={product} />
+      <Suspense fallback={<ReviewsSkeleton />}>
+        <Reviews productId={id} />
+      </Suspense>
+      <Suspense key={id} fallback={<RecommendationsSkeleton />}>
+        <Recommendations productId={id} />
+      </Suspense>
+    </div>
+  );
+}
+
+async function Recommendations({ productId }: { productId: string }) {
+  const products = await getRecommendations(productId);
+  return (
+    <ul>
+      {products.map((product) => (
+        <li key={product.id}>{product.name}</li>
+      ))}
+    </ul>
+  );
+}
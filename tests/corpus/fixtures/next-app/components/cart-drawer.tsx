@@ -0,0 +1,41 @@
+"use client";
+
+import { Suspense, use, useState } from "react";
+import { ErrorBoundary } from "react-error-boundary";
+import type { Cart } from "@/lib/types";
+
+export function CartDrawer({ cartPromise }: { cartPromise: Promise<Cart> }) {
+  const [open, setOpen] = useState(false);
+
+  return (
+    <>
+      <button type="button" onClick={() => setOpen(!open)}>
+        Cart
+      </button>
+      {open && (
+        <ErrorBoundary fallback={<p>Could not load the cart</p>}>
+          <Suspense fallback={<CartSkeleton />}>
+            <CartItems cartPromise={cartPromise} />
+          </Suspense>
+        </ErrorBoundary>
+      )}
+    </>
+  );
+}
+
+function CartItems({ cartPromise }: { cartPromise: Promise<Cart> }) {
+  const cart = use(cartPromise);
+  return (
+    <ul>
+      {cart.items.map((item) => (
+        <li key={item.id}>
+          {item.name} × {item.quantity}
+        </li>
+      ))}
+    </ul>
+  );
+}
+
+function CartSkeleton() {
+  return <div className="h-32 animate-pulse" />;
+}
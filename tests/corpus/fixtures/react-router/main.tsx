@@ -0,0 +1,42 @@
+import { StrictMode, Suspense, lazy } from "react";
+import { createRoot } from "react-dom/client";
+import { createBrowserRouter, RouterProvider } from "react-router-dom";
+import { RootLayout } from "./layouts/root";
+import { PageSpinner } from "./components/page-spinner";
+
+const Settings = lazy(() => import("./routes/settings"));
+const Billing = lazy(() =>
+  import("./routes/billing").then((module) => ({ default: module.BillingPage })),
+);
+
+const router = createBrowserRouter([
+  {
+    path: "/",
+    element: <RootLayout />,
+    children: [
+      { index: true, lazy: () => import("./routes/home") },
+      {
+        path: "settings",
+        element: (
+          <Suspense fallback={<PageSpinner />}>
+            <Settings />
+          </Suspense>
+        ),
+      },
+      {
+        path: "billing",
+        element: (
+          <Suspense fallback={<PageSpinner />}>
+            <Billing />
+          </Suspense>
+        ),
+      },
+    ],
+  },
+]);
+
+createRoot(document.getElementById("root")!).render(
+  <StrictMode>
+    <RouterProvider router={router} />
+  </StrictMode>,
+);
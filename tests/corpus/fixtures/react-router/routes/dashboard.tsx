@@ -0,0 +1,32 @@
+import { Suspense } from "react";
+import { Await, useLoaderData } from "react-router";
+import type { Route } from "./+types/dashboard";
+import { getActivity, getStats } from "../data.server";
+import { ActivityFeed, StatsGrid } from "../components/dashboard";
+
+export async function loader({ request }: Route.LoaderArgs) {
+  const stats = await getStats(request);
+  // Not awaited so the activity streams in later
+  const activity = getActivity(request);
+  return { stats, activity };
+}
+
+export default function Dashboard() {
+  const { stats, activity } = useLoaderData<typeof loader>();
+
+  return (
+    <section>
+      <h1>Dashboard</h1>
+      <StatsGrid stats={stats} />
+      <Suspense fallback={<p>Loading activity…</p>}>
+        <Await resolve={activity} errorElement={<p>Could not load the activity</p>}>
+          {(items) => <ActivityFeed items={items} />}
+        </Await>
+      </Suspense>
+    </section>
+  );
+}
+
+export function ErrorBoundary({ error }: Route.ErrorBoundaryProps) {
+  return <p>{error instanceof Error ? error.message : "Unknown error"}</p>;
+}
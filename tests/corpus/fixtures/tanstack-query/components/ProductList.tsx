@@ -0,0 +1,68 @@
+import { Suspense } from "react";
+import {
+  QueryErrorResetBoundary,
+  useSuspenseQueries,
+  useSuspenseQuery,
+} from "@tanstack/react-query";
+import { ErrorBoundary } from "react-error-boundary";
+import { fetchProducts } from "../api";
+import { productKeys, productQueryOptions } from "../queries";
+import { ProductCard, ProductGridSkeleton } from "./ProductCard";
+
+function ProductGrid({ category }: { category: string }) {
+  const { data: products } = useSuspenseQuery({
+    queryKey: productKeys.list(category),
+    queryFn: () => fetchProducts(category),
+    staleTime: 60_000,
+  });
+  return (
+    <div className="grid">
+      {products.map((product) => (
+        <ProductCard key={product.id} product={product} />
+      ))}
+    </div>
+  );
+}
+
+function CategoryCounts({ categories }: { categories: string[] }) {
+  const results = useSuspenseQueries({
+    queries: categories.map((category) => ({
+      queryKey: productKeys.count(category),
+      queryFn: () => fetchProducts(category).then((products) => products.length),
+    })),
+  });
+  return (
+    <dl>
+      {results.map(({ data }, index) => (
+        <div key={categories[index]}>
+          <dt>{categories[index]}</dt>
+          <dd>{data}</dd>
+        </div>
+      ))}
+    </dl>
+  );
+}
+
+export function useProduct(id: string) {
+  return useSuspenseQuery(productQueryOptions(id));
+}
+
+export function ProductList({ category, categories }: { category: string; categories: string[] }) {
+  return (
+    <QueryErrorResetBoundary>
+      {({ reset }) => (
+        <ErrorBoundary
+          onReset={reset}
+          fallbackRender={({ resetErrorBoundary }) => (
+            <button onClick={resetErrorBoundary}>Try again</button>
+          )}
+        >
+          <Suspense fallback={<ProductGridSkeleton />}>
+            <CategoryCounts categories={categories} />
+            <ProductGrid category={category} />
+          </Suspense>
+        </ErrorBoundary>
+      )}
+    </QueryErrorResetBoundary>
+  );
+}
@@ -0,0 +1,151 @@
+//! Differential tests against the Babel plugin used for legacy builds
+//!
+//! Every fixture of `tests/differential/fixtures` is transformed with the options of
+//! `tests/differential/config.json` and compared to the checked-in output of the Babel
+//! plugin in `tests/differential/babel`. Formatting and import order may differ, but both
+//! implementations must inject the same trackers with the same boundary IDs and props.
+//! Update the goldens with the Babel plugin output whenever its semantics change.
+#![cfg(feature = "library")]
+
+use std::{collections::BTreeSet, fs, path::Path};
+
+use react_swc_suspense_tracker::{transform_source, Config, Context, Environment};
+use swc_core::{
+    common::{sync::Lrc, FileName, SourceMap, Spanned},
+    ecma::{
+        ast::{EsVersion, JSXAttrName, JSXAttrOrSpread, JSXElementName, JSXOpeningElement},
+        parser::{parse_file_as_module, EsSyntax, Syntax},
+        visit::{Visit, VisitWith},
+    },
+};
+
+const DIFFERENTIAL_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/differential");
+
+/// The tracker elements of a module with their props and all imported modules
+#[derive(Debug, PartialEq, Eq)]
+struct Semantics {
+    trackers: Vec<(String, Vec<String>)>,
+    imports: BTreeSet<String>,
+}
+
+/// Extracts the [`Semantics`] of transformed code
+fn semantics(code: &str) -> Semantics {
+    let source_map: Lrc<SourceMap> = Default::default();
+    let source_file = source_map.new_source_file(
+        FileName::Custom("output.jsx".into()).into(),
+        code.to_string(),
+    );
+    let module = parse_file_as_module(
+        &source_file,
+        Syntax::Es(EsSyntax {
+            jsx: true,
+            ..Default::default()
+        }),
+        EsVersion::latest(),
+        None,
+        &mut Vec::new(),
+    )
+    .unwrap_or_else(|error| panic!("failed to parse output: {error:?}\n{code}"));
+
+    let mut collector = TrackerCollector {
+        source_map: &source_map,
+        trackers: Vec::new(),
+    };
+    module.visit_with(&mut collector);
+    Semantics {
+        trackers: collector.trackers,
+        imports: module
+            .body
+            .iter()
+            .filter_map(|item| item.as_module_decl()?.as_import())
+            .filter_map(|import| import.src.value.as_str().map(str::to_string))
+            .collect(),
+    }
+}
+
+struct TrackerCollector<'a> {
+    source_map: &'a SourceMap,
+    trackers: Vec<(String, Vec<String>)>,
+}
+
+impl TrackerCollector<'_> {
+    /// Returns the source of a node without whitespace so formatting differences don't matter
+    fn snippet(&self, node: &impl Spanned) -> String {
+        self.source_map
+            .with_snippet_of_span(node.span(), |snippet| snippet.split_whitespace().collect())
+            .expect("snippet of parsed code")
+    }
+}
+
+impl Visit for TrackerCollector<'_> {
+    fn visit_jsx_opening_element(&mut self, element: &JSXOpeningElement) {
+        if let JSXElementName::Ident(name) = &element.name {
+            if name.sym.ends_with("TrackerSWC") {
+                let props = element
+                    .attrs
+                    .iter()
+                    .map(|attr| match attr {
+                        JSXAttrOrSpread::JSXAttr(attr) => {
+                            let name = match &attr.name {
+                                JSXAttrName::Ident(name) => name.sym.to_string(),
+                                JSXAttrName::JSXNamespacedName(name) => {
+                                    format!("{}:{}", name.ns.sym, name.name.sym)
+                                }
+                            };
+                            match &attr.value {
+                                Some(value) => format!("{name}={}", self.snippet(value)),
+                                None => name,
+                            }
+                        }
+                        JSXAttrOrSpread::SpreadElement(spread) => {
+                            format!("...{}", self.snippet(&spread.expr))
+                        }
+                    })
+                    .collect();
+                self.trackers.push((name.sym.to_string(), props));
+            }
+        }
+        element.visit_children_with(self);
+    }
+}
+
+#[test]
+fn swc_and_babel_outputs_are_equivalent() {
+    let config: Config = serde_json::from_str(
+        &fs::read_to_string(format!("{DIFFERENTIAL_DIR}/config.json")).expect("read config"),
+    )
+    .expect("invalid config");
+
+    let mut fixtures: Vec<_> = fs::read_dir(Path::new(DIFFERENTIAL_DIR).join("fixtures"))
+        .expect("read fixtures")
+        .map(|entry| entry.expect("fixture entry").path())
+        .collect();
+    fixtures.sort();
+    assert!(!fixtures.is_empty(), "the corpus has no fixtures");
+
+    for fixture in fixtures {
+        let name = fixture.file_name().unwrap().to_string_lossy().to_string();
+        let golden = Path::new(DIFFERENTIAL_DIR)
+            .join("babel")
+            .join(fixture.with_extension("js").file_name().unwrap());
+        let babel_output = fs::read_to_string(&golden)
+            .unwrap_or_else(|_| panic!("missing Babel output {}", golden.display()));
+
+        let output = transform_source(
+            &fs::read_to_string(&fixture).expect("read fixture"),
+            config.clone(),
+            Context {
+                env_name: Environment::Development,
+                filename: format!("src/{name}"),
+            },
+        )
+        .unwrap_or_else(|error| panic!("failed to transform {name}: {error}"));
+
+        assert_eq!(
+            semantics(&output.code),
+            semantics(&babel_output),
+            "the SWC output of {name} differs from the Babel output:\n{}",
+            output.code
+        );
+    }
+}
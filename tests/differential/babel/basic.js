@@ -0,0 +1,10 @@
+import { BoundaryTrackerSWC } from "react-swc-suspense-tracker/context";
+import { Suspense } from "react";
+
+export default function App() {
+  return (
+    <BoundaryTrackerSWC fallback={<Spinner />} boundaryId="src/basic.jsx:5" boundary={Suspense}>
+      <Profile />
+    </BoundaryTrackerSWC>
+  );
+}
@@ -0,0 +1,15 @@
+import { BoundaryTrackerSWC } from "react-swc-suspense-tracker/context";
+import { ErrorBoundaryTrackerSWC } from "react-swc-suspense-tracker/error-context";
+import { Suspense as ReactSuspense } from "react";
+import { ErrorBoundary } from "react-error-boundary";
+
+export const Page = () => (
+  <ErrorBoundaryTrackerSWC fallback={<p>Something went wrong</p>} boundaryId="src/nested.jsx:5" boundary={ErrorBoundary} boundaryPropMap={{ fallback: "fallback" }}>
+    <BoundaryTrackerSWC fallback={<p>Loading...</p>} boundaryId="src/nested.jsx:6" boundary={ReactSuspense}>
+      <Feed />
+      <BoundaryTrackerSWC fallback={null} boundaryId="src/nested.jsx:8" boundary={ReactSuspense}>
+        <Comments />
+      </BoundaryTrackerSWC>
+    </BoundaryTrackerSWC>
+  </ErrorBoundaryTrackerSWC>
+);
@@ -0,0 +1,10 @@
+import { BoundaryTrackerSWC } from "react-swc-suspense-tracker/context";
+import { Suspense } from "react";
+
+export function List({ items, ...props }) {
+  return items.map((item) => (
+    <BoundaryTrackerSWC key={item.id} {...props} fallback={<Row.Skeleton />} boundaryId="src/spread-props.jsx:5" boundary={Suspense}>
+      <Row item={item} />
+    </BoundaryTrackerSWC>
+  ));
+}
@@ -0,0 +1,9 @@
+import { Suspense } from "react";
+
+export default function App() {
+  return (
+    <Suspense fallback={<Spinner />}>
+      <Profile />
+    </Suspense>
+  );
+}
@@ -0,0 +1,13 @@
+import { Suspense as ReactSuspense } from "react";
+import { ErrorBoundary } from "react-error-boundary";
+
+export const Page = () => (
+  <ErrorBoundary fallback={<p>Something went wrong</p>}>
+    <ReactSuspense fallback={<p>Loading...</p>}>
+      <Feed />
+      <ReactSuspense fallback={null}>
+        <Comments />
+      </ReactSuspense>
+    </ReactSuspense>
+  </ErrorBoundary>
+);
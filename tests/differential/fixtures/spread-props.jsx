@@ -0,0 +1,9 @@
+import { Suspense } from "react";
+
+export function List({ items, ...props }) {
+  return items.map((item) => (
+    <Suspense key={item.id} {...props} fallback={<Row.Skeleton />}>
+      <Row item={item} />
+    </Suspense>
+  ));
+}
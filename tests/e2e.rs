@@ -0,0 +1,246 @@
+//! End-to-end tests which execute the transformed fixtures of `tests/e2e/fixtures`
+//!
+//! Every fixture is transformed with [`transform_source`], compiled to plain JavaScript
+//! with the swc JSX transform and rendered by Node (`tests/e2e/run.mjs`) against stub
+//! modules of React and the tracker runtime (`tests/e2e/stubs`). The stubs record the
+//! props every tracker received so the tests catch output which only breaks at runtime.
+#![cfg(feature = "library")]
+
+use std::{
+    collections::HashSet,
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use react_swc_suspense_tracker::{transform_source, Context, Environment};
+use serde_json::{json, Value};
+use swc_core::{
+    common::{
+        comments::SingleThreadedComments, sync::Lrc, FileName, Globals, Mark, SourceMap, GLOBALS,
+    },
+    ecma::{
+        ast::{EsVersion, JSXAttrName, JSXAttrOrSpread, JSXOpeningElement, Program},
+        codegen::to_code_default,
+        parser::{parse_file_as_module, EsSyntax, Syntax},
+        transforms::{base::resolver, react},
+        visit::{Visit, VisitWith},
+    },
+};
+
+const E2E_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/e2e");
+
+/// Transforms and renders a fixture and returns what the stub runtime recorded
+fn run_fixture(name: &str, config: Value) -> Value {
+    let source =
+        fs::read_to_string(format!("{E2E_DIR}/fixtures/{name}")).expect("failed to read fixture");
+    let output = transform_source(
+        &source,
+        serde_json::from_value(config).expect("invalid config"),
+        Context {
+            env_name: Environment::Development,
+            filename: format!("src/{name}"),
+        },
+    )
+    .expect("failed to transform fixture");
+
+    let module = parse_jsx(&output.code);
+    assert_no_duplicate_props(&module);
+
+    let dir = create_project(name, &compile_jsx(module));
+    let result = Command::new("node")
+        .arg("run.mjs")
+        .arg("./app.mjs")
+        .current_dir(&dir)
+        .output()
+        .expect("failed to run node - is Node.js installed?");
+    fs::remove_dir_all(&dir).ok();
+    assert!(
+        result.status.success(),
+        "executing the transformed {name} failed:\n{}\n{}",
+        String::from_utf8_lossy(&result.stderr),
+        output.code
+    );
+    serde_json::from_slice(&result.stdout).expect("run.mjs printed invalid JSON")
+}
+
+fn parse_jsx(code: &str) -> Program {
+    let source_map: Lrc<SourceMap> = Default::default();
+    let source_file =
+        source_map.new_source_file(FileName::Custom("app.jsx".into()).into(), code.to_string());
+    let module = parse_file_as_module(
+        &source_file,
+        Syntax::Es(EsSyntax {
+            jsx: true,
+            ..Default::default()
+        }),
+        EsVersion::latest(),
+        None,
+        &mut Vec::new(),
+    )
+    .unwrap_or_else(|error| panic!("transformed code is not valid JSX: {error:?}\n{code}"));
+    Program::Module(module)
+}
+
+/// Compiles JSX to `React.createElement` calls which Node can execute
+fn compile_jsx(program: Program) -> String {
+    let source_map: Lrc<SourceMap> = Default::default();
+    GLOBALS.set(&Globals::new(), || {
+        let unresolved_mark = Mark::new();
+        let top_level_mark = Mark::new();
+        let program = program
+            .apply(resolver(unresolved_mark, top_level_mark, false))
+            .apply(react::jsx(
+                source_map.clone(),
+                None::<SingleThreadedComments>,
+                Default::default(),
+                top_level_mark,
+                unresolved_mark,
+            ));
+        to_code_default(source_map, None, &program)
+    })
+}
+
+/// Writes the compiled module, the runner and the stub packages into a temporary directory
+fn create_project(name: &str, code: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "suspense-tracker-e2e-{}-{name}",
+        std::process::id()
+    ));
+    let stubs = Path::new(E2E_DIR).join("stubs");
+    write(&dir.join("package.json"), r#"{ "type": "module" }"#);
+    write(&dir.join("app.mjs"), code);
+    fs::copy(Path::new(E2E_DIR).join("run.mjs"), dir.join("run.mjs")).expect("copy runner");
+
+    for (package, stub, entries) in [
+        (
+            "react",
+            "react.mjs",
+            &[".", "./jsx-runtime", "./jsx-dev-runtime"][..],
+        ),
+        (
+            "react-error-boundary",
+            "react-error-boundary.mjs",
+            &["."][..],
+        ),
+        (
+            "react-swc-suspense-tracker",
+            "tracker.mjs",
+            &[".", "./context", "./context-test", "./error-context"][..],
+        ),
+    ] {
+        let package_dir = dir.join("node_modules").join(package);
+        let exports: serde_json::Map<String, Value> = entries
+            .iter()
+            .map(|entry| (entry.to_string(), json!("./index.mjs")))
+            .collect();
+        write(
+            &package_dir.join("package.json"),
+            &json!({ "name": package, "type": "module", "exports": exports }).to_string(),
+        );
+        fs::copy(stubs.join(stub), package_dir.join("index.mjs")).expect("copy stub");
+    }
+    dir
+}
+
+fn write(path: &Path, contents: &str) {
+    fs::create_dir_all(path.parent().expect("path has a parent")).expect("create directory");
+    fs::write(path, contents).expect("write file");
+}
+
+/// Duplicate props are valid syntax but silently override each other at runtime
+fn assert_no_duplicate_props(program: &Program) {
+    struct DuplicatePropFinder;
+
+    impl Visit for DuplicatePropFinder {
+        fn visit_jsx_opening_element(&mut self, element: &JSXOpeningElement) {
+            let mut names = HashSet::new();
+            for attr in &element.attrs {
+                if let JSXAttrOrSpread::JSXAttr(attr) = attr {
+                    if let JSXAttrName::Ident(name) = &attr.name {
+                        assert!(
+                            names.insert(name.sym.as_str()),
+                            "duplicate prop `{}`",
+                            name.sym
+                        );
+                    }
+                }
+            }
+            element.visit_children_with(self);
+        }
+    }
+
+    program.visit_with(&mut DuplicatePropFinder);
+}
+
+#[test]
+fn suspense_boundaries_render_the_tracker() {
+    let result = run_fixture("suspense.jsx", json!({}));
+
+    assert_eq!(
+        result["rendered"],
+        json!([{
+            "kind": "suspense",
+            "props": {
+                "fallback": "element div",
+                "boundaryId": "src/suspense.jsx:8",
+                "boundary": "function Suspense",
+                "children": "element Profile",
+            },
+        }])
+    );
+}
+
+#[test]
+fn error_boundaries_render_their_tracker() {
+    let result = run_fixture(
+        "error-boundary.jsx",
+        json!({
+            "boundaries": [{ "component": "ErrorBoundary", "from": "react-error-boundary", "kind": "error" }],
+        }),
+    );
+
+    let rendered: Vec<(&str, &str)> = result["rendered"]
+        .as_array()
+        .expect("rendered trackers")
+        .iter()
+        .map(|tracker| {
+            (
+                tracker["kind"].as_str().unwrap(),
+                tracker["props"]["boundaryId"].as_str().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        rendered,
+        vec![
+            ("error", "src/error-boundary.jsx:7"),
+            ("suspense", "src/error-boundary.jsx:8"),
+        ]
+    );
+}
+
+#[test]
+fn optional_tracker_props_survive_spread_props() {
+    let result = run_fixture(
+        "tracker-props.jsx",
+        json!({
+            "emitDebugName": true,
+            "emitOwnerStack": true,
+            "emitTestAttributes": true,
+            "emitRegistry": true,
+        }),
+    );
+
+    let props = &result["rendered"][0]["props"];
+    assert_eq!(props["boundaryId"], "src/tracker-props.jsx:7");
+    assert_eq!(props["debugName"], "content");
+    assert_eq!(props["ownerStack"], json!(["Article"]));
+    assert_eq!(
+        result["registered"]
+            .as_array()
+            .expect("registered boundaries")
+            .len(),
+        1
+    );
+}
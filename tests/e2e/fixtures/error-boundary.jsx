@@ -0,0 +1,14 @@
+import React, { Suspense } from "react";
+import { ErrorBoundary } from "react-error-boundary";
+
+const Feed = () => <ul />;
+
+export const Page = () => (
+  <ErrorBoundary fallback={<p>Something went wrong</p>}>
+    <Suspense fallback={<p>Loading feed...</p>}>
+      <Feed />
+    </Suspense>
+  </ErrorBoundary>
+);
+
+export default Page;
@@ -0,0 +1,13 @@
+import React, { Suspense } from "react";
+
+const Profile = ({ name }) => <p>{name}</p>;
+
+export default function App() {
+  return (
+    <main>
+      <Suspense fallback={<div className="spinner">Loading...</div>}>
+        <Profile name="Ada" />
+      </Suspense>
+    </main>
+  );
+}
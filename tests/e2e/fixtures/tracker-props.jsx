@@ -0,0 +1,12 @@
+import React, { Suspense } from "react";
+
+const Comments = () => <section />;
+
+export default function Article(props) {
+  const content = (
+    <Suspense {...props} fallback={<span>Loading comments...</span>}>
+      <Comments />
+    </Suspense>
+  );
+  return <article>{content}</article>;
+}
@@ -0,0 +1,37 @@
+// Renders the default export of a transformed module with the stub runtime
+// and prints the recorded trackers as JSON
+import { rendered, registered } from "react-swc-suspense-tracker/context";
+
+const { default: App } = await import(process.argv[2]);
+
+const render = (node) => {
+  if (Array.isArray(node)) {
+    node.forEach(render);
+  } else if (node && typeof node === "object" && "props" in node) {
+    const { type, props } = node;
+    render(typeof type === "function" ? type(props) : props.children);
+    render(props.fallback);
+  }
+};
+render(App({}));
+
+const describe = (value) => {
+  if (Array.isArray(value)) return value.map(describe);
+  if (typeof value === "function") return `function ${value.name}`;
+  if (value && typeof value === "object" && "props" in value) {
+    return `element ${typeof value.type === "function" ? value.type.name : String(value.type)}`;
+  }
+  return value;
+};
+
+console.log(
+  JSON.stringify({
+    rendered: rendered.map(({ kind, props }) => ({
+      kind,
+      props: Object.fromEntries(
+        Object.entries(props).map(([name, value]) => [name, describe(value)]),
+      ),
+    })),
+    registered,
+  }),
+);
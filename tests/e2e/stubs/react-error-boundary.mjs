@@ -0,0 +1 @@
+export const ErrorBoundary = ({ children }) => children;
@@ -0,0 +1,21 @@
+// Minimal stand-in for React which builds a plain element tree instead of rendering
+export const Fragment = Symbol.for("react.fragment");
+export const Profiler = Symbol.for("react.profiler");
+
+export const Suspense = ({ children }) => children;
+
+export const createElement = (type, props, ...children) => ({
+  type,
+  props: {
+    ...props,
+    ...(children.length > 0 && {
+      children: children.length === 1 ? children[0] : children,
+    }),
+  },
+});
+
+export const jsx = (type, props) => ({ type, props });
+export const jsxs = jsx;
+export const jsxDEV = jsx;
+
+export default { Fragment, Profiler, Suspense, createElement };
@@ -0,0 +1,16 @@
+// Stand-in for all runtime entry points of react-swc-suspense-tracker
+// which records how the injected trackers were rendered
+export const rendered = [];
+export const registered = [];
+
+const track = (kind) => (props) => {
+  rendered.push({ kind, props });
+  return props.children;
+};
+
+export const BoundaryTrackerSWC = track("suspense");
+export const ErrorBoundaryTrackerSWC = track("error");
+
+export const registerBoundaries = (boundaries) => {
+  registered.push(...boundaries);
+};
@@ -0,0 +1,204 @@
+//! Fast Refresh tests which run the React Refresh transform of swc on the transformed code
+//!
+//! Plugins run before the built-in transforms of swc, so the refresh transform registers
+//! the components and computes the hook signatures of the transformed module. Both must be
+//! the same as without the transform and must not change when an edit shifts the lines of
+//! the boundaries, otherwise Fast Refresh remounts the components (or misses hook changes).
+#![cfg(feature = "library")]
+
+use react_swc_suspense_tracker::{transform_source, Context, Environment};
+use serde_json::{json, Value};
+use swc_core::{
+    common::{
+        comments::SingleThreadedComments, sync::Lrc, FileName, Globals, Mark, SourceMap, GLOBALS,
+    },
+    ecma::{
+        ast::{EsVersion, JSXElementName, JSXOpeningElement, Program},
+        codegen::to_code_default,
+        parser::{parse_file_as_module, Syntax, TsSyntax},
+        transforms::{base::resolver, react},
+        visit::{Visit, VisitWith},
+    },
+};
+
+const PRODUCT: &str = r#"import { Suspense, lazy, useState } from "react";
+import { useSuspenseQuery } from "@tanstack/react-query";
+import { useReadQuery } from "@apollo/client";
+
+const Reviews = lazy(() => import("./Reviews"));
+
+function Price({ id }: { id: string }) {
+  const { data } = useSuspenseQuery({ queryKey: ["price", id], queryFn: fetchPrice });
+  return <span>{data}</span>;
+}
+
+function Stock({ queryRef }) {
+  const { data } = useReadQuery(queryRef);
+  return <span>{data.stock}</span>;
+}
+
+export default function Product({ id, queryRef }: { id: string; queryRef: unknown }) {
+  const [tab, setTab] = useState("details");
+  return (
+    <Suspense fallback={<Spinner />}>
+      <Price id={id} />
+      <Suspense fallback={null}>
+        <Stock queryRef={queryRef} />
+      </Suspense>
+      {tab === "reviews" && <Reviews />}
+    </Suspense>
+  );
+}
+"#;
+
+/// The output of the refresh transform
+struct Refreshed {
+    code: String,
+    /// The names of all rendered elements
+    elements: Vec<String>,
+}
+
+impl Refreshed {
+    /// The component registrations and hook signatures the refresh transform added
+    fn refresh_calls(&self) -> Vec<&str> {
+        self.code
+            .lines()
+            .map(str::trim)
+            .filter(|line| {
+                line.contains("$RefreshReg$")
+                    || line.contains("$RefreshSig$")
+                    || line.starts_with("_s(")
+                    || line.starts_with("_c")
+            })
+            .collect()
+    }
+}
+
+struct ElementCollector(Vec<String>);
+
+impl Visit for ElementCollector {
+    fn visit_jsx_opening_element(&mut self, element: &JSXOpeningElement) {
+        if let JSXElementName::Ident(name) = &element.name {
+            self.0.push(name.sym.to_string());
+        }
+        element.visit_children_with(self);
+    }
+}
+
+/// Runs the React Refresh transform of swc (as `jsc.transform.react.refresh` does)
+fn refresh(code: &str) -> Refreshed {
+    let source_map: Lrc<SourceMap> = Default::default();
+    let source_file = source_map.new_source_file(
+        FileName::Custom("src/Product.tsx".into()).into(),
+        code.to_string(),
+    );
+    let module = parse_file_as_module(
+        &source_file,
+        Syntax::Typescript(TsSyntax {
+            tsx: true,
+            ..Default::default()
+        }),
+        EsVersion::latest(),
+        None,
+        &mut Vec::new(),
+    )
+    .unwrap_or_else(|error| panic!("failed to parse module: {error:?}\n{code}"));
+
+    GLOBALS.set(&Globals::new(), || {
+        let unresolved_mark = Mark::new();
+        let top_level_mark = Mark::new();
+        let program = Program::Module(module)
+            .apply(resolver(unresolved_mark, top_level_mark, true))
+            .apply(react::refresh(
+                true,
+                Some(Default::default()),
+                source_map.clone(),
+                None::<SingleThreadedComments>,
+                top_level_mark,
+            ));
+        let mut elements = ElementCollector(Vec::new());
+        program.visit_with(&mut elements);
+        Refreshed {
+            code: to_code_default(source_map, None, &program),
+            elements: elements.0,
+        }
+    })
+}
+
+fn transform(code: &str, config: Value) -> String {
+    transform_source(
+        code,
+        serde_json::from_value(config).expect("invalid config"),
+        Context {
+            env_name: Environment::Development,
+            filename: "src/Product.tsx".to_string(),
+        },
+    )
+    .expect("failed to transform module")
+    .code
+}
+
+fn fast_refresh_config() -> Value {
+    json!({
+        "fastRefresh": true,
+        "emitRegistry": true,
+        "trackLazy": true,
+        "trackTanstackQuery": true,
+        "trackApollo": true,
+        "trackFallback": true,
+        "trackContentMount": true,
+    })
+}
+
+#[test]
+fn refresh_registrations_and_signatures_are_unchanged() {
+    let original = refresh(PRODUCT);
+    let transformed = refresh(&transform(PRODUCT, fast_refresh_config()));
+    assert!(
+        transformed.code.contains("BoundaryTrackerSWC"),
+        "not transformed:\n{}",
+        transformed.code
+    );
+    assert_eq!(
+        transformed.refresh_calls(),
+        original.refresh_calls(),
+        "the transform changed what Fast Refresh registers:\n{}",
+        transformed.code
+    );
+}
+
+#[test]
+fn wrapped_hooks_leave_the_component_signature() {
+    // Without `fastRefresh` the `useReadQuery` call moves into an arrow function
+    let mut config = fast_refresh_config();
+    config["fastRefresh"] = json!(false);
+    let original = refresh(PRODUCT);
+    let transformed = refresh(&transform(PRODUCT, config));
+    assert!(
+        transformed.code.contains("__trackSuspendingHook"),
+        "not wrapped:\n{}",
+        transformed.code
+    );
+    assert_ne!(transformed.refresh_calls(), original.refresh_calls());
+}
+
+#[test]
+fn edits_which_shift_lines_keep_the_element_types() {
+    let before = refresh(&transform(PRODUCT, fast_refresh_config()));
+    let edited = format!("// Shows a product\n\n{PRODUCT}");
+    let after = refresh(&transform(&edited, fast_refresh_config()));
+
+    assert!(before.code.contains(r#"boundaryId="src/Product.tsx:20""#));
+    assert!(after.code.contains(r#"boundaryId="src/Product.tsx:22""#));
+    assert_eq!(after.refresh_calls(), before.refresh_calls());
+    // The injected trackers are imported, Fast Refresh only registers local components
+    assert_eq!(after.elements, before.elements);
+    assert!(
+        !after
+            .refresh_calls()
+            .iter()
+            .any(|call| call.contains("TrackerSWC")),
+        "a tracker was registered as component:\n{}",
+        after.code
+    );
+}
@@ -0,0 +1,188 @@
+//! Tests which run the compiled wasm plugin through the SWC plugin runner
+//!
+//! Unlike the tests of [`TransformVisitor`](react_swc_suspense_tracker::TransformVisitor)
+//! these go through the host boundary: the config JSON and the metadata (filename and
+//! environment) are passed by the host and lines are resolved with the source map proxy.
+//! The wasm module is built with `cargo build --release --target wasm32-wasip1` unless
+//! `SUSPENSE_TRACKER_WASM` points to an already compiled module.
+//!
+//! The plugin runner can't be compiled together with the plugin side of `swc_core`:
+//! `cargo test --no-default-features --features plugin-host-tests --test plugin_host`
+#![cfg(feature = "plugin-host-tests")]
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::Command,
+    sync::{Arc, OnceLock},
+};
+
+use serde_json::{json, Value};
+use swc_core::{
+    common::{
+        plugin::{
+            metadata::TransformPluginMetadataContext,
+            serialized::{PluginSerializedBytes, VersionedSerializable},
+        },
+        sync::Lrc,
+        FileName, Globals, Mark, SourceMap, GLOBALS,
+    },
+    ecma::{
+        ast::{EsVersion, Program},
+        codegen::to_code_default,
+        parser::{parse_file_as_module, Syntax, TsSyntax},
+    },
+    plugin_runner::{create_plugin_transform_executor, plugin_module_bytes::RawPluginModuleBytes},
+};
+
+const APP: &str = r#"import { Suspense } from "react";
+
+export const App = () => (
+  <Suspense fallback={<Loading />}>
+    <Content />
+  </Suspense>
+);"#;
+
+/// Builds the wasm module once for all tests
+fn wasm_module() -> &'static [u8] {
+    static MODULE: OnceLock<Vec<u8>> = OnceLock::new();
+    MODULE.get_or_init(|| {
+        let path = match env::var_os("SUSPENSE_TRACKER_WASM") {
+            Some(path) => PathBuf::from(path),
+            None => build_wasm_module(),
+        };
+        fs::read(&path).unwrap_or_else(|error| panic!("failed to read {}: {error}", path.display()))
+    })
+}
+
+/// Builds the wasm module into its own target directory, `cargo test` still holds the lock
+/// of the default one
+fn build_wasm_module() -> PathBuf {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let target_dir = manifest_dir.join("target/plugin-host-tests");
+    let status = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()))
+        .args([
+            "build",
+            "--release",
+            "--target",
+            "wasm32-wasip1",
+            "--target-dir",
+        ])
+        .arg(&target_dir)
+        .env("RUSTFLAGS", "--cfg swc_ast_unknown")
+        .current_dir(manifest_dir)
+        .status()
+        .expect("failed to run cargo");
+    assert!(
+        status.success(),
+        "building the wasm module failed - is the wasm32-wasip1 target installed?"
+    );
+    target_dir.join("wasm32-wasip1/release/react_swc_suspense_tracker.wasm")
+}
+
+/// Transforms `code` with the wasm plugin and returns the emitted code
+fn run_plugin(
+    code: &str,
+    filename: Option<&str>,
+    env_name: &str,
+    config: Value,
+) -> Result<String, String> {
+    let source_map: Lrc<SourceMap> = Default::default();
+    let source_file = source_map.new_source_file(
+        FileName::Custom(filename.unwrap_or("input.tsx").into()).into(),
+        code.to_string(),
+    );
+    let module = parse_file_as_module(
+        &source_file,
+        Syntax::Typescript(TsSyntax {
+            tsx: true,
+            ..Default::default()
+        }),
+        EsVersion::latest(),
+        None,
+        &mut Vec::new(),
+    )
+    .expect("failed to parse module");
+
+    GLOBALS.set(&Globals::new(), || {
+        let program = PluginSerializedBytes::try_serialize(&VersionedSerializable::new(
+            Program::Module(module),
+        ))
+        .expect("failed to serialize program");
+        let metadata = Arc::new(TransformPluginMetadataContext::new(
+            filename.map(str::to_string),
+            env_name.to_string(),
+            None,
+        ));
+        let mut executor = create_plugin_transform_executor(
+            &source_map,
+            &Mark::new(),
+            &metadata,
+            None,
+            Box::new(RawPluginModuleBytes::new(
+                "react_swc_suspense_tracker.wasm".to_string(),
+                wasm_module().to_vec(),
+            )),
+            Some(config),
+            None,
+        );
+        let output = executor
+            .transform(&program, Some(false))
+            .map_err(|error| format!("{error:?}"))?;
+        let program: Program = output
+            .deserialize()
+            .map_err(|error| format!("{error:?}"))?
+            .into_inner();
+        Ok(to_code_default(source_map.clone(), None, &program))
+    })
+}
+
+#[test]
+fn metadata_and_source_map_are_read_through_the_host() {
+    let code = run_plugin(APP, Some("src/App.tsx"), "development", json!({}))
+        .expect("failed to run the plugin");
+    assert!(
+        code.contains(
+            r#"import { BoundaryTrackerSWC } from "react-swc-suspense-tracker/context";"#
+        ),
+        "missing tracker import:\n{code}"
+    );
+    // The line is resolved with the source map proxy of the host
+    assert!(
+        code.contains(r#"boundaryId="src/App.tsx:4""#),
+        "wrong boundary ID:\n{code}"
+    );
+}
+
+#[test]
+fn production_builds_are_not_transformed() {
+    let code = run_plugin(APP, Some("src/App.tsx"), "production", json!({}))
+        .expect("failed to run the plugin");
+    assert!(!code.contains("BoundaryTrackerSWC"), "transformed:\n{code}");
+}
+
+#[test]
+fn missing_filename_falls_back_to_the_configured_filename() {
+    let code = run_plugin(
+        APP,
+        None,
+        "development",
+        json!({ "fallbackFilename": "unknown.tsx" }),
+    )
+    .expect("failed to run the plugin");
+    assert!(
+        code.contains(r#"boundaryId="unknown.tsx:4""#),
+        "wrong boundary ID:\n{code}"
+    );
+}
+
+#[test]
+fn invalid_config_fails_the_transform() {
+    let result = run_plugin(
+        APP,
+        Some("src/App.tsx"),
+        "development",
+        json!({ "unknownOption": true }),
+    );
+    assert!(result.is_err(), "the plugin accepted an unknown option");
+}
@@ -0,0 +1,229 @@
+//! Source map tests which run the transform with a real `SourceMap`
+//!
+//! Every fixture is checked with LF line endings, CRLF line endings and a leading BOM:
+//! the boundary IDs must reference the original lines (also when the lines are resolved
+//! from the source text instead of the source map) and the emitted mappings of code the
+//! transform doesn't touch must be the same as without the transform.
+#![cfg(feature = "library")]
+
+use std::ops::Range;
+
+use react_swc_suspense_tracker::{Config, Environment, TransformVisitor};
+use swc_core::{
+    common::{sync::Lrc, BytePos, FileName, Globals, SourceMap, Spanned, GLOBALS},
+    ecma::{
+        ast::{EsVersion, Module},
+        codegen::{text_writer::JsWriter, Emitter},
+        parser::{parse_file_as_module, Syntax, TsSyntax},
+        visit::VisitMutWith,
+    },
+};
+
+/// Boundaries with multi-line elements, preceded by a multi-line comment and template
+const MULTI_LINE_JSX: &str = r#"import { Suspense } from "react";
+
+/*
+ * Formats a price
+ */
+export function formatPrice(value: number) {
+  return `${value.toFixed(2)}
+EUR`;
+}
+
+export const App = () => (
+  <Suspense
+    fallback={<Spinner />}
+  >
+    <Header />
+    <Suspense fallback={null}><Cart /></Suspense>
+  </Suspense>
+);
+
+export function total(prices: number[]) {
+  return prices.reduce((sum, price) => sum + price, 0);
+}
+"#;
+
+/// Lines of the `<Suspense>` elements of [`MULTI_LINE_JSX`]
+const MULTI_LINE_JSX_LINES: [usize; 2] = [12, 16];
+
+/// A boundary on the first line, which is the one a BOM is part of
+const FIRST_LINE_BOUNDARY: &str = r#"import { Suspense } from "react"; export const App = () => <Suspense fallback={null}><Header /></Suspense>;
+"#;
+
+/// The line endings and BOM variants of a fixture
+fn variants(code: &str) -> [(&'static str, String); 4] {
+    [
+        ("LF", code.to_string()),
+        ("CRLF", code.replace('\n', "\r\n")),
+        ("BOM", format!("\u{feff}{code}")),
+        (
+            "BOM + CRLF",
+            format!("\u{feff}{}", code.replace('\n', "\r\n")),
+        ),
+    ]
+}
+
+fn tsx_syntax() -> Syntax {
+    Syntax::Typescript(TsSyntax {
+        tsx: true,
+        ..Default::default()
+    })
+}
+
+fn parse(code: &str) -> (Lrc<SourceMap>, Module) {
+    let source_map: Lrc<SourceMap> = Default::default();
+    let source_file = source_map.new_source_file(
+        FileName::Custom("src/App.tsx".into()).into(),
+        code.to_string(),
+    );
+    let module = parse_file_as_module(
+        &source_file,
+        tsx_syntax(),
+        EsVersion::latest(),
+        None,
+        &mut Vec::new(),
+    )
+    .expect("failed to parse module");
+    (source_map, module)
+}
+
+/// Transforms `code` and returns the emitted module together with the boundary IDs
+///
+/// Lines are resolved with the source map or, without `use_source_map`, from the source text.
+fn transform(
+    code: &str,
+    config: Config,
+    use_source_map: bool,
+) -> (Lrc<SourceMap>, Module, Vec<String>) {
+    let (source_map, mut module) = parse(code);
+    let builder = TransformVisitor::builder()
+        .config(config)
+        .env(Environment::Development)
+        .filename("src/App.tsx");
+    let mut visitor = if use_source_map {
+        builder.source_map(source_map.clone()).build()
+    } else {
+        builder.source_text(code.to_string()).build()
+    };
+    GLOBALS.set(&Globals::new(), || module.visit_mut_with(&mut visitor));
+    let ids = visitor
+        .boundaries()
+        .iter()
+        .map(|boundary| boundary.id.clone())
+        .collect();
+    (source_map, module, ids)
+}
+
+/// Emits a module and returns the code with its mappings of original positions
+/// to generated (0-based) lines and columns
+fn emit(source_map: &Lrc<SourceMap>, module: &Module) -> (String, Vec<(BytePos, u32, u32)>) {
+    let mut code = Vec::new();
+    let mut mappings = Vec::new();
+    {
+        let mut emitter = Emitter {
+            cfg: Default::default(),
+            cm: source_map.clone(),
+            comments: None,
+            wr: JsWriter::new(source_map.clone(), "\n", &mut code, Some(&mut mappings)),
+        };
+        emitter.emit_module(module).expect("failed to emit module");
+    }
+    let mappings = mappings
+        .into_iter()
+        .map(|(pos, line_col)| (pos, line_col.line, line_col.col))
+        .collect();
+    (
+        String::from_utf8(code).expect("emitted invalid UTF-8"),
+        mappings,
+    )
+}
+
+/// Source ranges of the module items without boundary elements
+fn untouched_ranges(source_map: &SourceMap, module: &Module) -> Vec<Range<BytePos>> {
+    module
+        .body
+        .iter()
+        .map(Spanned::span)
+        .filter(|span| {
+            !source_map
+                .with_snippet_of_span(*span, |snippet| snippet.contains("<Suspense"))
+                .expect("snippet of parsed code")
+        })
+        .map(|span| span.lo..span.hi)
+        .collect()
+}
+
+#[test]
+fn boundary_ids_reference_the_original_lines() {
+    for (variant, code) in variants(MULTI_LINE_JSX) {
+        for use_source_map in [true, false] {
+            let (_, _, ids) = transform(&code, Config::default(), use_source_map);
+            let expected: Vec<String> = MULTI_LINE_JSX_LINES
+                .iter()
+                .map(|line| format!("src/App.tsx:{line}"))
+                .collect();
+            assert_eq!(
+                ids, expected,
+                "wrong boundary IDs for {variant} (source map: {use_source_map})"
+            );
+        }
+    }
+}
+
+#[test]
+fn boundary_columns_ignore_carriage_returns_and_the_bom() {
+    let config = Config {
+        id_format: Some("{file}:{line}:{column}".to_string()),
+        ..Default::default()
+    };
+    for (variant, code) in variants(FIRST_LINE_BOUNDARY) {
+        for use_source_map in [true, false] {
+            let (_, _, ids) = transform(&code, config.clone(), use_source_map);
+            assert_eq!(
+                ids,
+                vec!["src/App.tsx:1:60"],
+                "wrong boundary IDs for {variant} (source map: {use_source_map})"
+            );
+        }
+    }
+    for (variant, code) in variants(MULTI_LINE_JSX) {
+        let (_, _, ids) = transform(&code, config.clone(), true);
+        assert_eq!(
+            ids,
+            vec!["src/App.tsx:12:3", "src/App.tsx:16:5"],
+            "wrong boundary IDs for {variant}"
+        );
+    }
+}
+
+#[test]
+fn mappings_of_untouched_code_are_unchanged() {
+    for (variant, code) in variants(MULTI_LINE_JSX) {
+        let (source_map, module) = parse(&code);
+        let untouched = untouched_ranges(&source_map, &module);
+        let (_, original) = emit(&source_map, &module);
+
+        let (source_map, module, _) = transform(&code, Config::default(), true);
+        let (output, transformed) = emit(&source_map, &module);
+        assert!(
+            output.starts_with("import { BoundaryTrackerSWC }"),
+            "expected the tracker import on the first line:\n{output}"
+        );
+
+        let is_untouched =
+            |(pos, _, _): &&(BytePos, u32, u32)| untouched.iter().any(|range| range.contains(pos));
+        let expected: Vec<_> = original
+            .iter()
+            .filter(is_untouched)
+            // The tracker import is emitted above the original code
+            .map(|(pos, line, col)| (*pos, line + 1, *col))
+            .collect();
+        let actual: Vec<_> = transformed.iter().filter(is_untouched).copied().collect();
+        assert!(!expected.is_empty(), "no mappings of untouched code");
+        assert_eq!(
+            actual, expected,
+            "the mappings of untouched code changed for {variant}:\n{output}"
+        );
+    }
+}